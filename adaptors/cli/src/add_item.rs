@@ -1,10 +1,17 @@
-use backlog::{Assignee, BacklogItem, Story, StoryPoint, Task};
+use backlog::{Assignee, BacklogItem, ItemKind, StoryPoint};
 use backlog_service::{AddItemCmd, BacklogUseCase, Command, IncommingError, IncommingResult};
 
 use super::{error_handler, CliAdaptoer};
 
 pub async fn add_item_handler(ctx: CliAdaptoer, cmd: AddItemCliCmd) {
-    error_handler(|| ctx.add_item(cmd)).await
+    error_handler(|| async move {
+        let added = ctx.add_item(cmd).await?;
+        for duplicate in added.duplicates() {
+            eprintln!("warning: the item looks like {}", duplicate);
+        }
+        Ok(added.backlog().clone())
+    })
+    .await
 }
 
 #[derive(Clone, Debug, clap::Parser)]
@@ -13,27 +20,26 @@ pub struct AddItemCliCmd {
     title: String,
     point: Option<u8>,
     assignee: Option<String>,
+    /// Add the item even if it looks like any existing item.
+    #[clap(long)]
+    force: bool,
 }
 
 impl Command for AddItemCliCmd {}
 
 impl AddItemCmd for AddItemCliCmd {
     fn item(&self) -> IncommingResult<Box<dyn BacklogItem>> {
-        let point = self.point.map(StoryPoint::new).transpose().unwrap();
+        let point = self
+            .point
+            .map(StoryPoint::new)
+            .transpose()
+            .map_err(|err| IncommingError::invalid_value("StoryPoint", err.to_string()))?;
         let assignee = self.assignee.as_ref().map(|v| Assignee::new(v));
-        let item: Box<dyn BacklogItem> = match self.item_type.as_str() {
-            "Story" => Box::new(Story::new(&self.title, point, assignee)),
-            "Task" => Box::new(Task::new(&self.title, point, assignee)),
-            _ => {
-                return Err(IncommingError::invalid_value(
-                    "item_type",
-                    format!(
-                        "the field does not take the value, {}",
-                        self.item_type.as_str()
-                    ),
-                ))
-            }
-        };
-        Ok(item)
+        ItemKind::create(&self.item_type, &self.title, point, assignee)
+            .map_err(|err| IncommingError::invalid_value("item_type", err.to_string()))
+    }
+
+    fn force(&self) -> IncommingResult<bool> {
+        Ok(self.force)
     }
 }
@@ -0,0 +1,184 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+use backlog::{Assignee, Attachment, Entity, Uuid};
+use backlog_service::{
+    AttachItemCmd, AttachmentCmd, AttachmentsCmd, BacklogUseCase, Command, IncommingError,
+    IncommingResult,
+};
+
+use super::{error_handler, print_handler, CliAdaptoer};
+use crate::output::{render_table, OutputFormat, TableView};
+
+pub async fn attach_handler(ctx: CliAdaptoer, cmd: AttachCliCmd) {
+    error_handler(|| ctx.attach_item(cmd)).await
+}
+
+pub async fn attachments_handler(ctx: CliAdaptoer, cmd: AttachmentsCliCmd) {
+    let format = cmd.format;
+    let id = cmd.id;
+    match cmd.command {
+        None => {
+            print_handler(|| async move {
+                let attachments = ctx.get_attachments(cmd).await?;
+                format.render(&attachments)
+            })
+            .await
+        }
+        Some(AttachmentsSubCommand::Download(download)) => {
+            print_handler(|| async move {
+                let output = download.output.clone();
+                let (attachment, content) = ctx
+                    .download_attachment(AttachmentCliCmd {
+                        id,
+                        attachment: download.attachment,
+                    })
+                    .await?;
+                save(&attachment, &content, output)
+            })
+            .await
+        }
+        Some(AttachmentsSubCommand::Delete(delete)) => {
+            error_handler(|| {
+                ctx.delete_attachment(AttachmentCliCmd {
+                    id,
+                    attachment: delete.attachment,
+                })
+            })
+            .await
+        }
+    }
+}
+
+/// write the content to the output, or to a new file named after the attachment.
+fn save(attachment: &Attachment, content: &[u8], output: Option<PathBuf>) -> eyre::Result<String> {
+    let mut file = match &output {
+        Some(path) => std::fs::File::create(path)?,
+        None => OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(attachment.filename())?,
+    };
+    file.write_all(content)?;
+    let path = output.unwrap_or_else(|| attachment.filename().into());
+    Ok(format!("{} bytes to {}", content.len(), path.display()))
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct AttachCliCmd {
+    id: Uuid,
+    file: PathBuf,
+    /// The user who attaches the file. Defaults to $USER.
+    #[clap(short, long)]
+    uploader: Option<String>,
+}
+
+impl Command for AttachCliCmd {}
+
+impl AttachItemCmd for AttachCliCmd {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn filename(&self) -> IncommingResult<String> {
+        self.file
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .ok_or_else(|| IncommingError::invalid_value("file", "the path has no file name"))
+    }
+
+    fn content(&self) -> IncommingResult<Vec<u8>> {
+        std::fs::read(&self.file).map_err(|err| {
+            IncommingError::invalid_value("file", format!("{}, {}", self.file.display(), err))
+        })
+    }
+
+    fn uploader(&self) -> IncommingResult<Assignee> {
+        self.uploader
+            .clone()
+            .or_else(|| std::env::var("USER").ok())
+            .map(|uploader| Assignee::new(&uploader))
+            .ok_or_else(|| IncommingError::invalid_value("uploader", "--uploader is required"))
+    }
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct AttachmentsCliCmd {
+    id: Uuid,
+    #[clap(subcommand)]
+    command: Option<AttachmentsSubCommand>,
+    /// json or table.
+    #[clap(short, long, default_value = "table")]
+    format: OutputFormat,
+}
+
+impl Command for AttachmentsCliCmd {}
+
+impl AttachmentsCmd for AttachmentsCliCmd {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+}
+
+#[derive(Clone, Debug, clap::Subcommand)]
+pub enum AttachmentsSubCommand {
+    /// Save the content of the attachment.
+    Download(DownloadCliCmd),
+    /// Remove the attachment from the item.
+    Delete(DeleteCliCmd),
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct DownloadCliCmd {
+    attachment: Uuid,
+    /// Defaults to the filename of the attachment, which must not exist yet.
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct DeleteCliCmd {
+    attachment: Uuid,
+}
+
+struct AttachmentCliCmd {
+    id: Uuid,
+    attachment: Uuid,
+}
+
+impl Command for AttachmentCliCmd {}
+
+impl AttachmentCmd for AttachmentCliCmd {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn attachment(&self) -> IncommingResult<Uuid> {
+        Ok(self.attachment)
+    }
+}
+
+impl TableView for Vec<Attachment> {
+    fn table(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .iter()
+            .map(|attachment| {
+                vec![
+                    attachment.id().to_string(),
+                    attachment.filename().to_string(),
+                    attachment.size().to_string(),
+                    attachment.uploader().name().to_string(),
+                    attachment
+                        .uploaded_at()
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string(),
+                ]
+            })
+            .collect();
+        render_table(
+            &["id", "filename", "size", "uploader", "uploaded at"],
+            &rows,
+        )
+    }
+}
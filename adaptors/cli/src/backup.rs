@@ -0,0 +1,48 @@
+use backlog_repo::Backup;
+use backlog_service::{BacklogUseCase, Command, IncommingResult, RestoreCmd};
+
+use super::{error_handler, print_handler, CliAdaptoer};
+use crate::output::render_table;
+
+pub async fn backups_handler(ctx: CliAdaptoer) {
+    print_handler(|| async move {
+        let backups = ctx.get_backups().await?;
+        Ok(table(&backups))
+    })
+    .await
+}
+
+pub async fn restore_handler(ctx: CliAdaptoer, cmd: RestoreCliCmd) {
+    error_handler(|| ctx.restore_backup(cmd)).await
+}
+
+fn table(backups: &[Backup]) -> String {
+    let rows: Vec<Vec<String>> = backups
+        .iter()
+        .map(|backup| {
+            vec![
+                backup.generation().to_string(),
+                backup.saved_at().format("%Y-%m-%d %H:%M:%S").to_string(),
+                backup
+                    .items()
+                    .map(|items| items.to_string())
+                    .unwrap_or_else(|| "unreadable".to_string()),
+            ]
+        })
+        .collect();
+    render_table(&["generation", "saved at", "items"], &rows)
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct RestoreCliCmd {
+    /// 1 is the newest backup. The current data becomes the newest backup.
+    generation: usize,
+}
+
+impl Command for RestoreCliCmd {}
+
+impl RestoreCmd for RestoreCliCmd {
+    fn generation(&self) -> IncommingResult<usize> {
+        Ok(self.generation)
+    }
+}
@@ -0,0 +1,188 @@
+use backlog::{Board, BoardConfig, Card, Column, Status, Swimlane, Uuid, WipPolicy};
+use backlog_service::{
+    BacklogUseCase, BoardCmd, Command, ConfigureBoardCmd, IncommingError, IncommingResult,
+    MoveItemCmd,
+};
+
+use super::{print_handler, CliAdaptoer};
+use crate::output::{render_table, OutputFormat, TableView};
+
+pub async fn board_handler(ctx: CliAdaptoer, cmd: BoardCliCmd) {
+    let format = cmd.format;
+    print_handler(|| async move {
+        let board = match cmd.command.clone() {
+            None => ctx.board(cmd).await?,
+            Some(BoardSubCommand::Move(cmd)) => ctx.move_item(cmd).await?,
+            Some(BoardSubCommand::Configure(cmd)) => ctx.configure_board(cmd).await?,
+        };
+        format.render(&board)
+    })
+    .await
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct BoardCliCmd {
+    #[clap(subcommand)]
+    command: Option<BoardSubCommand>,
+    /// none, assignee or type. If it is omitted, the configured swimlane is used.
+    #[clap(long)]
+    swimlane: Option<String>,
+    /// json or table
+    #[clap(short, long, default_value = "table")]
+    format: OutputFormat,
+}
+
+#[derive(Clone, Debug, clap::Subcommand)]
+pub enum BoardSubCommand {
+    /// Move the item into the column.
+    Move(MoveItemCliCmd),
+    /// Replace the columns, swimlane and WIP policy.
+    Configure(ConfigureBoardCliCmd),
+}
+
+impl Command for BoardCliCmd {}
+
+impl BoardCmd for BoardCliCmd {
+    fn swimlane(&self) -> IncommingResult<Option<Swimlane>> {
+        self.swimlane
+            .as_ref()
+            .map(|swimlane| swimlane.parse())
+            .transpose()
+            .map_err(|err: backlog::BacklogError| {
+                IncommingError::invalid_value("swimlane", err.to_string())
+            })
+    }
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct MoveItemCliCmd {
+    id: Uuid,
+    column: String,
+}
+
+impl Command for MoveItemCliCmd {}
+
+impl MoveItemCmd for MoveItemCliCmd {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn column(&self) -> IncommingResult<String> {
+        Ok(self.column.clone())
+    }
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct ConfigureBoardCliCmd {
+    /// NAME=STATUS[,STATUS...][:WIP_LIMIT], e.g. "Doing=InProgress:3". Repeat it for each column.
+    #[clap(long = "column", required = true)]
+    columns: Vec<String>,
+    /// none, assignee or type
+    #[clap(long, default_value = "none")]
+    swimlane: String,
+    /// fail or warn
+    #[clap(long, default_value = "fail")]
+    wip_policy: String,
+}
+
+impl Command for ConfigureBoardCliCmd {}
+
+impl ConfigureBoardCmd for ConfigureBoardCliCmd {
+    fn config(&self) -> IncommingResult<BoardConfig> {
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| parse_column(column))
+            .collect::<IncommingResult<Vec<_>>>()?;
+        let swimlane = self
+            .swimlane
+            .parse()
+            .map_err(|err: backlog::BacklogError| {
+                IncommingError::invalid_value("swimlane", err.to_string())
+            })?;
+        let wip_policy: WipPolicy =
+            self.wip_policy
+                .parse()
+                .map_err(|err: backlog::BacklogError| {
+                    IncommingError::invalid_value("wip_policy", err.to_string())
+                })?;
+        BoardConfig::new(columns, swimlane, wip_policy)
+            .map_err(|err| IncommingError::invalid_value("column", err.to_string()))
+    }
+}
+
+fn parse_column(spec: &str) -> IncommingResult<Column> {
+    let invalid = |msg: String| IncommingError::invalid_value("column", msg);
+    let (name, rest) = spec
+        .split_once('=')
+        .ok_or_else(|| invalid(format!("the column must be NAME=STATUS, {}", spec)))?;
+    let (statuses, limit) = match rest.split_once(':') {
+        Some((statuses, limit)) => {
+            let limit = limit
+                .parse::<usize>()
+                .map_err(|err| invalid(format!("{}, {}", err, spec)))?;
+            (statuses, Some(limit))
+        }
+        None => (rest, None),
+    };
+    let statuses = statuses
+        .split(',')
+        .map(|status| status.trim().parse::<Status>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| invalid(err.to_string()))?;
+    Column::new(name.trim(), statuses, limit).map_err(|err| invalid(err.to_string()))
+}
+
+impl TableView for Board {
+    fn table(&self) -> String {
+        let header: Vec<String> = self
+            .columns()
+            .iter()
+            .map(|column| match column.wip_limit() {
+                Some(limit) => format!("{} ({}/{})", column.name(), column.count(), limit),
+                None => format!("{} ({})", column.name(), column.count()),
+            })
+            .collect();
+
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        let show_lanes =
+            self.swimlanes().len() > 1 || self.swimlanes().iter().any(|l| l.name().is_some());
+        for lane in self.swimlanes() {
+            if show_lanes {
+                let mut row = vec![String::new(); header.len()];
+                row[0] = format!("[{}]", lane.name().unwrap_or("none"));
+                rows.push(row);
+            }
+            let height = lane
+                .cells()
+                .iter()
+                .map(|cell| cell.len())
+                .max()
+                .unwrap_or(0);
+            for i in 0..height {
+                rows.push(
+                    lane.cells()
+                        .iter()
+                        .map(|cell| cell.get(i).map(card).unwrap_or_default())
+                        .collect(),
+                );
+            }
+        }
+
+        let header: Vec<&str> = header.iter().map(|h| h.as_str()).collect();
+        let mut table = render_table(&header, &rows);
+        for warning in self.warnings() {
+            table.push_str(&format!("\nwarning: {}", warning));
+        }
+        table
+    }
+}
+
+fn card(card: &Card) -> String {
+    let id = card.id().to_string();
+    let mut text = format!("{} {}", &id[..8], card.title());
+    if let Some(point) = card.point() {
+        text.push_str(&format!(" ({})", point));
+    }
+    text
+}
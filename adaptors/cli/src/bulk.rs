@@ -0,0 +1,120 @@
+use std::io::Read;
+
+use backlog::{Assignee, BacklogError, BulkChange, BulkOutcome, BulkReport, StoryPoint, Uuid};
+use backlog_service::{BacklogUseCase, BulkCmd, Command, IncommingError, IncommingResult};
+
+use super::{print_handler, CliAdaptoer};
+use crate::output::{render_table, OutputFormat, TableView};
+
+pub async fn bulk_handler(ctx: CliAdaptoer, cmd: BulkCliCmd) {
+    let format = cmd.format;
+    print_handler(|| async move {
+        let report = ctx.bulk_update(cmd).await?;
+        format.render(&report)
+    })
+    .await
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct BulkCliCmd {
+    /// The ids of items. If they are omitted, they are read from stdin.
+    ids: Vec<Uuid>,
+    #[clap(long, group = "change")]
+    assign: Option<String>,
+    #[clap(long, group = "change")]
+    estimate: Option<u8>,
+    /// Move the items into the sprint.
+    #[clap(long, group = "change")]
+    sprint: Option<Uuid>,
+    /// Move the items out of any sprint.
+    #[clap(long, group = "change")]
+    unplan: bool,
+    /// Todo, InProgress or Done
+    #[clap(long, group = "change")]
+    status: Option<String>,
+    /// Highest, High, Medium, Low or Lowest
+    #[clap(long, group = "change")]
+    priority: Option<String>,
+    /// json or table
+    #[clap(short, long, default_value = "table")]
+    format: OutputFormat,
+}
+
+impl Command for BulkCliCmd {}
+
+impl BulkCmd for BulkCliCmd {
+    fn ids(&self) -> IncommingResult<Vec<Uuid>> {
+        if !self.ids.is_empty() {
+            return Ok(self.ids.clone());
+        }
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|err| IncommingError::invalid_value("ids", err.to_string()))?;
+        input
+            .split_whitespace()
+            .map(|id| {
+                id.parse()
+                    .map_err(|err| IncommingError::invalid_value("ids", format!("{}, {}", err, id)))
+            })
+            .collect()
+    }
+
+    fn change(&self) -> IncommingResult<BulkChange> {
+        let invalid = |field: &'static str| {
+            move |err: BacklogError| IncommingError::invalid_value(field, err.to_string())
+        };
+        if let Some(assignee) = &self.assign {
+            return Ok(BulkChange::Assign(Assignee::new(assignee)));
+        }
+        if let Some(point) = self.estimate {
+            return StoryPoint::new(point)
+                .map(BulkChange::Estimate)
+                .map_err(invalid("estimate"));
+        }
+        if let Some(sprint) = self.sprint {
+            return Ok(BulkChange::Plan(Some(sprint)));
+        }
+        if self.unplan {
+            return Ok(BulkChange::Plan(None));
+        }
+        if let Some(status) = &self.status {
+            return status
+                .parse()
+                .map(BulkChange::ChangeStatus)
+                .map_err(invalid("status"));
+        }
+        if let Some(priority) = &self.priority {
+            return priority
+                .parse()
+                .map(BulkChange::Prioritize)
+                .map_err(invalid("priority"));
+        }
+        Err(IncommingError::invalid_value(
+            "change",
+            "one of --assign, --estimate, --sprint, --unplan, --status or --priority is required",
+        ))
+    }
+}
+
+impl TableView for BulkReport {
+    fn table(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .results()
+            .iter()
+            .map(|result| {
+                let (outcome, reason) = match result.outcome() {
+                    BulkOutcome::Changed => ("changed", ""),
+                    BulkOutcome::Unchanged => ("unchanged", ""),
+                    BulkOutcome::Failed { reason } => ("failed", reason.as_str()),
+                };
+                vec![
+                    result.id().to_string(),
+                    outcome.to_string(),
+                    reason.to_string(),
+                ]
+            })
+            .collect();
+        render_table(&["id", "result", "reason"], &rows)
+    }
+}
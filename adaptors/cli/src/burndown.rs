@@ -0,0 +1,103 @@
+use backlog::{Burndown, Uuid};
+use backlog_service::{BacklogUseCase, BurndownCmd, Command, IncommingResult};
+
+use super::{print_handler, CliAdaptoer};
+use crate::output::{render_table, OutputFormat, TableView};
+
+const CHART_HEIGHT: usize = 10;
+
+pub async fn burndown_handler(ctx: CliAdaptoer, cmd: BurndownCliCmd) {
+    let format = cmd.format;
+    print_handler(|| async move {
+        let burndown = ctx.burndown(cmd).await?;
+        format.render(&burndown)
+    })
+    .await
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct BurndownCliCmd {
+    sprint: Uuid,
+    /// json or table
+    #[clap(short, long, default_value = "table")]
+    format: OutputFormat,
+}
+
+impl Command for BurndownCliCmd {}
+
+impl BurndownCmd for BurndownCliCmd {
+    fn sprint(&self) -> IncommingResult<Uuid> {
+        Ok(self.sprint)
+    }
+}
+
+impl TableView for Burndown {
+    fn table(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .days()
+            .iter()
+            .map(|day| {
+                vec![
+                    day.date().to_string(),
+                    day.scope().to_string(),
+                    day.completed().to_string(),
+                    day.remaining().to_string(),
+                    format!("{:.1}", day.ideal()),
+                    format!("{:+}", day.scope_change()),
+                ]
+            })
+            .collect();
+        let table = render_table(
+            &["date", "scope", "completed", "remaining", "ideal", "change"],
+            &rows,
+        );
+        format!(
+            "{} ({} - {})\n\n{}\n\n{}",
+            self.name(),
+            self.start(),
+            self.end(),
+            chart(self),
+            table
+        )
+    }
+}
+
+/// draw the remaining (`*`), completed (`o`) and ideal (`.`) points as an ASCII chart.
+fn chart(burndown: &Burndown) -> String {
+    let max = burndown
+        .days()
+        .iter()
+        .map(|day| day.scope() as f64)
+        .fold(1.0, f64::max);
+    let row = |value: f64| (value / max * CHART_HEIGHT as f64).round() as usize;
+
+    let mut lines = Vec::new();
+    for level in (0..=CHART_HEIGHT).rev() {
+        let cells: String = burndown
+            .days()
+            .iter()
+            .map(|day| {
+                if row(day.remaining() as f64) == level {
+                    " * "
+                } else if row(day.completed() as f64) == level {
+                    " o "
+                } else if row(day.ideal()) == level {
+                    " . "
+                } else {
+                    "   "
+                }
+            })
+            .collect();
+        let label = max * level as f64 / CHART_HEIGHT as f64;
+        lines.push(format!("{:>5.1} |{}", label, cells));
+    }
+    lines.push(format!("      +{}", "-".repeat(burndown.days().len() * 3)));
+    let days: String = burndown
+        .days()
+        .iter()
+        .map(|day| format!("{:>3}", day.date().format("%d")))
+        .collect();
+    lines.push(format!("       {}", days));
+    lines.push("       * remaining  o completed  . ideal".to_string());
+    lines.join("\n")
+}
@@ -0,0 +1,82 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use backlog_service::BacklogUseCase;
+use fs::{DataFormat, DirBacklogRepository, FsBacklogRepository};
+
+use super::{print_handler, CliAdaptoer};
+
+pub async fn convert_handler(ctx: CliAdaptoer, cmd: ConvertCliCmd) {
+    print_handler(|| async move {
+        if cmd.output.exists() && !cmd.force {
+            eyre::bail!(
+                "the output already exists, {}. Overwrite it with --force",
+                cmd.output.display()
+            );
+        }
+        let same_file =
+            cmd.output.exists() && cmd.output.canonicalize().ok() == ctx.data.canonicalize().ok();
+        if same_file || cmd.output == ctx.data {
+            eyre::bail!(
+                "the output is the data file itself, {}",
+                cmd.output.display()
+            );
+        }
+        let backlog = match cmd.layout {
+            Layout::File => {
+                let mut target = FsBacklogRepository::new(cmd.output.clone());
+                if let Some(format) = cmd.to {
+                    target = target.with_format(format);
+                }
+                ctx.convert(&target).await?
+            }
+            Layout::Dir => {
+                let mut target = DirBacklogRepository::new(cmd.output.clone());
+                if let Some(format) = cmd.to {
+                    target = target.with_format(format);
+                }
+                ctx.convert(&target).await?
+            }
+        };
+        Ok(format!(
+            "{} items are written to {}",
+            backlog.items().len(),
+            cmd.output.display()
+        ))
+    })
+    .await
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct ConvertCliCmd {
+    /// The data file to write, in the format chosen by its extension, or the directory.
+    output: PathBuf,
+    /// json, yaml or toml, whatever the extension of the output is. yaml for the directory by default.
+    #[clap(long)]
+    to: Option<DataFormat>,
+    /// file writes the whole backlog into the output, and dir writes a file per item into the output directory.
+    #[clap(long, default_value = "file")]
+    layout: Layout,
+    /// Overwrite the output if it exists.
+    #[clap(long)]
+    force: bool,
+}
+
+/// How the converted backlog is laid out on the disk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Layout {
+    File,
+    Dir,
+}
+
+impl FromStr for Layout {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "file" => Ok(Self::File),
+            "dir" => Ok(Self::Dir),
+            _ => Err(format!("the layout must be file or dir, {}", s)),
+        }
+    }
+}
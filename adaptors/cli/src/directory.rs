@@ -0,0 +1,137 @@
+use backlog::{TeamProfile, User};
+use backlog_service::{
+    AddTeamCmd, AddUserCmd, BacklogUseCase, Command, IncommingError, IncommingResult,
+    UpdateTeamCmd, UpdateUserCmd,
+};
+
+use super::{error_handler, CliAdaptoer};
+
+pub async fn get_directory_handler(ctx: CliAdaptoer) {
+    error_handler(|| ctx.get_directory()).await
+}
+
+pub async fn user_handler(ctx: CliAdaptoer, cmd: UserCliCmd) {
+    match cmd.command {
+        UserSubCommand::Add(cmd) => error_handler(|| ctx.add_user(cmd)).await,
+        UserSubCommand::Update(cmd) => error_handler(|| ctx.update_user(cmd)).await,
+    }
+}
+
+pub async fn team_handler(ctx: CliAdaptoer, cmd: TeamCliCmd) {
+    match cmd.command {
+        TeamSubCommand::Add(cmd) => error_handler(|| ctx.add_team(cmd)).await,
+        TeamSubCommand::Update(cmd) => error_handler(|| ctx.update_team(cmd)).await,
+    }
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct UserCliCmd {
+    #[clap(subcommand)]
+    command: UserSubCommand,
+}
+
+#[derive(Clone, Debug, clap::Subcommand)]
+pub enum UserSubCommand {
+    /// Add the user to the directory.
+    Add(AddUserCliCmd),
+    /// Rename, activate or deactivate the user.
+    Update(UpdateUserCliCmd),
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct TeamCliCmd {
+    #[clap(subcommand)]
+    command: TeamSubCommand,
+}
+
+#[derive(Clone, Debug, clap::Subcommand)]
+pub enum TeamSubCommand {
+    /// Add the team to the directory.
+    Add(AddTeamCliCmd),
+    /// Activate or deactivate the team.
+    Update(UpdateTeamCliCmd),
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct AddUserCliCmd {
+    handle: String,
+    display_name: String,
+}
+
+impl Command for AddUserCliCmd {}
+
+impl AddUserCmd for AddUserCliCmd {
+    fn user(&self) -> IncommingResult<User> {
+        User::new(&self.handle, &self.display_name)
+            .map_err(|err| IncommingError::invalid_value("handle", err.to_string()))
+    }
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct UpdateUserCliCmd {
+    handle: String,
+    /// Rename the user and reassign every item assigned to the user.
+    #[clap(long)]
+    rename: Option<String>,
+    #[clap(long)]
+    display_name: Option<String>,
+    /// true or false
+    #[clap(long)]
+    active: Option<bool>,
+}
+
+impl Command for UpdateUserCliCmd {}
+
+impl UpdateUserCmd for UpdateUserCliCmd {
+    fn handle(&self) -> IncommingResult<String> {
+        Ok(self.handle.clone())
+    }
+    fn new_handle(&self) -> IncommingResult<Option<String>> {
+        Ok(self.rename.clone())
+    }
+    fn display_name(&self) -> IncommingResult<Option<String>> {
+        Ok(self.display_name.clone())
+    }
+    fn active(&self) -> IncommingResult<Option<bool>> {
+        Ok(self.active)
+    }
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct AddTeamCliCmd {
+    handle: String,
+    display_name: String,
+}
+
+impl Command for AddTeamCliCmd {}
+
+impl AddTeamCmd for AddTeamCliCmd {
+    fn team(&self) -> IncommingResult<TeamProfile> {
+        TeamProfile::new(&self.handle, &self.display_name)
+            .map_err(|err| IncommingError::invalid_value("handle", err.to_string()))
+    }
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct UpdateTeamCliCmd {
+    handle: String,
+    #[clap(long)]
+    display_name: Option<String>,
+    /// true or false
+    #[clap(long)]
+    active: Option<bool>,
+}
+
+impl Command for UpdateTeamCliCmd {}
+
+impl UpdateTeamCmd for UpdateTeamCliCmd {
+    fn handle(&self) -> IncommingResult<String> {
+        Ok(self.handle.clone())
+    }
+    fn display_name(&self) -> IncommingResult<Option<String>> {
+        Ok(self.display_name.clone())
+    }
+    fn active(&self) -> IncommingResult<Option<bool>> {
+        Ok(self.active)
+    }
+}
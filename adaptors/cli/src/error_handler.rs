@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 use std::future::Future;
 
 pub async fn error_handler<F, T, Fut>(f: F)
@@ -12,3 +12,15 @@ where
         Ok(r) => println!("{:?}", r),
     }
 }
+
+pub async fn print_handler<F, T, Fut>(f: F)
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = eyre::Result<T>>,
+    T: Display,
+{
+    match f().await {
+        Err(e) => eprintln!("{:?}", e),
+        Ok(r) => println!("{}", r),
+    }
+}
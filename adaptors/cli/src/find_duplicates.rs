@@ -0,0 +1,52 @@
+use backlog::DuplicateCluster;
+use backlog_service::{BacklogUseCase, Command, FindDuplicatesCmd, IncommingResult};
+
+use super::{print_handler, CliAdaptoer};
+use crate::output::{render_table, OutputFormat, TableView};
+
+pub async fn find_duplicates_handler(ctx: CliAdaptoer, cmd: FindDuplicatesCliCmd) {
+    let format = cmd.format;
+    print_handler(|| async move {
+        let clusters = ctx.find_duplicates(cmd).await?;
+        format.render(&clusters)
+    })
+    .await
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct FindDuplicatesCliCmd {
+    /// The similarity of titles from 0 to 1, above which items are duplicates.
+    #[clap(short, long)]
+    threshold: Option<f64>,
+    /// json or table
+    #[clap(short, long, default_value = "table")]
+    format: OutputFormat,
+}
+
+impl Command for FindDuplicatesCliCmd {}
+
+impl FindDuplicatesCmd for FindDuplicatesCliCmd {
+    fn threshold(&self) -> IncommingResult<Option<f64>> {
+        Ok(self.threshold)
+    }
+}
+
+impl TableView for Vec<DuplicateCluster> {
+    fn table(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .iter()
+            .enumerate()
+            .flat_map(|(i, cluster)| {
+                cluster.items().iter().map(move |item| {
+                    vec![
+                        (i + 1).to_string(),
+                        item.id().to_string(),
+                        item.title().to_string(),
+                        format!("{:.0}%", item.similarity() * 100.0),
+                    ]
+                })
+            })
+            .collect();
+        render_table(&["cluster", "id", "title", "similarity"], &rows)
+    }
+}
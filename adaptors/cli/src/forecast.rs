@@ -0,0 +1,146 @@
+use backlog::{Forecast, ForecastBasis, ItemsForecast, NaiveDate, Simulation, Team};
+use backlog_service::{
+    BacklogUseCase, Command, ForecastCmd, ForecastItemsCmd, IncommingError, IncommingResult,
+};
+
+use super::{print_handler, CliAdaptoer};
+use crate::output::{render_table, OutputFormat, TableView};
+
+pub async fn forecast_handler(ctx: CliAdaptoer, cmd: ForecastCliCmd) {
+    let format = cmd.format;
+    print_handler(|| async move {
+        match cmd.by {
+            Some(_) => format.render(&ctx.forecast_items(cmd).await?),
+            None => format.render(&ctx.forecast(cmd).await?),
+        }
+    })
+    .await
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct ForecastCliCmd {
+    /// velocity or throughput
+    #[clap(short, long, default_value = "velocity")]
+    basis: String,
+    /// The seed of the simulation. If it is omitted, a random seed is used.
+    #[clap(long)]
+    seed: Option<u64>,
+    /// The number of simulated trials.
+    #[clap(long, default_value = "10000")]
+    trials: usize,
+    /// Sample only the sprints of the team.
+    #[clap(long)]
+    team: Option<String>,
+    /// Forecast how many items will be done by the date (YYYY-MM-DD) instead.
+    #[clap(long)]
+    by: Option<NaiveDate>,
+    /// json or table
+    #[clap(short, long, default_value = "table")]
+    format: OutputFormat,
+}
+
+impl ForecastCliCmd {
+    fn to_simulation(&self) -> IncommingResult<Simulation> {
+        let team = self.team.as_ref().map(|team| Team::new(team));
+        Simulation::new(self.seed, self.trials, team)
+            .map_err(|err| IncommingError::invalid_value("trials", err.to_string()))
+    }
+}
+
+impl Command for ForecastCliCmd {}
+
+impl ForecastCmd for ForecastCliCmd {
+    fn basis(&self) -> IncommingResult<ForecastBasis> {
+        self.basis.parse().map_err(|err: backlog::BacklogError| {
+            IncommingError::invalid_value("basis", err.to_string())
+        })
+    }
+
+    fn simulation(&self) -> IncommingResult<Simulation> {
+        self.to_simulation()
+    }
+}
+
+impl ForecastItemsCmd for ForecastCliCmd {
+    fn date(&self) -> IncommingResult<NaiveDate> {
+        self.by
+            .ok_or_else(|| IncommingError::invalid_value("by", "the date is required"))
+    }
+
+    fn simulation(&self) -> IncommingResult<Simulation> {
+        self.to_simulation()
+    }
+}
+
+impl TableView for Forecast {
+    fn table(&self) -> String {
+        let unit = match self.basis() {
+            ForecastBasis::Velocity => "points",
+            ForecastBasis::Throughput => "items",
+        };
+        let rows: Vec<Vec<String>> = self
+            .completions()
+            .iter()
+            .map(|completion| {
+                vec![
+                    format!("{}%", completion.confidence()),
+                    completion.sprints().to_string(),
+                    completion.date().to_string(),
+                ]
+            })
+            .collect();
+        let mut lines = vec![
+            format!("remaining: {} {}", self.remaining(), unit),
+            format!(
+                "sprint length: {} days, samples: {:?}",
+                self.sprint_length(),
+                self.samples()
+            ),
+            format!(
+                "seed: {}, trials: {}",
+                self.simulation().seed(),
+                self.simulation().trials()
+            ),
+        ];
+        if self.unestimated() > 0 {
+            lines.push(format!(
+                "warning: {} items are not estimated and counted as 0 points",
+                self.unestimated()
+            ));
+        }
+        lines.push(String::new());
+        lines.push(render_table(&["confidence", "sprints", "date"], &rows));
+        lines.join("\n")
+    }
+}
+
+impl TableView for ItemsForecast {
+    fn table(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .outcomes()
+            .iter()
+            .map(|outcome| {
+                vec![
+                    format!("{}%", outcome.confidence()),
+                    outcome.items().to_string(),
+                ]
+            })
+            .collect();
+        let lines = [
+            format!(
+                "by {}: {} sprints, {} items remaining",
+                self.date(),
+                self.sprints(),
+                self.remaining()
+            ),
+            format!(
+                "seed: {}, trials: {}",
+                self.simulation().seed(),
+                self.simulation().trials()
+            ),
+            String::new(),
+            render_table(&["confidence", "items at least"], &rows),
+        ];
+        lines.join("\n")
+    }
+}
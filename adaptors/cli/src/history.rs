@@ -0,0 +1,69 @@
+use backlog_repo::HistoryEntry;
+use backlog_service::{BacklogUseCase, Command, DiffCmd, HistoryCmd, IncommingResult};
+
+use super::{print_handler, CliAdaptoer};
+use crate::output::render_table;
+
+pub async fn history_handler(ctx: CliAdaptoer, cmd: HistoryCliCmd) {
+    print_handler(|| async move {
+        let history = ctx.get_history(cmd).await?;
+        Ok(table(&history))
+    })
+    .await
+}
+
+pub async fn diff_handler(ctx: CliAdaptoer, cmd: DiffCliCmd) {
+    print_handler(|| async move { ctx.diff(cmd).await }).await
+}
+
+fn table(history: &[HistoryEntry]) -> String {
+    let rows: Vec<Vec<String>> = history
+        .iter()
+        .map(|entry| {
+            vec![
+                entry.id().chars().take(8).collect(),
+                entry.saved_at().format("%Y-%m-%d %H:%M:%S").to_string(),
+                entry.author().to_string(),
+                entry.summary().to_string(),
+            ]
+        })
+        .collect();
+    render_table(&["revision", "saved at", "author", "summary"], &rows)
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct HistoryCliCmd {
+    /// The number of the saves to list at most.
+    #[clap(short = 'n', long, default_value = "20")]
+    limit: usize,
+}
+
+impl Command for HistoryCliCmd {}
+
+impl HistoryCmd for HistoryCliCmd {
+    fn limit(&self) -> IncommingResult<usize> {
+        Ok(self.limit)
+    }
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct DiffCliCmd {
+    /// The older revision, e.g. the revision listed by history.
+    #[clap(default_value = "HEAD~1")]
+    from: String,
+    /// The newer revision.
+    #[clap(default_value = "HEAD")]
+    to: String,
+}
+
+impl Command for DiffCliCmd {}
+
+impl DiffCmd for DiffCliCmd {
+    fn from(&self) -> IncommingResult<String> {
+        Ok(self.from.clone())
+    }
+
+    fn to(&self) -> IncommingResult<String> {
+        Ok(self.to.clone())
+    }
+}
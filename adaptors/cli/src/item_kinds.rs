@@ -0,0 +1,7 @@
+use backlog::ItemKind;
+
+use super::error_handler;
+
+pub async fn item_kinds_handler() {
+    error_handler(|| async { eyre::Result::Ok(ItemKind::all()) }).await
+}
@@ -1,31 +1,105 @@
 mod add_item;
 mod assign_item;
+mod attachment;
 mod backlog;
+mod backup;
+mod board;
+mod bulk;
+mod burndown;
+mod convert;
+mod directory;
 mod error_handler;
 mod estimate_item;
+mod find_duplicates;
+mod forecast;
+mod history;
+mod item_kinds;
+mod list_items;
+mod migrate;
+mod output;
+mod prioritize_item;
+mod rank_item;
+mod relate_item;
+mod release;
+mod split_item;
+mod sprint;
+mod velocity;
+mod warning_notifier;
+mod watch_item;
 
 pub use clap;
-pub use error_handler::error_handler;
+pub use error_handler::{error_handler, print_handler};
 
 use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
 
 use crate::backlog::get_backlog_handler;
 use add_item::{add_item_handler, AddItemCliCmd};
 use assign_item::{assign_item_handler, AssignItemCliCmd};
-use backlog_repo::ProvideBacklogRepository;
+use attachment::{attach_handler, attachments_handler, AttachCliCmd, AttachmentsCliCmd};
+use backlog_repo::{BacklogRepository, ProvideBacklogRepository};
 use backlog_service::BacklogUseCase;
+use backup::{backups_handler, restore_handler, RestoreCliCmd};
+use blob_storage::ProvideBlobStorage;
+use board::{board_handler, BoardCliCmd};
+use bulk::{bulk_handler, BulkCliCmd};
+use burndown::{burndown_handler, BurndownCliCmd};
 use clap::Parser;
+use convert::{convert_handler, ConvertCliCmd};
+use directory::{get_directory_handler, team_handler, user_handler, TeamCliCmd, UserCliCmd};
 use estimate_item::{estimate_item_handler, EstimateItemCliCmd};
-use fs::FsBacklogRepository;
+use find_duplicates::{find_duplicates_handler, FindDuplicatesCliCmd};
+use forecast::{forecast_handler, ForecastCliCmd};
+use fs::{
+    DataFormat, DirBacklogRepository, FsBacklogRepository, FsBlobStorage, JournalBacklogRepository,
+};
+use git::{Author, GitBacklogRepository};
+use history::{diff_handler, history_handler, DiffCliCmd, HistoryCliCmd};
+use item_kinds::item_kinds_handler;
+use list_items::{list_items_handler, ListItemsCliCmd};
+use mailbox::MailboxNotifier;
+use migrate::{migrate_handler, MigrateCliCmd};
+use notifier::{Notifiers, ProvideNotifier};
+use prioritize_item::{prioritize_item_handler, PrioritizeItemCliCmd};
+use rank_item::{rank_item_handler, RankItemCliCmd};
+use relate_item::{
+    label_item_handler, link_item_handler, set_epic_handler, LabelItemCliCmd, LinkItemCliCmd,
+    SetEpicCliCmd,
+};
+use release::{release_handler, ReleaseCliCmd};
+use split_item::{split_item_handler, SplitItemCliCmd};
+use sprint::{sprint_handler, SprintCliCmd};
+use sqlite::SqliteBacklogRepository;
+use velocity::{velocity_handler, VelocityCliCmd};
+use warning_notifier::WarningNotifier;
+use watch_item::{unwatch_item_handler, watch_item_handler, WatchItemCliCmd};
+use webhook::WebhookNotifier;
 
 pub struct CliAdaptoer {
-    fs: FsBacklogRepository,
+    data: PathBuf,
+    repo: Arc<dyn BacklogRepository + Send + Sync>,
+    blobs: FsBlobStorage,
+    notifiers: WarningNotifier,
 }
 
 impl CliAdaptoer {
-    pub fn new(path: PathBuf) -> Self {
+    /// `repo` stores the backlog in `data`, and the attachments are kept beside it.
+    pub fn new(data: PathBuf, repo: Arc<dyn BacklogRepository + Send + Sync>) -> Self {
         Self {
-            fs: FsBacklogRepository::new(path),
+            blobs: FsBlobStorage::beside(&data),
+            data,
+            repo,
+            notifiers: WarningNotifier::default(),
+        }
+    }
+
+    /// the failure to notify the watchers is only warned, as the change has been saved.
+    pub fn with_notifiers(self, notifiers: Notifiers) -> Self {
+        Self {
+            notifiers: WarningNotifier::new(notifiers),
+            ..self
         }
     }
 }
@@ -33,10 +107,26 @@ impl CliAdaptoer {
 impl BacklogUseCase for CliAdaptoer {}
 
 impl ProvideBacklogRepository for CliAdaptoer {
-    type Repository = FsBacklogRepository;
+    type Repository = Arc<dyn BacklogRepository + Send + Sync>;
 
     fn provide(&self) -> &Self::Repository {
-        &self.fs
+        &self.repo
+    }
+}
+
+impl ProvideBlobStorage for CliAdaptoer {
+    type BlobStorage = FsBlobStorage;
+
+    fn blob_storage(&self) -> &Self::BlobStorage {
+        &self.blobs
+    }
+}
+
+impl ProvideNotifier for CliAdaptoer {
+    type Notifier = WarningNotifier;
+
+    fn notifier(&self) -> &Self::Notifier {
+        &self.notifiers
     }
 }
 
@@ -47,6 +137,34 @@ pub struct Args {
     command: SubCommand,
     #[clap(short, default_value = "data.yaml")]
     data: std::path::PathBuf,
+    /// fs, journal, dir, sqlite or git, which stores the backlog in the data file.
+    /// journal appends each save to a journal beside the data file,
+    /// dir takes the data as a directory with a file per item, and
+    /// git commits every save into the repository which contains the data file.
+    #[clap(long, default_value = "fs")]
+    repository: RepositoryKind,
+    /// json, yaml or toml for the fs, journal, dir and git repositories. The extension of the data file chooses it by default.
+    #[clap(long)]
+    format: Option<DataFormat>,
+    /// The author of the commits of the git repository, e.g. "Alice Liddell <alice@example.com>".
+    /// The user configured in git by default.
+    #[clap(long)]
+    author: Option<Author>,
+    /// The number of the backups of the data file kept by each save.
+    #[clap(long, default_value = "5")]
+    backups: usize,
+    /// The number of the entries of the journal which are compacted into the data file.
+    #[clap(long, default_value = "100")]
+    compact_after: usize,
+    /// The seconds to wait for the other rjira holding the data file, up to a day.
+    #[clap(long, default_value = "5", parse(try_from_str = parse_lock_timeout))]
+    lock_timeout: Duration,
+    /// Write the notifications to the watchers into this directory.
+    #[clap(long)]
+    mailbox: Option<PathBuf>,
+    /// POST the notifications to the watchers to this http url. Repeatable.
+    #[clap(long, multiple_occurrences = true)]
+    webhook: Vec<WebhookNotifier>,
 }
 
 impl Args {
@@ -54,19 +172,209 @@ impl Args {
         self.data.clone()
     }
 
+    /// The repository of the data file, configured by the options.
+    pub fn repository(&self) -> Arc<dyn BacklogRepository + Send + Sync> {
+        match self.repository {
+            RepositoryKind::Fs => {
+                let mut fs = FsBacklogRepository::new(self.data())
+                    .with_backups(self.backups)
+                    .with_lock_timeout(self.lock_timeout);
+                if let Some(format) = self.format {
+                    fs = fs.with_format(format);
+                }
+                Arc::new(fs)
+            }
+            RepositoryKind::Journal => {
+                let mut journal = JournalBacklogRepository::new(self.data())
+                    .with_compact_after(self.compact_after)
+                    .with_lock_timeout(self.lock_timeout);
+                if let Some(format) = self.format {
+                    journal = journal.with_format(format);
+                }
+                Arc::new(journal)
+            }
+            RepositoryKind::Dir => {
+                let mut dir =
+                    DirBacklogRepository::new(self.data()).with_lock_timeout(self.lock_timeout);
+                if let Some(format) = self.format {
+                    dir = dir.with_format(format);
+                }
+                Arc::new(dir)
+            }
+            RepositoryKind::Sqlite => Arc::new(
+                SqliteBacklogRepository::new(self.data()).with_busy_timeout(self.lock_timeout),
+            ),
+            RepositoryKind::Git => {
+                let mut git =
+                    GitBacklogRepository::new(self.data()).with_lock_timeout(self.lock_timeout);
+                if let Some(format) = self.format {
+                    git = git.with_format(format);
+                }
+                if let Some(author) = &self.author {
+                    git = git.with_author(author.clone());
+                }
+                Arc::new(git)
+            }
+        }
+    }
+
+    pub fn notifiers(&self) -> Notifiers {
+        let mut notifiers = Notifiers::new();
+        if let Some(dir) = &self.mailbox {
+            notifiers = notifiers.with(MailboxNotifier::new(dir.clone()));
+        }
+        for webhook in &self.webhook {
+            notifiers = notifiers.with(webhook.clone());
+        }
+        notifiers
+    }
+
     pub async fn run(&self, adaptors: CliAdaptoer) {
         match &self.command {
             SubCommand::GetBacklog => get_backlog_handler(adaptors).await,
             SubCommand::AddItem(cmd) => add_item_handler(adaptors, cmd.clone()).await,
             SubCommand::EstimateItem(cmd) => estimate_item_handler(adaptors, cmd.clone()).await,
             SubCommand::AssignItem(cmd) => assign_item_handler(adaptors, cmd.clone()).await,
+            SubCommand::PrioritizeItem(cmd) => prioritize_item_handler(adaptors, cmd.clone()).await,
+            SubCommand::RankItem(cmd) => rank_item_handler(adaptors, cmd.clone()).await,
+            SubCommand::SplitItem(cmd) => split_item_handler(adaptors, cmd.clone()).await,
+            SubCommand::Attach(cmd) => attach_handler(adaptors, cmd.clone()).await,
+            SubCommand::Attachments(cmd) => attachments_handler(adaptors, cmd.clone()).await,
+            SubCommand::Watch(cmd) => watch_item_handler(adaptors, cmd.clone()).await,
+            SubCommand::Unwatch(cmd) => unwatch_item_handler(adaptors, cmd.clone()).await,
+            SubCommand::Label(cmd) => label_item_handler(adaptors, cmd.clone()).await,
+            SubCommand::Epic(cmd) => set_epic_handler(adaptors, cmd.clone()).await,
+            SubCommand::Link(cmd) => link_item_handler(adaptors, cmd.clone()).await,
+            SubCommand::ListItems(cmd) => list_items_handler(adaptors, cmd.clone()).await,
+            SubCommand::Bulk(cmd) => bulk_handler(adaptors, cmd.clone()).await,
+            SubCommand::FindDuplicates(cmd) => find_duplicates_handler(adaptors, cmd.clone()).await,
+            SubCommand::ItemKinds => item_kinds_handler().await,
+            SubCommand::Migrate(cmd) => migrate_handler(adaptors, cmd.clone()).await,
+            SubCommand::Backups => backups_handler(adaptors).await,
+            SubCommand::Restore(cmd) => restore_handler(adaptors, cmd.clone()).await,
+            SubCommand::Convert(cmd) => convert_handler(adaptors, cmd.clone()).await,
+            SubCommand::History(cmd) => history_handler(adaptors, cmd.clone()).await,
+            SubCommand::Diff(cmd) => diff_handler(adaptors, cmd.clone()).await,
+            SubCommand::Velocity(cmd) => velocity_handler(adaptors, cmd.clone()).await,
+            SubCommand::Burndown(cmd) => burndown_handler(adaptors, cmd.clone()).await,
+            SubCommand::Sprint(cmd) => sprint_handler(adaptors, cmd.clone()).await,
+            SubCommand::Forecast(cmd) => forecast_handler(adaptors, cmd.clone()).await,
+            SubCommand::Directory => get_directory_handler(adaptors).await,
+            SubCommand::User(cmd) => user_handler(adaptors, cmd.clone()).await,
+            SubCommand::Team(cmd) => team_handler(adaptors, cmd.clone()).await,
+            SubCommand::Board(cmd) => board_handler(adaptors, cmd.clone()).await,
+            SubCommand::Release(cmd) => release_handler(adaptors, cmd.clone()).await,
         }
     }
 }
+
+/// The longest lock timeout, which keeps the seconds in the range of `Duration`.
+const MAX_LOCK_TIMEOUT_SECS: f64 = 24.0 * 60.0 * 60.0;
+
+/// Parse the seconds of the lock timeout, which are neither negative nor longer than a day.
+fn parse_lock_timeout(s: &str) -> Result<Duration, String> {
+    let secs: f64 = s
+        .parse()
+        .map_err(|err| format!("the lock timeout must be the seconds, {}, {}", err, s))?;
+    if !secs.is_finite() || !(0.0..=MAX_LOCK_TIMEOUT_SECS).contains(&secs) {
+        return Err(format!(
+            "the lock timeout must be from 0 to {} seconds, {}",
+            MAX_LOCK_TIMEOUT_SECS, s
+        ));
+    }
+    Ok(Duration::from_secs_f64(secs))
+}
+
+/// The kinds of the repository which stores the backlog.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RepositoryKind {
+    Fs,
+    Journal,
+    Dir,
+    Sqlite,
+    Git,
+}
+
+impl FromStr for RepositoryKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fs" => Ok(Self::Fs),
+            "journal" => Ok(Self::Journal),
+            "dir" => Ok(Self::Dir),
+            "sqlite" => Ok(Self::Sqlite),
+            "git" => Ok(Self::Git),
+            _ => Err(format!(
+                "the repository must be fs, journal, dir, sqlite or git, {}",
+                s
+            )),
+        }
+    }
+}
+
 #[derive(clap::Subcommand, Debug)]
 pub enum SubCommand {
     GetBacklog,
     AddItem(AddItemCliCmd),
     EstimateItem(EstimateItemCliCmd),
     AssignItem(AssignItemCliCmd),
+    /// Change the priority of the item.
+    PrioritizeItem(PrioritizeItemCliCmd),
+    /// Move the item in the ranking.
+    RankItem(RankItemCliCmd),
+    /// Split the story into smaller stories.
+    SplitItem(SplitItemCliCmd),
+    /// Attach the file to the item.
+    Attach(AttachCliCmd),
+    /// List the attachments of the item, or download or delete one.
+    Attachments(AttachmentsCliCmd),
+    /// Notify the user of every change to the item.
+    Watch(WatchItemCliCmd),
+    /// Stop notifying the user of changes to the item.
+    Unwatch(WatchItemCliCmd),
+    /// Replace the labels of the story.
+    Label(LabelItemCliCmd),
+    /// Group the story under another item as its epic.
+    Epic(SetEpicCliCmd),
+    /// Replace the items which the story is related to.
+    Link(LinkItemCliCmd),
+    /// List the items sorted by rank or priority.
+    ListItems(ListItemsCliCmd),
+    /// Apply one change to many items.
+    Bulk(BulkCliCmd),
+    /// List the groups of items which look like each other.
+    FindDuplicates(FindDuplicatesCliCmd),
+    /// List the kinds of items that can be added.
+    ItemKinds,
+    /// Upgrade the data file to the current format.
+    Migrate(MigrateCliCmd),
+    /// List the backups of the data file, the newest first.
+    Backups,
+    /// Replace the data file with the backup.
+    Restore(RestoreCliCmd),
+    /// Write the backlog into another data file, e.g. in another format.
+    Convert(ConvertCliCmd),
+    /// List the saves recorded in the history of the git repository, the newest first.
+    History(HistoryCliCmd),
+    /// Show how the data file has changed between the revisions of the history.
+    Diff(DiffCliCmd),
+    /// Report the velocity of each team.
+    Velocity(VelocityCliCmd),
+    /// Draw the burndown chart of the sprint.
+    Burndown(BurndownCliCmd),
+    /// List the sprints, or add them and plan the items into them.
+    Sprint(SprintCliCmd),
+    /// Forecast the completion of the backlog by Monte Carlo simulation.
+    Forecast(ForecastCliCmd),
+    /// Show the users and teams.
+    Directory,
+    /// Manage the users who can be assigned.
+    User(UserCliCmd),
+    /// Manage the teams.
+    Team(TeamCliCmd),
+    /// Show the board, or move items across its columns.
+    Board(BoardCliCmd),
+    /// List the releases, or manage them and their items.
+    Release(ReleaseCliCmd),
 }
@@ -0,0 +1,63 @@
+use backlog::{BacklogItem, ItemOrder};
+use backlog_service::{BacklogUseCase, Command, IncommingError, IncommingResult, ListItemsCmd};
+
+use super::{print_handler, CliAdaptoer};
+use crate::output::{render_table, OutputFormat, TableView};
+
+pub async fn list_items_handler(ctx: CliAdaptoer, cmd: ListItemsCliCmd) {
+    let format = cmd.format;
+    print_handler(|| async move {
+        let items = ctx.list_items(cmd).await?;
+        format.render(&items)
+    })
+    .await
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct ListItemsCliCmd {
+    /// rank, or priority to sort by priority and then rank
+    #[clap(short, long, default_value = "rank")]
+    sort: String,
+    /// json or table
+    #[clap(short, long, default_value = "table")]
+    format: OutputFormat,
+}
+
+impl Command for ListItemsCliCmd {}
+
+impl ListItemsCmd for ListItemsCliCmd {
+    fn order(&self) -> IncommingResult<ItemOrder> {
+        self.sort.parse().map_err(|err: backlog::BacklogError| {
+            IncommingError::invalid_value("sort", err.to_string())
+        })
+    }
+}
+
+impl TableView for Vec<Box<dyn BacklogItem>> {
+    fn table(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .iter()
+            .map(|item| {
+                vec![
+                    item.id().to_string(),
+                    item.kind().to_string(),
+                    format!("{:?}", item.priority()),
+                    item.title().value().to_string(),
+                    item.point()
+                        .map(|p| p.value().to_string())
+                        .unwrap_or_default(),
+                    item.assignee()
+                        .map(|a| a.name().to_string())
+                        .unwrap_or_default(),
+                    format!("{:?}", item.status()),
+                ]
+            })
+            .collect();
+        render_table(
+            &[
+                "id", "type", "priority", "title", "point", "assignee", "status",
+            ],
+            &rows,
+        )
+    }
+}
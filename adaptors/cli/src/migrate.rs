@@ -0,0 +1,22 @@
+use backlog_service::{BacklogUseCase, Command, IncommingResult, MigrateCmd};
+
+use super::{print_handler, CliAdaptoer};
+
+pub async fn migrate_handler(ctx: CliAdaptoer, cmd: MigrateCliCmd) {
+    print_handler(|| ctx.migrate(cmd)).await
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct MigrateCliCmd {
+    /// Show what would change without writing the data file.
+    #[clap(long)]
+    dry_run: bool,
+}
+
+impl Command for MigrateCliCmd {}
+
+impl MigrateCmd for MigrateCliCmd {
+    fn dry_run(&self) -> IncommingResult<bool> {
+        Ok(self.dry_run)
+    }
+}
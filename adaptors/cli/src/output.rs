@@ -0,0 +1,68 @@
+use std::str::FromStr;
+
+use serde::Serialize;
+
+/// The format of reports printed to the terminal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Json,
+    Table,
+}
+
+impl OutputFormat {
+    pub fn render<T: Serialize + TableView>(&self, value: &T) -> eyre::Result<String> {
+        match self {
+            Self::Json => Ok(serde_json::to_string_pretty(value)?),
+            Self::Table => Ok(value.table()),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "table" => Ok(Self::Table),
+            _ => Err(format!("the format must be json or table, {}", s)),
+        }
+    }
+}
+
+/// It can be rendered as a plain text table.
+pub trait TableView {
+    fn table(&self) -> String;
+}
+
+/// render rows with columns padded to the widest cell.
+pub fn render_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.len()).collect();
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: Vec<String>| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![line(header.iter().map(|h| h.to_string()).collect())];
+    lines.push(
+        widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<_>>()
+            .join("-+-"),
+    );
+    lines.extend(rows.iter().map(|row| line(row.clone())));
+    lines.join("\n")
+}
@@ -0,0 +1,30 @@
+use backlog::{Priority, Uuid};
+use backlog_service::{
+    BacklogUseCase, Command, IncommingError, IncommingResult, PrioritizeItemCmd,
+};
+
+use super::{error_handler, CliAdaptoer};
+
+pub async fn prioritize_item_handler(ctx: CliAdaptoer, cmd: PrioritizeItemCliCmd) {
+    error_handler(|| ctx.prioritize_item(cmd)).await
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct PrioritizeItemCliCmd {
+    id: Uuid,
+    /// Highest, High, Medium, Low or Lowest
+    priority: String,
+}
+
+impl Command for PrioritizeItemCliCmd {}
+
+impl PrioritizeItemCmd for PrioritizeItemCliCmd {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+    fn priority(&self) -> IncommingResult<Priority> {
+        self.priority.parse().map_err(|err: backlog::BacklogError| {
+            IncommingError::invalid_value("priority", err.to_string())
+        })
+    }
+}
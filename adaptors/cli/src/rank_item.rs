@@ -0,0 +1,45 @@
+use backlog::{Placement, Uuid};
+use backlog_service::{BacklogUseCase, Command, IncommingError, IncommingResult, RankItemCmd};
+
+use super::{error_handler, CliAdaptoer};
+
+pub async fn rank_item_handler(ctx: CliAdaptoer, cmd: RankItemCliCmd) {
+    error_handler(|| ctx.rank_item(cmd)).await
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct RankItemCliCmd {
+    id: Uuid,
+    /// Move the item to the top.
+    #[clap(long, conflicts_with_all = &["bottom", "after", "before"])]
+    top: bool,
+    /// Move the item to the bottom.
+    #[clap(long, conflicts_with_all = &["after", "before"])]
+    bottom: bool,
+    /// Move the item right after the item.
+    #[clap(long, conflicts_with = "before")]
+    after: Option<Uuid>,
+    /// Move the item right before the item.
+    #[clap(long)]
+    before: Option<Uuid>,
+}
+
+impl Command for RankItemCliCmd {}
+
+impl RankItemCmd for RankItemCliCmd {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+    fn placement(&self) -> IncommingResult<Placement> {
+        match (self.top, self.bottom, self.after, self.before) {
+            (true, _, _, _) => Ok(Placement::Top),
+            (_, true, _, _) => Ok(Placement::Bottom),
+            (_, _, Some(after), _) => Ok(Placement::After(after)),
+            (_, _, _, Some(before)) => Ok(Placement::Before(before)),
+            _ => Err(IncommingError::invalid_value(
+                "placement",
+                "one of --top, --bottom, --after or --before is required",
+            )),
+        }
+    }
+}
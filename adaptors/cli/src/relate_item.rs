@@ -0,0 +1,72 @@
+use backlog::Uuid;
+use backlog_service::{
+    BacklogUseCase, Command, IncommingResult, LabelItemCmd, LinkItemCmd, SetEpicCmd,
+};
+
+use super::{error_handler, CliAdaptoer};
+
+pub async fn label_item_handler(ctx: CliAdaptoer, cmd: LabelItemCliCmd) {
+    error_handler(|| ctx.label_item(cmd)).await
+}
+
+pub async fn set_epic_handler(ctx: CliAdaptoer, cmd: SetEpicCliCmd) {
+    error_handler(|| ctx.set_epic(cmd)).await
+}
+
+pub async fn link_item_handler(ctx: CliAdaptoer, cmd: LinkItemCliCmd) {
+    error_handler(|| ctx.link_item(cmd)).await
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct LabelItemCliCmd {
+    id: Uuid,
+    /// the labels which replace the current ones, none to remove them all.
+    labels: Vec<String>,
+}
+
+impl Command for LabelItemCliCmd {}
+
+impl LabelItemCmd for LabelItemCliCmd {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+    fn labels(&self) -> IncommingResult<Vec<String>> {
+        Ok(self.labels.clone())
+    }
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct SetEpicCliCmd {
+    id: Uuid,
+    /// the item to group it under, or none to ungroup it.
+    epic: Option<Uuid>,
+}
+
+impl Command for SetEpicCliCmd {}
+
+impl SetEpicCmd for SetEpicCliCmd {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+    fn epic(&self) -> IncommingResult<Option<Uuid>> {
+        Ok(self.epic)
+    }
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct LinkItemCliCmd {
+    id: Uuid,
+    /// the related items which replace the current ones, none to unlink them all.
+    links: Vec<Uuid>,
+}
+
+impl Command for LinkItemCliCmd {}
+
+impl LinkItemCmd for LinkItemCliCmd {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+    fn links(&self) -> IncommingResult<Vec<Uuid>> {
+        Ok(self.links.clone())
+    }
+}
@@ -0,0 +1,147 @@
+use backlog::{NaiveDate, Release, ReleaseNotes, UnresolvedItems, Uuid};
+use backlog_service::{
+    AddReleaseCmd, BacklogUseCase, Command, IncommingError, IncommingResult, ReleaseCmd,
+    SetFixVersionCmd,
+};
+
+use super::{error_handler, print_handler, CliAdaptoer};
+use crate::output::{render_table, OutputFormat, TableView};
+
+pub async fn release_handler(ctx: CliAdaptoer, cmd: ReleaseCliCmd) {
+    let format = cmd.format;
+    match cmd.command {
+        None => {
+            print_handler(|| async move {
+                let releases = ctx.get_releases().await?;
+                format.render(&releases)
+            })
+            .await
+        }
+        Some(ReleaseSubCommand::Add(cmd)) => error_handler(|| ctx.add_release(cmd)).await,
+        Some(ReleaseSubCommand::Assign(cmd)) => error_handler(|| ctx.set_fix_version(cmd)).await,
+        Some(ReleaseSubCommand::Notes(cmd)) => {
+            print_handler(|| async move {
+                let notes = ctx.release_notes(cmd).await?;
+                format.render(&notes)
+            })
+            .await
+        }
+        Some(ReleaseSubCommand::Unresolved(cmd)) => {
+            print_handler(|| async move {
+                let unresolved = ctx.unresolved_items(cmd).await?;
+                format.render(&unresolved)
+            })
+            .await
+        }
+        Some(ReleaseSubCommand::Ship(cmd)) => error_handler(|| ctx.ship_release(cmd)).await,
+    }
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct ReleaseCliCmd {
+    #[clap(subcommand)]
+    command: Option<ReleaseSubCommand>,
+    /// json or table. The release notes are rendered as markdown by table.
+    #[clap(short, long, default_value = "table")]
+    format: OutputFormat,
+}
+
+#[derive(Clone, Debug, clap::Subcommand)]
+pub enum ReleaseSubCommand {
+    /// Add the release.
+    Add(AddReleaseCliCmd),
+    /// Target the item at the release. Omit the release to clear it.
+    Assign(SetFixVersionCliCmd),
+    /// Make the notes of the done items, grouped by type.
+    Notes(ReleaseNameCliCmd),
+    /// List the items of the release which are not done yet.
+    Unresolved(ReleaseNameCliCmd),
+    /// Mark the release as shipped.
+    Ship(ReleaseNameCliCmd),
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct AddReleaseCliCmd {
+    name: String,
+    /// YYYY-MM-DD
+    target: NaiveDate,
+}
+
+impl Command for AddReleaseCliCmd {}
+
+impl AddReleaseCmd for AddReleaseCliCmd {
+    fn release(&self) -> IncommingResult<Release> {
+        Release::new(&self.name, self.target)
+            .map_err(|err| IncommingError::invalid_value("name", err.to_string()))
+    }
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct SetFixVersionCliCmd {
+    id: Uuid,
+    release: Option<String>,
+}
+
+impl Command for SetFixVersionCliCmd {}
+
+impl SetFixVersionCmd for SetFixVersionCliCmd {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn release(&self) -> IncommingResult<Option<String>> {
+        Ok(self.release.clone())
+    }
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct ReleaseNameCliCmd {
+    name: String,
+}
+
+impl Command for ReleaseNameCliCmd {}
+
+impl ReleaseCmd for ReleaseNameCliCmd {
+    fn name(&self) -> IncommingResult<String> {
+        Ok(self.name.clone())
+    }
+}
+
+impl TableView for Vec<Release> {
+    fn table(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .iter()
+            .map(|release| {
+                vec![
+                    release.name().to_string(),
+                    release.target().to_string(),
+                    if release.is_released() { "yes" } else { "no" }.to_string(),
+                ]
+            })
+            .collect();
+        render_table(&["name", "target", "released"], &rows)
+    }
+}
+
+impl TableView for ReleaseNotes {
+    fn table(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl TableView for UnresolvedItems {
+    fn table(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .items()
+            .iter()
+            .map(|item| {
+                vec![
+                    item.id().to_string(),
+                    item.title().to_string(),
+                    format!("{:?}", item.status()),
+                ]
+            })
+            .collect();
+        render_table(&["id", "title", "status"], &rows)
+    }
+}
@@ -0,0 +1,38 @@
+use backlog::{SplitInto, Uuid};
+use backlog_service::{BacklogUseCase, Command, IncommingError, IncommingResult, SplitItemCmd};
+
+use super::{error_handler, CliAdaptoer};
+
+pub async fn split_item_handler(ctx: CliAdaptoer, cmd: SplitItemCliCmd) {
+    error_handler(|| ctx.split_item(cmd)).await
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct SplitItemCliCmd {
+    id: Uuid,
+    /// The title of the new item. Repeat it for each item.
+    #[clap(long = "title", conflicts_with = "count")]
+    titles: Vec<String>,
+    /// Split the item into this many items titled after the original.
+    #[clap(long)]
+    count: Option<usize>,
+}
+
+impl Command for SplitItemCliCmd {}
+
+impl SplitItemCmd for SplitItemCliCmd {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn split_into(&self) -> IncommingResult<SplitInto> {
+        match (self.count, self.titles.is_empty()) {
+            (Some(count), _) => Ok(SplitInto::Count(count)),
+            (None, false) => Ok(SplitInto::Titles(self.titles.clone())),
+            (None, true) => Err(IncommingError::invalid_value(
+                "into",
+                "either --title or --count is required",
+            )),
+        }
+    }
+}
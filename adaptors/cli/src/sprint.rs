@@ -0,0 +1,94 @@
+use backlog::{Entity, NaiveDate, Sprint, Team, Uuid};
+use backlog_service::{
+    AddSprintCmd, BacklogUseCase, Command, IncommingError, IncommingResult, PlanItemCmd,
+};
+
+use super::{error_handler, print_handler, CliAdaptoer};
+use crate::output::{render_table, OutputFormat, TableView};
+
+pub async fn sprint_handler(ctx: CliAdaptoer, cmd: SprintCliCmd) {
+    let format = cmd.format;
+    match cmd.command {
+        None => {
+            print_handler(|| async move {
+                let sprints = ctx.get_sprints().await?;
+                format.render(&sprints)
+            })
+            .await
+        }
+        Some(SprintSubCommand::Add(cmd)) => error_handler(|| ctx.add_sprint(cmd)).await,
+        Some(SprintSubCommand::Plan(cmd)) => error_handler(|| ctx.plan_item(cmd)).await,
+    }
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct SprintCliCmd {
+    #[clap(subcommand)]
+    command: Option<SprintSubCommand>,
+    /// json or table.
+    #[clap(short, long, default_value = "table")]
+    format: OutputFormat,
+}
+
+#[derive(Clone, Debug, clap::Subcommand)]
+pub enum SprintSubCommand {
+    /// Add the sprint of the team.
+    Add(AddSprintCliCmd),
+    /// Plan the item into the sprint. Omit the sprint to move it out.
+    Plan(PlanItemCliCmd),
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct AddSprintCliCmd {
+    name: String,
+    team: String,
+    /// YYYY-MM-DD
+    start: NaiveDate,
+    /// YYYY-MM-DD, the last day of the sprint.
+    end: NaiveDate,
+}
+
+impl Command for AddSprintCliCmd {}
+
+impl AddSprintCmd for AddSprintCliCmd {
+    fn sprint(&self) -> IncommingResult<Sprint> {
+        Sprint::new(&self.name, Team::new(&self.team), self.start, self.end)
+            .map_err(|err| IncommingError::invalid_value("end", err.to_string()))
+    }
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct PlanItemCliCmd {
+    id: Uuid,
+    sprint: Option<Uuid>,
+}
+
+impl Command for PlanItemCliCmd {}
+
+impl PlanItemCmd for PlanItemCliCmd {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn sprint(&self) -> IncommingResult<Option<Uuid>> {
+        Ok(self.sprint)
+    }
+}
+
+impl TableView for Vec<Sprint> {
+    fn table(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .iter()
+            .map(|sprint| {
+                vec![
+                    sprint.id().to_string(),
+                    sprint.name().to_string(),
+                    sprint.team().name().to_string(),
+                    sprint.start().to_string(),
+                    sprint.end().to_string(),
+                ]
+            })
+            .collect();
+        render_table(&["id", "name", "team", "start", "end"], &rows)
+    }
+}
@@ -0,0 +1,57 @@
+use backlog::VelocityReport;
+use backlog_service::{BacklogUseCase, Command, IncommingResult, VelocityCmd};
+
+use super::{print_handler, CliAdaptoer};
+use crate::output::{render_table, OutputFormat, TableView};
+
+pub async fn velocity_handler(ctx: CliAdaptoer, cmd: VelocityCliCmd) {
+    let format = cmd.format;
+    print_handler(|| async move {
+        let report = ctx.velocity(cmd).await?;
+        format.render(&report)
+    })
+    .await
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct VelocityCliCmd {
+    /// The number of sprints used by the rolling average.
+    #[clap(short, long, default_value = "3")]
+    window: usize,
+    /// json or table
+    #[clap(short, long, default_value = "table")]
+    format: OutputFormat,
+}
+
+impl Command for VelocityCliCmd {}
+
+impl VelocityCmd for VelocityCliCmd {
+    fn window(&self) -> IncommingResult<usize> {
+        Ok(self.window)
+    }
+}
+
+impl TableView for VelocityReport {
+    fn table(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .teams()
+            .iter()
+            .flat_map(|team| {
+                team.sprints().iter().map(move |sprint| {
+                    vec![
+                        team.team().name().to_string(),
+                        sprint.name().to_string(),
+                        sprint.end().to_string(),
+                        sprint.points().to_string(),
+                        format!("{:.1}", sprint.rolling_average()),
+                        format!("{:.1}", sprint.rolling_std_dev()),
+                    ]
+                })
+            })
+            .collect();
+        render_table(
+            &["team", "sprint", "end", "points", "average", "std dev"],
+            &rows,
+        )
+    }
+}
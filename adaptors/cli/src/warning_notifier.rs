@@ -0,0 +1,22 @@
+use backlog::Notification;
+use notifier::{Notifier, NotifierResult, Notifiers};
+
+/// Warn of the notifications which fail to be delivered, because the change has already been saved.
+#[derive(Clone, Debug, Default)]
+pub struct WarningNotifier(Notifiers);
+
+impl WarningNotifier {
+    pub fn new(notifiers: Notifiers) -> Self {
+        Self(notifiers)
+    }
+}
+
+#[async_trait::async_trait]
+impl Notifier for WarningNotifier {
+    async fn notify(&self, notification: &Notification) -> NotifierResult<()> {
+        if let Err(err) = self.0.notify(notification).await {
+            eprintln!("warning: fail to notify the watchers, {}", err);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use backlog::{Assignee, Uuid};
+use backlog_service::{BacklogUseCase, Command, IncommingResult, WatchItemCmd};
+
+use super::{error_handler, CliAdaptoer};
+
+pub async fn watch_item_handler(ctx: CliAdaptoer, cmd: WatchItemCliCmd) {
+    error_handler(|| ctx.watch_item(cmd)).await
+}
+
+pub async fn unwatch_item_handler(ctx: CliAdaptoer, cmd: WatchItemCliCmd) {
+    error_handler(|| ctx.unwatch_item(cmd)).await
+}
+
+#[derive(Clone, Debug, clap::Parser)]
+pub struct WatchItemCliCmd {
+    id: Uuid,
+    watcher: String,
+}
+
+impl Command for WatchItemCliCmd {}
+
+impl WatchItemCmd for WatchItemCliCmd {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+    fn watcher(&self) -> IncommingResult<Assignee> {
+        Ok(Assignee::new(&self.watcher))
+    }
+}
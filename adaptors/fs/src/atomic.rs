@@ -0,0 +1,63 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use backlog_repo::{BacklogRepositoryError, BacklogRepositoryResult};
+
+/// Write a temporary file, sync it, and rename it over the file.
+pub fn write_file(path: &Path, content: &str) -> BacklogRepositoryResult<()> {
+    write_file_with(path, content, || Ok(()))
+}
+
+/// Write the file as `write_file` does, and run `before_rename` once the temporary file is synced,
+/// e.g. to rotate the backups of the file which is about to be replaced.
+///
+/// If `before_rename` fails, the file is left as it is.
+pub(crate) fn write_file_with<F>(
+    path: &Path,
+    content: &str,
+    before_rename: F,
+) -> BacklogRepositoryResult<()>
+where
+    F: FnOnce() -> BacklogRepositoryResult<()>,
+{
+    let tmp = path.with_file_name(format!(
+        ".{}.{}.tmp",
+        path.file_name().unwrap_or_default().to_string_lossy(),
+        std::process::id()
+    ));
+    let written = File::create(&tmp)
+        .map_err(BacklogRepositoryError::from)
+        .and_then(|mut file| {
+            file.write_all(content.as_bytes())?;
+            file.sync_all()?;
+            Ok(())
+        })
+        .and_then(|_| before_rename());
+    if let Err(err) = written {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(err);
+    }
+    std::fs::rename(&tmp, path)?;
+    sync_parent(path)?;
+    Ok(())
+}
+
+/// make the change of the entry of `path` in its directory durable.
+pub(crate) fn sync_parent(path: &Path) -> std::io::Result<()> {
+    match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => sync_dir(dir),
+        _ => sync_dir(Path::new(".")),
+    }
+}
+
+/// make the rename durable.
+#[cfg(unix)]
+pub(crate) fn sync_dir(dir: &Path) -> std::io::Result<()> {
+    File::open(dir)?.sync_all()
+}
+
+#[cfg(not(unix))]
+pub(crate) fn sync_dir(_dir: &Path) -> std::io::Result<()> {
+    Ok(())
+}
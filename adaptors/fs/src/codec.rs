@@ -0,0 +1,177 @@
+use std::fmt::Debug;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use backlog::Backlog;
+use backlog_repo::{BacklogRepositoryError, BacklogRepositoryResult};
+
+/// Reads and writes the data file in a specific format.
+///
+/// The stored document is decoded into a json value first, so that any format can be migrated.
+pub trait Codec: Debug + Send + Sync {
+    /// Decode the document as it is stored, before it is migrated.
+    fn document(&self, content: &str) -> BacklogRepositoryResult<serde_json::Value>;
+
+    /// Decode the backlog from the text itself, which locates the invalid content.
+    fn decode(&self, content: &str) -> BacklogRepositoryResult<Backlog>;
+
+    fn encode(&self, backlog: &Backlog) -> BacklogRepositoryResult<String>;
+
+    /// Encode a part of the document, e.g. an item stored in a file of its own.
+    fn encode_document(&self, document: &serde_json::Value) -> BacklogRepositoryResult<String>;
+}
+
+/// The formats of the data file supported out of the box.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl DataFormat {
+    /// Choose the format by the extension of the file, e.g. `yaml` or `yml` for YAML.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension()?.to_str()?.parse().ok()
+    }
+
+    /// The extension of the files written in the format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Yaml => "yaml",
+            Self::Toml => "toml",
+        }
+    }
+
+    pub fn codec(self) -> Arc<dyn Codec> {
+        match self {
+            Self::Json => Arc::new(JsonCodec),
+            Self::Yaml => Arc::new(YamlCodec),
+            Self::Toml => Arc::new(TomlCodec),
+        }
+    }
+}
+
+impl FromStr for DataFormat {
+    type Err = BacklogRepositoryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "yaml" | "yml" => Ok(Self::Yaml),
+            "toml" => Ok(Self::Toml),
+            _ => Err(BacklogRepositoryError::UnknownFormat(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct JsonCodec;
+
+impl Codec for JsonCodec {
+    fn document(&self, content: &str) -> BacklogRepositoryResult<serde_json::Value> {
+        serde_json::from_str(content).map_err(json_error)
+    }
+
+    fn decode(&self, content: &str) -> BacklogRepositoryResult<Backlog> {
+        serde_json::from_str(content).map_err(json_error)
+    }
+
+    fn encode(&self, backlog: &Backlog) -> BacklogRepositoryResult<String> {
+        Ok(serde_json::to_string_pretty(backlog)? + "\n")
+    }
+
+    fn encode_document(&self, document: &serde_json::Value) -> BacklogRepositoryResult<String> {
+        Ok(serde_json::to_string_pretty(document)? + "\n")
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct YamlCodec;
+
+impl Codec for YamlCodec {
+    fn document(&self, content: &str) -> BacklogRepositoryResult<serde_json::Value> {
+        serde_yaml::from_str(content).map_err(yaml_error)
+    }
+
+    fn decode(&self, content: &str) -> BacklogRepositoryResult<Backlog> {
+        serde_yaml::from_str(content).map_err(yaml_error)
+    }
+
+    fn encode(&self, backlog: &Backlog) -> BacklogRepositoryResult<String> {
+        Ok(serde_yaml::to_string(backlog)?)
+    }
+
+    fn encode_document(&self, document: &serde_json::Value) -> BacklogRepositoryResult<String> {
+        Ok(serde_yaml::to_string(document)?)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TomlCodec;
+
+impl Codec for TomlCodec {
+    fn document(&self, content: &str) -> BacklogRepositoryResult<serde_json::Value> {
+        toml::from_str(content).map_err(toml_error)
+    }
+
+    fn decode(&self, content: &str) -> BacklogRepositoryResult<Backlog> {
+        toml::from_str(content).map_err(toml_error)
+    }
+
+    /// TOML has no null, so the missing values are left out,
+    /// and the plain values are written before the tables, as TOML requires.
+    fn encode(&self, backlog: &Backlog) -> BacklogRepositoryResult<String> {
+        self.encode_document(&serde_json::to_value(backlog)?)
+    }
+
+    fn encode_document(&self, document: &serde_json::Value) -> BacklogRepositoryResult<String> {
+        let value = toml::Value::try_from(without_nulls(document.clone()))?;
+        Ok(toml::to_string(&value)?)
+    }
+}
+
+fn without_nulls(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (key, without_nulls(value)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(without_nulls).collect()),
+        value => value,
+    }
+}
+
+fn json_error(err: serde_json::Error) -> BacklogRepositoryError {
+    let located = err.line() > 0;
+    BacklogRepositoryError::parse(
+        Some(err.line()).filter(|_| located),
+        Some(err.column()).filter(|_| located),
+        err.to_string(),
+    )
+}
+
+fn yaml_error(err: serde_yaml::Error) -> BacklogRepositoryError {
+    let location = err.location();
+    BacklogRepositoryError::parse(
+        location.as_ref().map(|l| l.line()),
+        location.as_ref().map(|l| l.column()),
+        err.to_string(),
+    )
+}
+
+/// toml counts the line and the column from 0.
+fn toml_error(err: toml::de::Error) -> BacklogRepositoryError {
+    let location = err.line_col();
+    BacklogRepositoryError::parse(
+        location.map(|(line, _)| line + 1),
+        location.map(|(_, column)| column + 1),
+        err.to_string(),
+    )
+}
@@ -0,0 +1,417 @@
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use backlog::{Backlog, BacklogItem, MigrationReport, Uuid, FORMAT_VERSION};
+use backlog_repo::{
+    BacklogLock, BacklogRepository, BacklogRepositoryError, BacklogRepositoryResult, LockMode,
+};
+use serde_json::{Map, Value};
+
+use crate::atomic::write_file;
+use crate::{lock_file, DataFormat, FsBacklogRepository};
+
+/// Stores the backlog as a directory, so that the changes of different items never conflict
+/// when the branches of a version control are merged.
+///
+/// ```text
+/// backlog/
+///   index.yaml      the order of the items, and everything but the items.
+///   items/
+///     <id>.yaml     an item.
+/// ```
+///
+/// The item whose file is not listed in the index is still loaded, after the listed ones,
+/// and the listed item without its file is left out,
+/// so a conflict in the index can be resolved by taking either side.
+///
+/// The directory is refused while `backlog.journal` beside it has the saves of
+/// `JournalBacklogRepository`, which the directory does not have.
+///
+/// The items are loaded lazily: `get` reads only the files changed since they were read last,
+/// and `get_item` reads the index and the file of the item alone.
+#[derive(Debug, Clone)]
+pub struct DirBacklogRepository {
+    dir: PathBuf,
+    format: DataFormat,
+    lock_timeout: Duration,
+    /// the items read so far, by their files, which are read again only when changed.
+    items: Arc<Mutex<HashMap<PathBuf, Loaded>>>,
+}
+
+#[derive(Debug)]
+struct Loaded {
+    /// the length and the modified time of the file when it was read.
+    stamp: (u64, SystemTime),
+    document: Value,
+}
+
+impl DirBacklogRepository {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            format: DataFormat::Yaml,
+            lock_timeout: FsBacklogRepository::LOCK_TIMEOUT,
+            items: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Read and write the files in `format`, which is also their extension.
+    pub fn with_format(self, format: DataFormat) -> Self {
+        Self { format, ..self }
+    }
+
+    /// Give up locking after `lock_timeout`, or at once with zero.
+    pub fn with_lock_timeout(self, lock_timeout: Duration) -> Self {
+        Self {
+            lock_timeout,
+            ..self
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// `backlog.journal` for `backlog/`.
+    fn journal_path(&self) -> PathBuf {
+        let name = self.dir.file_name().unwrap_or_default().to_string_lossy();
+        self.dir.with_file_name(format!("{}.journal", name))
+    }
+
+    /// Fail if the journal has any entry, which would be lost by reading or writing the directory.
+    fn ensure_no_journal(&self) -> BacklogRepositoryResult<()> {
+        let path = self.journal_path();
+        match std::fs::metadata(&path) {
+            Ok(metadata) if metadata.len() > 0 => Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "the journal has the saves which are not in the directory, {}",
+                    path.display()
+                ),
+            )
+            .into()),
+            Ok(_) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn index_path(&self) -> PathBuf {
+        self.dir.join(format!("index.{}", self.format.extension()))
+    }
+
+    fn items_dir(&self) -> PathBuf {
+        self.dir.join("items")
+    }
+
+    fn item_path(&self, id: &str) -> PathBuf {
+        self.items_dir()
+            .join(format!("{}.{}", id, self.format.extension()))
+    }
+
+    /// The ids of the items which have their files, in no particular order.
+    fn item_files(&self) -> BacklogRepositoryResult<Vec<String>> {
+        let entries = match std::fs::read_dir(self.items_dir()) {
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            entries => entries?,
+        };
+        let mut ids = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if DataFormat::from_path(&path) != Some(self.format) {
+                continue;
+            }
+            if let Some(id) = path.file_stem().and_then(|stem| stem.to_str()) {
+                ids.push(id.to_string());
+            }
+        }
+        Ok(ids)
+    }
+
+    fn read_to_string(path: &Path) -> BacklogRepositoryResult<Option<String>> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Ok(Some(content)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Read the index as it is stored, before it is migrated.
+    ///
+    /// A missing index means a new backlog, which is `None`.
+    fn index(&self) -> BacklogRepositoryResult<Option<Value>> {
+        match Self::read_to_string(&self.index_path())? {
+            None => Ok(None),
+            Some(content) if content.trim().is_empty() => Ok(None),
+            Some(content) => self.format.codec().document(&content).map(Some),
+        }
+    }
+
+    /// The length and the modified time of the file, or `None` without it.
+    fn stamp(path: &Path) -> BacklogRepositoryResult<Option<(u64, SystemTime)>> {
+        match std::fs::metadata(path) {
+            Ok(metadata) => Ok(Some((metadata.len(), metadata.modified()?))),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Read the item, whose file is read only if it has changed since it was read last.
+    fn item(&self, id: &str) -> BacklogRepositoryResult<Option<Value>> {
+        let path = self.item_path(id);
+        let stamp = match Self::stamp(&path)? {
+            None => return Ok(None),
+            Some(stamp) => stamp,
+        };
+        let mut items = self.items.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(loaded) = items.get(&path) {
+            if loaded.stamp == stamp {
+                return Ok(Some(loaded.document.clone()));
+            }
+        }
+        let content = match Self::read_to_string(&path)? {
+            None => return Ok(None),
+            Some(content) => content,
+        };
+        let document = self.format.codec().document(&content)?;
+        items.insert(
+            path,
+            Loaded {
+                stamp,
+                document: document.clone(),
+            },
+        );
+        Ok(Some(document))
+    }
+
+    /// Assemble the document of the whole backlog as it is stored, before it is migrated.
+    fn document(&self) -> BacklogRepositoryResult<Option<Value>> {
+        let mut document = match self.index()? {
+            None => return Ok(None),
+            Some(index) => index,
+        };
+        let mut ids: Vec<String> = match document.get("items").and_then(Value::as_array) {
+            Some(ids) => ids
+                .iter()
+                .filter_map(|id| id.as_str().map(str::to_string))
+                .collect(),
+            None => Vec::new(),
+        };
+        let mut unlisted: Vec<String> = self
+            .item_files()?
+            .into_iter()
+            .filter(|id| !ids.contains(id))
+            .collect();
+        unlisted.sort();
+        ids.extend(unlisted);
+
+        let mut items = Map::new();
+        for id in ids {
+            if let Some(item) = self.item(&id)? {
+                items.insert(id, item);
+            }
+        }
+        document["items"] = Value::Object(items);
+        Ok(Some(document))
+    }
+
+    fn read(&self) -> BacklogRepositoryResult<Backlog> {
+        let mut document = match self.document()? {
+            None => return Ok(Backlog::new()),
+            Some(document) => document,
+        };
+        backlog::migrate(&mut document)?;
+        serde_json::from_value(document)
+            .map_err(|err| BacklogRepositoryError::parse(None, None, err.to_string()))
+    }
+
+    /// Read the file again next time, even if it is rewritten within the resolution of its time.
+    fn forget(&self, path: &Path) {
+        let mut items = self.items.lock().unwrap_or_else(|err| err.into_inner());
+        items.remove(path);
+    }
+
+    /// Write the changed items and remove the files of the removed ones, then the index.
+    ///
+    /// The index is written last, so the revision is advanced only when all the items are.
+    fn write(&self, backlog: &Backlog) -> BacklogRepositoryResult<()> {
+        let codec = self.format.codec();
+        let mut document = serde_json::to_value(backlog)?;
+        let items = match document.get_mut("items").map(Value::take) {
+            Some(Value::Object(items)) => items,
+            _ => Map::new(),
+        };
+        std::fs::create_dir_all(self.items_dir())?;
+        for (id, item) in &items {
+            let path = self.item_path(id);
+            let content = codec.encode_document(item)?;
+            if Self::read_to_string(&path)?.as_deref() != Some(content.as_str()) {
+                self.forget(&path);
+                write_file(&path, &content)?;
+            }
+        }
+        for id in self.item_files()? {
+            if !items.contains_key(&id) {
+                let path = self.item_path(&id);
+                self.forget(&path);
+                std::fs::remove_file(path)?;
+            }
+        }
+        document["items"] = Value::Array(items.keys().cloned().map(Value::from).collect());
+        write_file(&self.index_path(), &codec.encode_document(&document)?)
+    }
+}
+
+#[async_trait::async_trait]
+impl BacklogRepository for DirBacklogRepository {
+    /// The lock is taken on `.lock` in the directory.
+    async fn lock(&self, mode: LockMode) -> BacklogRepositoryResult<BacklogLock> {
+        std::fs::create_dir_all(&self.dir)?;
+        lock_file(&self.dir.join(".lock"), mode, self.lock_timeout).await
+    }
+
+    async fn get(&self) -> BacklogRepositoryResult<Backlog> {
+        self.ensure_no_journal()?;
+        self.read()
+    }
+
+    /// Only the index and the file of the item are read,
+    /// unless the stored backlog is of an older format, which is migrated as a whole.
+    async fn get_item(&self, id: &Uuid) -> BacklogRepositoryResult<Option<Box<dyn BacklogItem>>> {
+        self.ensure_no_journal()?;
+        let index = match self.index()? {
+            None => return Ok(None),
+            Some(index) => index,
+        };
+        let version = index.get("format_version").and_then(Value::as_u64);
+        if version != Some(u64::from(FORMAT_VERSION)) {
+            return Ok(self.read()?.items().get(id).cloned());
+        }
+        match self.item(&id.to_string())? {
+            None => Ok(None),
+            Some(item) => serde_json::from_value(item)
+                .map(Some)
+                .map_err(|err| BacklogRepositoryError::parse(None, None, err.to_string())),
+        }
+    }
+
+    async fn save(&self, backlog: Backlog) -> BacklogRepositoryResult<()> {
+        self.ensure_no_journal()?;
+        let stored = self
+            .index()?
+            .and_then(|index| index.get("revision").and_then(Value::as_u64))
+            .unwrap_or_default();
+        if stored != backlog.revision() {
+            return Err(BacklogRepositoryError::Conflict {
+                loaded: backlog.revision(),
+                stored,
+            });
+        }
+        self.write(&backlog.with_revision(stored + 1))
+    }
+
+    async fn migrate(&self, dry_run: bool) -> BacklogRepositoryResult<MigrationReport> {
+        self.ensure_no_journal()?;
+        let mut document = match self.document()? {
+            None => return Ok(MigrationReport::up_to_date()),
+            Some(document) => document,
+        };
+        let report = backlog::migrate(&mut document)?;
+        if !dry_run && !report.is_up_to_date() {
+            let backlog: Backlog = serde_json::from_value(document)
+                .map_err(|err| BacklogRepositoryError::parse(None, None, err.to_string()))?;
+            let revision = backlog.revision();
+            self.write(&backlog.with_revision(revision + 1))?;
+        }
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use backlog::{AddItem, Entity, Story, Task};
+    use backlog_repo::contract;
+
+    use super::*;
+
+    fn repository() -> DirBacklogRepository {
+        let dir = std::env::temp_dir().join(format!("dir-{}", Backlog::new().id()));
+        DirBacklogRepository::new(dir)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_contract() {
+        contract::run(repository).await;
+    }
+
+    #[tokio::test]
+    async fn test_file_per_item() {
+        let repo = repository();
+        let mut backlog = repo.get().await.unwrap();
+        let story = Story::new("Login page", None, None);
+        let task = Task::new("Upgrade the database", None, None);
+        let (story_id, task_id) = (story.id(), task.id());
+        backlog.add_item(Box::new(story));
+        backlog.add_item(Box::new(task));
+        repo.save(backlog).await.unwrap();
+
+        let mut files: Vec<String> = std::fs::read_dir(repo.dir().join("items"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        files.sort();
+        let mut expected = vec![format!("{}.yaml", story_id), format!("{}.yaml", task_id)];
+        expected.sort();
+        assert_eq!(files, expected);
+        let item = repo.get_item(&task_id).await.unwrap().unwrap();
+        assert_eq!(item.title().value(), "Upgrade the database");
+        assert!(repo.get_item(&Uuid::new_v4()).await.unwrap().is_none());
+
+        // the item is loaded alone, without reading the file of another.
+        let story_file = repo.dir().join("items").join(format!("{}.yaml", story_id));
+        let story_content = std::fs::read_to_string(&story_file).unwrap();
+        std::fs::write(&story_file, "- broken").unwrap();
+        assert!(repo.get_item(&task_id).await.unwrap().is_some());
+        assert!(repo.get().await.is_err());
+        std::fs::write(&story_file, story_content).unwrap();
+
+        // the item merged in without the index is still loaded, after the listed ones.
+        let merged = repo.dir().join("items").join(format!("{}.yaml", task_id));
+        let moved = std::fs::read_to_string(&merged).unwrap();
+        std::fs::remove_file(&merged).unwrap();
+        let mut backlog = repo.get().await.unwrap();
+        assert_eq!(backlog.items().len(), 1);
+        backlog.add_item(Box::new(Story::new("Signup page", None, None)));
+        repo.save(backlog).await.unwrap();
+        std::fs::write(&merged, moved).unwrap();
+        let backlog = repo.get().await.unwrap();
+        let titles: Vec<&str> = backlog
+            .items()
+            .values()
+            .map(|item| item.title().value())
+            .collect();
+        assert_eq!(titles[..2], ["Login page", "Signup page"]);
+        assert_eq!(titles.len(), 3);
+
+        std::fs::remove_dir_all(repo.dir()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_refuse_the_journal_left_beside() {
+        let repo = repository();
+        repo.save(Backlog::new()).await.unwrap();
+        let journal = repo.journal_path();
+        std::fs::write(&journal, "{\"revision\":2}\n").unwrap();
+        assert!(repo.get().await.is_err());
+        assert!(repo.save(Backlog::new().with_revision(1)).await.is_err());
+
+        // the emptied journal, as it is left by the compaction, is no matter.
+        std::fs::write(&journal, "").unwrap();
+        assert_eq!(repo.get().await.unwrap().revision(), 1);
+        std::fs::remove_file(journal).unwrap();
+        std::fs::remove_dir_all(repo.dir()).unwrap();
+    }
+}
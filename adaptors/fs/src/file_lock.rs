@@ -0,0 +1,64 @@
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use backlog_repo::{BacklogLock, BacklogRepositoryError, BacklogRepositoryResult, LockMode};
+use fs2::FileExt;
+
+const LOCK_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Lock the file at `path` until the returned lock is dropped, waiting up to `timeout`.
+///
+/// The lock is advisory, so only the processes which lock it are excluded.
+pub async fn lock_file(
+    path: &Path,
+    mode: LockMode,
+    timeout: Duration,
+) -> BacklogRepositoryResult<BacklogLock> {
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)?;
+    let deadline = Instant::now() + timeout;
+    while !try_lock(&file, mode)? {
+        if Instant::now() >= deadline {
+            return Err(BacklogRepositoryError::Locked {
+                pid: read_pid(&file),
+            });
+        }
+        tokio::time::sleep(LOCK_INTERVAL).await;
+    }
+    write_pid(&file)?;
+    // the lock is released when the file is closed.
+    Ok(BacklogLock::new(file))
+}
+
+fn try_lock(file: &File, mode: LockMode) -> std::io::Result<bool> {
+    let locked = match mode {
+        LockMode::Shared => FileExt::try_lock_shared(file),
+        LockMode::Exclusive => FileExt::try_lock_exclusive(file),
+    };
+    match locked {
+        Ok(()) => Ok(true),
+        Err(err) if err.kind() == fs2::lock_contended_error().kind() => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// Record the holder, so that the waiting process can tell who holds the lock.
+fn write_pid(mut file: &File) -> std::io::Result<()> {
+    file.set_len(0)?;
+    file.seek(SeekFrom::Start(0))?;
+    write!(file, "{}", std::process::id())?;
+    file.flush()
+}
+
+fn read_pid(mut file: &File) -> Option<u32> {
+    let mut pid = String::new();
+    file.seek(SeekFrom::Start(0)).ok()?;
+    file.read_to_string(&mut pid).ok()?;
+    pid.trim().parse().ok()
+}
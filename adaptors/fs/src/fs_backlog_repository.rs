@@ -1,44 +1,501 @@
-use std::fs::OpenOptions;
-use std::path::PathBuf;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
 
-use backlog::Backlog;
-use backlog_repo::{BacklogRepository, BacklogRepositoryResult};
+use backlog::{Backlog, MigrationReport};
+use backlog_repo::{
+    BacklogLock, BacklogRepository, BacklogRepositoryError, BacklogRepositoryResult, Backup,
+    LockMode,
+};
+use chrono::{DateTime, Utc};
+
+use crate::atomic::write_file_with;
+use crate::{lock_file, Codec, DataFormat, JournalBacklogRepository};
 
 #[derive(Debug, Clone)]
 pub struct FsBacklogRepository {
     path: PathBuf,
+    codec: Arc<dyn Codec>,
+    backups: usize,
+    lock_timeout: Duration,
 }
 
 impl FsBacklogRepository {
+    /// The number of the backups kept by default.
+    pub const BACKUPS: usize = 5;
+    /// How long to wait for the lock by default.
+    pub const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// The format is chosen by the extension, and the file of any other extension is YAML.
     pub fn new(path: PathBuf) -> Self {
-        Self { path }
+        Self {
+            codec: DataFormat::from_path(&path)
+                .unwrap_or(DataFormat::Yaml)
+                .codec(),
+            path,
+            backups: Self::BACKUPS,
+            lock_timeout: Self::LOCK_TIMEOUT,
+        }
+    }
+
+    /// Read and write the file in `format` whatever its extension is.
+    pub fn with_format(self, format: DataFormat) -> Self {
+        self.with_codec(format.codec())
+    }
+
+    pub fn with_codec(self, codec: Arc<dyn Codec>) -> Self {
+        Self { codec, ..self }
+    }
+
+    /// Keep the last `backups` generations, e.g. `data.yaml.1` is the newest for `data.yaml`.
+    pub fn with_backups(self, backups: usize) -> Self {
+        Self { backups, ..self }
+    }
+
+    /// Give up locking after `lock_timeout`, or at once with zero.
+    pub fn with_lock_timeout(self, lock_timeout: Duration) -> Self {
+        Self {
+            lock_timeout,
+            ..self
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string()
+    }
+
+    fn backup_path(&self, generation: usize) -> PathBuf {
+        self.path
+            .with_file_name(format!("{}.{}", self.file_name(), generation))
+    }
+
+    /// The lock is taken on `data.yaml.lock` beside `data.yaml`,
+    /// because the data file itself is replaced on every save.
+    fn lock_path(&self) -> PathBuf {
+        self.path
+            .with_file_name(format!("{}.lock", self.file_name()))
+    }
+
+    /// Read the document as it is stored, before it is migrated.
+    ///
+    /// A missing or empty file means a new backlog, which is `None`.
+    fn document(
+        &self,
+        path: &Path,
+    ) -> BacklogRepositoryResult<Option<(String, serde_json::Value)>> {
+        let content = match std::fs::read_to_string(path) {
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            content => content?,
+        };
+        if content.trim().is_empty() {
+            return Ok(None);
+        }
+        let document = self.codec.document(&content)?;
+        if document.is_null() {
+            return Ok(None);
+        }
+        Ok(Some((content, document)))
+    }
+
+    fn read(&self, path: &Path) -> BacklogRepositoryResult<Backlog> {
+        let (content, mut document) = match self.document(path)? {
+            None => return Ok(Backlog::new()),
+            Some(document) => document,
+        };
+        let report = backlog::migrate(&mut document)?;
+        if report.is_up_to_date() {
+            // deserialize the text itself to locate the invalid content.
+            return self.codec.decode(&content);
+        }
+        serde_json::from_value(document)
+            .map_err(|err| BacklogRepositoryError::parse(None, None, err.to_string()))
+    }
+
+    /// Move the file aside, e.g. `data.yaml.20220401T120000.000Z.bak` for `data.yaml`.
+    fn back_up(&self) -> BacklogRepositoryResult<PathBuf> {
+        let backup = self.path.with_file_name(format!(
+            "{}.{}.bak",
+            self.file_name(),
+            Utc::now().format("%Y%m%dT%H%M%S%.3fZ")
+        ));
+        std::fs::rename(&self.path, &backup)?;
+        Ok(backup)
+    }
+
+    /// Shift the backups by one generation, and keep the current file as the newest.
+    ///
+    /// The current file stays in place, so it is never missing even if the process crashes.
+    fn rotate(&self) -> BacklogRepositoryResult<()> {
+        if self.backups == 0 || !self.path.exists() {
+            return Ok(());
+        }
+        for generation in (1..self.backups).rev() {
+            let from = self.backup_path(generation);
+            if from.exists() {
+                std::fs::rename(&from, self.backup_path(generation + 1))?;
+            }
+        }
+        let newest = self.backup_path(1);
+        if std::fs::hard_link(&self.path, &newest).is_err() {
+            std::fs::copy(&self.path, &newest)?;
+        }
+        Ok(())
+    }
+
+    /// The journal left beside the data file by `JournalBacklogRepository`, if it has any entry.
+    ///
+    /// The saves in the journal are newer than the data file, so they are never ignored.
+    fn journal(&self) -> BacklogRepositoryResult<Option<JournalBacklogRepository>> {
+        let journal =
+            JournalBacklogRepository::new(self.path.clone()).with_codec(self.codec.clone());
+        match std::fs::metadata(journal.journal_path()) {
+            Ok(metadata) if metadata.len() > 0 => Ok(Some(journal)),
+            Ok(_) => Ok(None),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Compact the journal into the data file before it is rewritten, which empties the journal.
+    fn compact_journal(&self) -> BacklogRepositoryResult<()> {
+        match self.journal()? {
+            Some(journal) => journal.compact(),
+            None => Ok(()),
+        }
+    }
+
+    /// Write the file atomically, rotating the backups just before it replaces the current one.
+    fn write(&self, backlog: &Backlog) -> BacklogRepositoryResult<()> {
+        let content = self.codec.encode(backlog)?;
+        write_file_with(&self.path, &content, || self.rotate())
     }
 }
 
 #[async_trait::async_trait]
 impl BacklogRepository for FsBacklogRepository {
+    async fn lock(&self, mode: LockMode) -> BacklogRepositoryResult<BacklogLock> {
+        lock_file(&self.lock_path(), mode, self.lock_timeout).await
+    }
+
+    /// The data file with the journal left beside it replayed, if any.
     async fn get(&self) -> BacklogRepositoryResult<Backlog> {
-        OpenOptions::new()
-            .create(true)
-            // If I use .write(false), I get the error that mean "InvalidInput".
-            .write(true)
-            .truncate(false)
-            .open(&self.path)?;
-        let file = std::fs::File::open(&self.path)?;
-        let backlog = serde_yaml::from_reader(file);
-        match backlog {
-            Err(_) => Ok(Backlog::new()),
-            Ok(backlog) => Ok(backlog),
+        match self.journal()? {
+            Some(journal) => journal.get().await,
+            None => self.read(&self.path),
         }
     }
 
+    /// The file which can not be parsed is never overwritten, but moved to a backup first.
+    ///
+    /// The other errors, e.g. the file can not be opened, fail the save and leave the file as it is.
     async fn save(&self, backlog: Backlog) -> BacklogRepositoryResult<()> {
-        let file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(&self.path)?;
-        serde_yaml::to_writer(file, &backlog)?;
-        Ok(())
+        self.compact_journal()?;
+        match self.read(&self.path) {
+            Err(BacklogRepositoryError::Parse { .. }) => {
+                self.back_up()?;
+            }
+            Err(err) => return Err(err),
+            Ok(stored) if stored.revision() != backlog.revision() => {
+                return Err(BacklogRepositoryError::Conflict {
+                    loaded: backlog.revision(),
+                    stored: stored.revision(),
+                });
+            }
+            Ok(_) => {}
+        }
+        let revision = backlog.revision() + 1;
+        self.write(&backlog.with_revision(revision))
+    }
+
+    async fn migrate(&self, dry_run: bool) -> BacklogRepositoryResult<MigrationReport> {
+        if !dry_run {
+            self.compact_journal()?;
+        }
+        let mut document = match self.document(&self.path)? {
+            None => return Ok(MigrationReport::up_to_date()),
+            Some((_, document)) => document,
+        };
+        let report = backlog::migrate(&mut document)?;
+        if !dry_run && !report.is_up_to_date() {
+            let backlog = serde_json::from_value(document)
+                .map_err(|err| BacklogRepositoryError::parse(None, None, err.to_string()))?;
+            self.save(backlog).await?;
+        }
+        Ok(report)
+    }
+
+    async fn backups(&self) -> BacklogRepositoryResult<Vec<Backup>> {
+        let mut backups = Vec::new();
+        for generation in 1..=self.backups {
+            let path = self.backup_path(generation);
+            let modified = match std::fs::metadata(&path) {
+                Err(err) if err.kind() == ErrorKind::NotFound => continue,
+                metadata => metadata?.modified()?,
+            };
+            let items = self.read(&path).ok().map(|backlog| backlog.items().len());
+            backups.push(Backup::new(
+                generation,
+                DateTime::<Utc>::from(modified),
+                items,
+            ));
+        }
+        Ok(backups)
+    }
+
+    async fn restore(&self, generation: usize) -> BacklogRepositoryResult<Backlog> {
+        let path = self.backup_path(generation);
+        if generation == 0 || generation > self.backups || !path.exists() {
+            return Err(BacklogRepositoryError::not_found(format!(
+                "the backup, generation: {}",
+                generation
+            )));
+        }
+        // the backup is saved over the stored revision, not its own one.
+        self.compact_journal()?;
+        let stored = self
+            .read(&self.path)
+            .map_or(0, |backlog| backlog.revision());
+        let backlog = self.read(&path)?.with_revision(stored);
+        self.save(backlog.clone()).await?;
+        Ok(backlog.with_revision(stored + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use backlog::{AddItem, Assignee, Entity, NaiveDate, Release, Story, StoryPoint, Task, User};
+    use backlog_repo::contract;
+
+    use super::*;
+
+    fn repository(content: Option<&str>) -> (PathBuf, FsBacklogRepository) {
+        let dir = std::env::temp_dir().join(format!("fs-{}", Backlog::new().id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("data.yaml");
+        if let Some(content) = content {
+            std::fs::write(&path, content).unwrap();
+        }
+        (dir, FsBacklogRepository::new(path))
+    }
+
+    fn files(dir: &Path) -> Vec<String> {
+        let mut files: Vec<String> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        files.sort();
+        files
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_contract() {
+        contract::run(|| repository(None).1).await;
+    }
+
+    #[tokio::test]
+    async fn test_get_new_backlog() {
+        for content in [None, Some(""), Some("\n"), Some("---\n")] {
+            let (dir, repo) = repository(content);
+            assert!(repo.get().await.unwrap().items().is_empty());
+            std::fs::remove_dir_all(dir).unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_expect_fail() {
+        let (dir, repo) = repository(Some("id: 1\nitems: [\n"));
+        match repo.get().await {
+            Err(BacklogRepositoryError::Parse { line, .. }) => assert!(line.is_some()),
+            other => panic!("expect a parse error, {:?}", other),
+        }
+
+        let (_, other) = repository(Some("format_version: 1\nid: not a uuid\nitems: {}\n"));
+        match other.get().await {
+            Err(BacklogRepositoryError::Parse { line, column, .. }) => {
+                assert_eq!((line, column), (Some(2), Some(5)));
+            }
+            other => panic!("expect a parse error, {:?}", other),
+        }
+        std::fs::remove_dir_all(dir).unwrap();
+        std::fs::remove_dir_all(other.path.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_save_backs_up_unreadable_file() {
+        let (dir, repo) = repository(Some("items: ["));
+        repo.save(Backlog::new()).await.unwrap();
+
+        let files: Vec<String> = files(&dir)
+            .into_iter()
+            .filter(|name| name.ends_with(".bak"))
+            .collect();
+        assert_eq!(files.len(), 1);
+        assert_eq!(
+            std::fs::read_to_string(dir.join(&files[0])).unwrap(),
+            "items: ["
+        );
+        assert!(repo.get().await.is_ok());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_save_expect_fail_on_unreadable_file() {
+        let (dir, repo) = repository(None);
+        std::fs::write(&repo.path, [0xff, 0xfe, 0xfd]).unwrap();
+        match repo.save(Backlog::new()).await {
+            Err(BacklogRepositoryError::Io(_)) => {}
+            other => panic!("expect an io error, {:?}", other),
+        }
+
+        assert_eq!(files(&dir), vec!["data.yaml"]);
+        assert_eq!(std::fs::read(&repo.path).unwrap(), vec![0xff, 0xfe, 0xfd]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_formats() {
+        let mut backlog = Backlog::new();
+        backlog
+            .add_user(User::new("alice", "Alice").unwrap())
+            .unwrap();
+        backlog
+            .add_release(Release::new("1.0", NaiveDate::from_ymd_opt(2022, 4, 1).unwrap()).unwrap())
+            .unwrap();
+        backlog.add_item(Box::new(Story::new(
+            "Login page",
+            Some(StoryPoint::new(3).unwrap()),
+            Some(Assignee::new("alice")),
+        )));
+        backlog.add_item(Box::new(Task::new("Upgrade the database", None, None)));
+        let expected = serde_json::to_value(&backlog).unwrap();
+
+        let (dir, _) = repository(None);
+        for (name, format, head) in [
+            ("data.json", None, "{"),
+            ("data.yml", None, "---"),
+            ("data.toml", None, "format_version = 1"),
+            ("data.txt", Some(DataFormat::Json), "{"),
+        ] {
+            let mut repo = FsBacklogRepository::new(dir.join(name));
+            if let Some(format) = format {
+                repo = repo.with_format(format);
+            }
+            repo.save(backlog.clone()).await.unwrap();
+            let content = std::fs::read_to_string(dir.join(name)).unwrap();
+            assert!(content.starts_with(head), "{}: {}", name, content);
+
+            let saved = repo.get().await.unwrap();
+            assert_eq!(saved.revision(), 1);
+            let saved = serde_json::to_value(saved.with_revision(0)).unwrap();
+            assert_eq!(saved, expected, "{}", name);
+        }
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_save_expect_conflict() {
+        let (dir, repo) = repository(None);
+        let loaded = repo.get().await.unwrap();
+        let stale = loaded.clone();
+        repo.save(loaded).await.unwrap();
+        assert_eq!(repo.get().await.unwrap().revision(), 1);
+
+        match repo.save(stale).await {
+            Err(BacklogRepositoryError::Conflict { loaded, stored }) => {
+                assert_eq!((loaded, stored), (0, 1));
+            }
+            other => panic!("expect a conflict, {:?}", other),
+        }
+        assert_eq!(repo.get().await.unwrap().revision(), 1);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_lock() {
+        let (dir, repo) = repository(None);
+        let repo = repo.with_lock_timeout(Duration::from_millis(100));
+
+        let shared = repo.lock(LockMode::Shared).await.unwrap();
+        assert!(shared.is_locked());
+        let other = repo.lock(LockMode::Shared).await.unwrap();
+        match repo.lock(LockMode::Exclusive).await {
+            Err(BacklogRepositoryError::Locked { pid }) => {
+                assert_eq!(pid, Some(std::process::id()));
+            }
+            other => panic!("expect a locked error, {:?}", other),
+        }
+        drop((shared, other));
+
+        let exclusive = repo.lock(LockMode::Exclusive).await.unwrap();
+        let err = repo.lock(LockMode::Shared).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "BacklogRepositoryError: backlog is locked by pid {}",
+                std::process::id()
+            )
+        );
+        drop(exclusive);
+        assert!(repo.lock(LockMode::Exclusive).await.is_ok());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rotate_and_restore() {
+        let (dir, repo) = repository(None);
+        let repo = repo.with_backups(2);
+        let mut ids = Vec::new();
+        for _ in 0..4 {
+            let revision = repo.get().await.unwrap().revision();
+            let backlog = Backlog::new().with_revision(revision);
+            ids.push(backlog.id());
+            repo.save(backlog).await.unwrap();
+        }
+        // no temporary file is left behind.
+        assert_eq!(files(&dir), ["data.yaml", "data.yaml.1", "data.yaml.2"]);
+        let backups = repo.backups().await.unwrap();
+        let generations: Vec<usize> = backups.iter().map(|b| b.generation()).collect();
+        assert_eq!(generations, [1, 2]);
+        assert_eq!(backups[0].items(), Some(0));
+
+        let restored = repo.restore(2).await.unwrap();
+        assert_eq!(restored.id(), ids[1]);
+        assert_eq!(repo.get().await.unwrap().id(), ids[1]);
+        // the replaced backlog becomes the newest backup.
+        assert_eq!(repo.restore(1).await.unwrap().id(), ids[3]);
+        assert!(repo.restore(3).await.is_err());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_compact_the_journal_left_beside() {
+        let (dir, repo) = repository(None);
+        let journal = JournalBacklogRepository::new(repo.path().to_path_buf());
+        let mut backlog = journal.get().await.unwrap();
+        backlog.add_item(Box::new(Story::new("Login page", None, None)));
+        journal.save(backlog).await.unwrap();
+
+        // the save only in the journal is read, and kept by the next save.
+        let mut backlog = repo.get().await.unwrap();
+        assert_eq!(backlog.revision(), 1);
+        assert_eq!(backlog.items().len(), 1);
+        backlog.add_item(Box::new(Story::new("Signup page", None, None)));
+        repo.save(backlog).await.unwrap();
+
+        assert_eq!(std::fs::metadata(journal.journal_path()).unwrap().len(), 0);
+        let backlog = repo.get().await.unwrap();
+        assert_eq!(backlog.revision(), 2);
+        assert_eq!(backlog.items().len(), 2);
+        assert_eq!(journal.get().await.unwrap().revision(), 2);
+        std::fs::remove_dir_all(dir).unwrap();
     }
 }
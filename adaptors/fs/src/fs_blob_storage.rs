@@ -0,0 +1,65 @@
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use blob_storage::{BlobStorage, BlobStorageError, BlobStorageResult};
+
+/// Store each content in a file named after its hash, under `<dir>/<first 2 chars of hash>/`.
+#[derive(Debug, Clone)]
+pub struct FsBlobStorage {
+    dir: PathBuf,
+}
+
+impl FsBlobStorage {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Store the contents next to the backlog file, e.g. `data.attachments/` for `data.yaml`.
+    pub fn beside(data: &Path) -> Self {
+        let stem = data.file_stem().unwrap_or_default().to_string_lossy();
+        Self::new(data.with_file_name(format!("{}.attachments", stem)))
+    }
+
+    fn path(&self, hash: &str) -> BlobStorageResult<PathBuf> {
+        if hash.len() < 3 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(BlobStorageError::not_found(format!(
+                "the hash is malformed, {}",
+                hash
+            )));
+        }
+        Ok(self.dir.join(&hash[..2]).join(hash))
+    }
+}
+
+#[async_trait::async_trait]
+impl BlobStorage for FsBlobStorage {
+    async fn put(&self, hash: &str, content: Vec<u8>) -> BlobStorageResult<()> {
+        let path = self.path(hash)?;
+        if path.exists() {
+            return Ok(());
+        }
+        let dir = path.parent().expect("the path is under the directory");
+        std::fs::create_dir_all(dir)?;
+        // never leave the partial content under the hash.
+        let tmp = dir.join(format!("{}.{}.tmp", hash, std::process::id()));
+        std::fs::write(&tmp, content)?;
+        std::fs::rename(&tmp, &path)?;
+        Ok(())
+    }
+
+    async fn get(&self, hash: &str) -> BlobStorageResult<Vec<u8>> {
+        let path = self.path(hash)?;
+        std::fs::read(&path).map_err(|err| match err.kind() {
+            ErrorKind::NotFound => BlobStorageError::not_found(hash),
+            _ => err.into(),
+        })
+    }
+
+    async fn delete(&self, hash: &str) -> BlobStorageResult<()> {
+        let path = self.path(hash)?;
+        match std::fs::remove_file(&path) {
+            Err(err) if err.kind() != ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+}
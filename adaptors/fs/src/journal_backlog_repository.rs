@@ -0,0 +1,550 @@
+use std::fs::{File, OpenOptions};
+use std::io::{ErrorKind, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use backlog::{Backlog, MigrationReport};
+use backlog_repo::{
+    BacklogLock, BacklogRepository, BacklogRepositoryError, BacklogRepositoryResult, LockMode,
+};
+use serde_json::{json, Map, Value};
+
+use crate::atomic::write_file;
+use crate::{lock_file, Codec, DataFormat, FsBacklogRepository};
+
+/// Appends each save to a journal beside the data file, instead of rewriting the data file,
+/// and compacts the journal into the data file, which is the snapshot, once it grows long.
+///
+/// A line of `data.yaml.journal` is the JSON of what the save changed, e.g.
+///
+/// ```text
+/// {"revision":6,"items":{"<id>":{..the item..}}}
+/// ```
+///
+/// so a save which changes an item appends that item alone.
+/// The removed item is `null`, the reordered items come with their `order`,
+/// and the other changed fields of the backlog come in `fields`.
+///
+/// The last line torn by a crash is ignored, and cut off by the next save.
+#[derive(Debug, Clone)]
+pub struct JournalBacklogRepository {
+    path: PathBuf,
+    codec: Arc<dyn Codec>,
+    compact_after: usize,
+    lock_timeout: Duration,
+    /// the journal replayed so far, which is read on from its end while the snapshot is the same.
+    replayed: Arc<Mutex<Option<Replayed>>>,
+}
+
+#[derive(Debug, Clone)]
+struct Replayed {
+    /// the length and the modified time of the snapshot, or `None` without it.
+    snapshot: Option<(u64, SystemTime)>,
+    /// the length of the complete lines of the journal.
+    offset: u64,
+    /// the number of the complete lines of the journal.
+    entries: usize,
+    /// the backlog document with every entry applied, or `None` for a new backlog.
+    document: Option<Value>,
+}
+
+impl JournalBacklogRepository {
+    /// The number of the entries which triggers the compaction by default.
+    pub const COMPACT_AFTER: usize = 100;
+
+    /// The format of the snapshot is chosen by the extension, and the file of any other extension is YAML.
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            codec: DataFormat::from_path(&path)
+                .unwrap_or(DataFormat::Yaml)
+                .codec(),
+            path,
+            compact_after: Self::COMPACT_AFTER,
+            lock_timeout: FsBacklogRepository::LOCK_TIMEOUT,
+            replayed: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Read and write the snapshot in `format` whatever its extension is.
+    pub fn with_format(self, format: DataFormat) -> Self {
+        Self {
+            codec: format.codec(),
+            ..self
+        }
+    }
+
+    /// Read and write the snapshot with `codec`, as the repository of the data file does.
+    pub(crate) fn with_codec(self, codec: Arc<dyn Codec>) -> Self {
+        Self { codec, ..self }
+    }
+
+    /// Compact the journal into the snapshot when a save makes it `compact_after` entries long,
+    /// or never with zero.
+    pub fn with_compact_after(self, compact_after: usize) -> Self {
+        Self {
+            compact_after,
+            ..self
+        }
+    }
+
+    /// Give up locking after `lock_timeout`, or at once with zero.
+    pub fn with_lock_timeout(self, lock_timeout: Duration) -> Self {
+        Self {
+            lock_timeout,
+            ..self
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string()
+    }
+
+    /// `data.yaml.journal` for `data.yaml`.
+    pub fn journal_path(&self) -> PathBuf {
+        self.path
+            .with_file_name(format!("{}.journal", self.file_name()))
+    }
+
+    /// The same lock as `FsBacklogRepository`, so that both never write the data file at once.
+    fn lock_path(&self) -> PathBuf {
+        self.path
+            .with_file_name(format!("{}.lock", self.file_name()))
+    }
+
+    fn snapshot_metadata(&self) -> BacklogRepositoryResult<Option<(u64, SystemTime)>> {
+        match std::fs::metadata(&self.path) {
+            Ok(metadata) => Ok(Some((metadata.len(), metadata.modified()?))),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Read the snapshot, migrated.
+    fn snapshot(&self) -> BacklogRepositoryResult<(Option<Value>, MigrationReport)> {
+        let content = match std::fs::read_to_string(&self.path) {
+            Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+            content => content?,
+        };
+        if content.trim().is_empty() {
+            return Ok((None, MigrationReport::up_to_date()));
+        }
+        let mut document = self.codec.document(&content)?;
+        if document.is_null() {
+            return Ok((None, MigrationReport::up_to_date()));
+        }
+        let report = backlog::migrate(&mut document)?;
+        Ok((Some(document), report))
+    }
+
+    /// Bring the replayed journal up to date, reading only the entries appended since the last time.
+    fn replay(&self) -> BacklogRepositoryResult<Replayed> {
+        let mut cached = self.replayed.lock().unwrap_or_else(|err| err.into_inner());
+        let snapshot = self.snapshot_metadata()?;
+        let mut journal = match File::open(self.journal_path()) {
+            Ok(journal) => Some(journal),
+            Err(err) if err.kind() == ErrorKind::NotFound => None,
+            Err(err) => return Err(err.into()),
+        };
+        let len = match &journal {
+            Some(journal) => journal.metadata()?.len(),
+            None => 0,
+        };
+        let mut replayed = match cached.take() {
+            Some(replayed) if replayed.snapshot == snapshot && replayed.offset <= len => replayed,
+            _ => Replayed {
+                snapshot,
+                offset: 0,
+                entries: 0,
+                document: self.snapshot()?.0,
+            },
+        };
+
+        if let Some(journal) = &mut journal {
+            let mut appended = Vec::new();
+            journal.seek(SeekFrom::Start(replayed.offset))?;
+            journal.read_to_end(&mut appended)?;
+            let mut rest = &appended[..];
+            while let Some(end) = rest.iter().position(|byte| *byte == b'\n') {
+                let line = &rest[..end];
+                rest = &rest[end + 1..];
+                let entry: Value = match serde_json::from_slice(line) {
+                    Ok(entry) => entry,
+                    // the last line was torn by a crash.
+                    Err(_) if rest.is_empty() => break,
+                    Err(err) => {
+                        return Err(BacklogRepositoryError::parse(
+                            Some(replayed.entries + 1),
+                            None,
+                            format!("{}, {}", self.journal_path().display(), err),
+                        ))
+                    }
+                };
+                replayed.document = Some(apply(replayed.document.take(), &entry)?);
+                replayed.offset += end as u64 + 1;
+                replayed.entries += 1;
+            }
+        }
+        *cached = Some(replayed.clone());
+        Ok(replayed)
+    }
+
+    fn read(&self) -> BacklogRepositoryResult<Backlog> {
+        match self.replay()?.document {
+            None => Ok(Backlog::new()),
+            Some(document) => serde_json::from_value(document)
+                .map_err(|err| BacklogRepositoryError::parse(None, None, err.to_string())),
+        }
+    }
+
+    /// Append the entry, cutting off the torn line after `offset` first.
+    fn append(&self, offset: u64, entry: &Value) -> BacklogRepositoryResult<u64> {
+        let mut line = serde_json::to_string(entry)?;
+        line.push('\n');
+        let mut journal = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(false)
+            .open(self.journal_path())?;
+        if journal.metadata()?.len() != offset {
+            journal.set_len(offset)?;
+        }
+        journal.seek(SeekFrom::Start(offset))?;
+        journal.write_all(line.as_bytes())?;
+        journal.sync_data()?;
+        Ok(line.len() as u64)
+    }
+
+    /// Write the journal replayed into the snapshot, then empty the journal.
+    ///
+    /// The entries already in the snapshot are skipped by their revisions,
+    /// so a crash before the journal is emptied loses nothing.
+    pub fn compact(&self) -> BacklogRepositoryResult<()> {
+        let replayed = self.replay()?;
+        let document = match replayed.document {
+            None => return Ok(()),
+            Some(document) => document,
+        };
+        let backlog: Backlog = serde_json::from_value(document)
+            .map_err(|err| BacklogRepositoryError::parse(None, None, err.to_string()))?;
+        write_file(&self.path, &self.codec.encode(&backlog)?)?;
+        if replayed.offset > 0 {
+            File::create(self.journal_path())?.sync_all()?;
+        }
+        *self.replayed.lock().unwrap_or_else(|err| err.into_inner()) = None;
+        Ok(())
+    }
+}
+
+/// Describe how `after` differs from `before` as an entry of the journal.
+fn diff(before: Option<&Value>, after: &Value) -> Value {
+    let empty = Map::new();
+    let before = before.and_then(Value::as_object).unwrap_or(&empty);
+    let after = after.as_object().unwrap_or(&empty);
+    let mut fields = Map::new();
+    for (key, value) in after {
+        if key != "items" && key != "revision" && before.get(key) != Some(value) {
+            fields.insert(key.clone(), value.clone());
+        }
+    }
+    let unset: Vec<&String> = before
+        .keys()
+        .filter(|key| !after.contains_key(*key))
+        .collect();
+
+    let (before_items, after_items) = (items(before, &empty), items(after, &empty));
+    let mut changed = Map::new();
+    for (id, item) in after_items {
+        if before_items.get(id) != Some(item) {
+            changed.insert(id.clone(), item.clone());
+        }
+    }
+    for id in before_items.keys() {
+        if !after_items.contains_key(id) {
+            changed.insert(id.clone(), Value::Null);
+        }
+    }
+    // the ids in the order that applying the changes alone leaves them.
+    let applied: Vec<&String> = before_items
+        .keys()
+        .filter(|id| after_items.contains_key(*id))
+        .chain(
+            after_items
+                .keys()
+                .filter(|id| !before_items.contains_key(*id)),
+        )
+        .collect();
+
+    let mut entry = json!({ "revision": after.get("revision").cloned().unwrap_or(Value::Null) });
+    if !changed.is_empty() {
+        entry["items"] = Value::Object(changed);
+    }
+    if !applied.into_iter().eq(after_items.keys()) {
+        entry["order"] = json!(after_items.keys().collect::<Vec<_>>());
+    }
+    if !fields.is_empty() {
+        entry["fields"] = Value::Object(fields);
+    }
+    if !unset.is_empty() {
+        entry["unset"] = json!(unset);
+    }
+    entry
+}
+
+fn items<'a>(
+    document: &'a Map<String, Value>,
+    empty: &'a Map<String, Value>,
+) -> &'a Map<String, Value> {
+    document
+        .get("items")
+        .and_then(Value::as_object)
+        .unwrap_or(empty)
+}
+
+/// Apply the entry of the journal to the document, unless the document already has its revision.
+fn apply(document: Option<Value>, entry: &Value) -> BacklogRepositoryResult<Value> {
+    let mut document = match document {
+        Some(Value::Object(document)) => document,
+        _ => Map::new(),
+    };
+    let stored = document
+        .get("revision")
+        .and_then(Value::as_u64)
+        .unwrap_or_default();
+    let revision = entry
+        .get("revision")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| BacklogRepositoryError::parse(None, None, "the entry has no revision"))?;
+    if revision <= stored {
+        return Ok(Value::Object(document));
+    }
+    if revision != stored + 1 {
+        return Err(BacklogRepositoryError::parse(
+            None,
+            None,
+            format!("the journal skips from revision {} to {}", stored, revision),
+        ));
+    }
+
+    if let Some(fields) = entry.get("fields").and_then(Value::as_object) {
+        for (key, value) in fields {
+            document.insert(key.clone(), value.clone());
+        }
+    }
+    if let Some(unset) = entry.get("unset").and_then(Value::as_array) {
+        document.retain(|key, _| !unset.iter().any(|unset| unset == key));
+    }
+    let mut items = match document.remove("items") {
+        Some(Value::Object(items)) => items,
+        _ => Map::new(),
+    };
+    if let Some(changed) = entry.get("items").and_then(Value::as_object) {
+        items.retain(|id, _| !matches!(changed.get(id), Some(Value::Null)));
+        for (id, item) in changed {
+            if !item.is_null() {
+                items.insert(id.clone(), item.clone());
+            }
+        }
+    }
+    if let Some(order) = entry.get("order").and_then(Value::as_array) {
+        let mut ordered = Map::new();
+        for id in order.iter().filter_map(Value::as_str) {
+            if let Some(item) = items.get(id) {
+                ordered.insert(id.to_string(), item.clone());
+            }
+        }
+        for (id, item) in items {
+            if !ordered.contains_key(&id) {
+                ordered.insert(id, item);
+            }
+        }
+        items = ordered;
+    }
+    document.insert("items".to_string(), Value::Object(items));
+    document.insert("revision".to_string(), Value::from(revision));
+    Ok(Value::Object(document))
+}
+
+#[async_trait::async_trait]
+impl BacklogRepository for JournalBacklogRepository {
+    async fn lock(&self, mode: LockMode) -> BacklogRepositoryResult<BacklogLock> {
+        lock_file(&self.lock_path(), mode, self.lock_timeout).await
+    }
+
+    async fn get(&self) -> BacklogRepositoryResult<Backlog> {
+        self.read()
+    }
+
+    async fn save(&self, backlog: Backlog) -> BacklogRepositoryResult<()> {
+        let replayed = self.replay()?;
+        let stored = replayed
+            .document
+            .as_ref()
+            .and_then(|document| document.get("revision"))
+            .and_then(Value::as_u64)
+            .unwrap_or_default();
+        if stored != backlog.revision() {
+            return Err(BacklogRepositoryError::Conflict {
+                loaded: backlog.revision(),
+                stored,
+            });
+        }
+        let document = serde_json::to_value(backlog.with_revision(stored + 1))?;
+        let entry = diff(replayed.document.as_ref(), &document);
+        let written = self.append(replayed.offset, &entry)?;
+
+        let entries = replayed.entries + 1;
+        *self.replayed.lock().unwrap_or_else(|err| err.into_inner()) = Some(Replayed {
+            offset: replayed.offset + written,
+            entries,
+            document: Some(document),
+            ..replayed
+        });
+        if self.compact_after > 0 && entries >= self.compact_after {
+            self.compact()?;
+        }
+        Ok(())
+    }
+
+    /// The journal is written in the current format, so only the snapshot is migrated.
+    async fn migrate(&self, dry_run: bool) -> BacklogRepositoryResult<MigrationReport> {
+        let (_, report) = self.snapshot()?;
+        if !dry_run && !report.is_up_to_date() {
+            self.compact()?;
+        }
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use backlog::{AddItem, Entity, EstimatableFromCollection, Story, StoryPoint, Task};
+    use backlog_repo::contract;
+
+    use super::*;
+
+    fn repository() -> (PathBuf, JournalBacklogRepository) {
+        let dir = std::env::temp_dir().join(format!("journal-{}", Backlog::new().id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        (
+            dir.clone(),
+            JournalBacklogRepository::new(dir.join("data.yaml")),
+        )
+    }
+
+    fn lines(repo: &JournalBacklogRepository) -> Vec<Value> {
+        std::fs::read_to_string(repo.journal_path())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_contract() {
+        contract::run(|| repository().1).await;
+    }
+
+    #[tokio::test]
+    async fn test_append_changed_item() {
+        let (dir, repo) = repository();
+        let mut backlog = repo.get().await.unwrap();
+        let story = Story::new("Login page", None, None);
+        let id = story.id();
+        backlog.add_item(Box::new(story));
+        backlog.add_item(Box::new(Task::new("Upgrade the database", None, None)));
+        repo.save(backlog).await.unwrap();
+
+        let mut backlog = repo.get().await.unwrap();
+        backlog
+            .estimate_item(&id, StoryPoint::new(5).unwrap())
+            .unwrap();
+        repo.save(backlog.clone()).await.unwrap();
+
+        let entries = lines(&repo);
+        assert_eq!(entries.len(), 2);
+        let changed: Vec<&String> = entries[1]["items"].as_object().unwrap().keys().collect();
+        assert_eq!(changed, [&id.to_string()]);
+        assert!(entries[1].get("fields").is_none());
+        assert!(!repo.path().exists());
+
+        // another repository replays the journal from the start.
+        let replayed = JournalBacklogRepository::new(repo.path().to_path_buf())
+            .get()
+            .await
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(replayed).unwrap(),
+            serde_json::to_value(backlog.with_revision(2)).unwrap()
+        );
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_torn_last_line() {
+        let (dir, repo) = repository();
+        let mut backlog = repo.get().await.unwrap();
+        backlog.add_item(Box::new(Story::new("Login page", None, None)));
+        repo.save(backlog).await.unwrap();
+        let mut journal = OpenOptions::new()
+            .append(true)
+            .open(repo.journal_path())
+            .unwrap();
+        journal.write_all(br#"{"revision":2,"ite"#).unwrap();
+
+        let repo = JournalBacklogRepository::new(repo.path().to_path_buf());
+        let mut backlog = repo.get().await.unwrap();
+        assert_eq!(backlog.revision(), 1);
+        assert_eq!(backlog.items().len(), 1);
+
+        backlog.add_item(Box::new(Story::new("Signup page", None, None)));
+        repo.save(backlog).await.unwrap();
+        assert_eq!(lines(&repo).len(), 2);
+        let saved = JournalBacklogRepository::new(repo.path().to_path_buf())
+            .get()
+            .await
+            .unwrap();
+        assert_eq!(saved.revision(), 2);
+        assert_eq!(saved.items().len(), 2);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_compact() {
+        let (dir, repo) = repository();
+        let repo = repo.with_compact_after(2);
+        for title in ["Login page", "Signup page", "Upgrade the database"] {
+            let mut backlog = repo.get().await.unwrap();
+            backlog.add_item(Box::new(Story::new(title, None, None)));
+            repo.save(backlog).await.unwrap();
+        }
+        assert_eq!(lines(&repo).len(), 1);
+        let snapshot: Backlog =
+            serde_yaml::from_str(&std::fs::read_to_string(repo.path()).unwrap()).unwrap();
+        assert_eq!(snapshot.revision(), 2);
+
+        // the entries already in the snapshot are skipped,
+        // as when the process crashed before the journal was emptied.
+        let journal = repo.journal_path();
+        let last = std::fs::read_to_string(&journal).unwrap();
+        std::fs::write(&journal, format!("{{\"revision\":2}}\n{}", last)).unwrap();
+        let backlog = JournalBacklogRepository::new(repo.path().to_path_buf())
+            .get()
+            .await
+            .unwrap();
+        assert_eq!(backlog.revision(), 3);
+        assert_eq!(backlog.items().len(), 3);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
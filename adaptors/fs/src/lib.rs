@@ -1,3 +1,15 @@
+mod atomic;
+mod codec;
+mod dir_backlog_repository;
+mod file_lock;
 mod fs_backlog_repository;
+mod fs_blob_storage;
+mod journal_backlog_repository;
 
+pub use atomic::write_file;
+pub use codec::{Codec, DataFormat, JsonCodec, TomlCodec, YamlCodec};
+pub use dir_backlog_repository::DirBacklogRepository;
+pub use file_lock::lock_file;
 pub use fs_backlog_repository::FsBacklogRepository;
+pub use fs_blob_storage::FsBlobStorage;
+pub use journal_backlog_repository::JournalBacklogRepository;
@@ -0,0 +1,54 @@
+use std::str::FromStr;
+
+/// The author of the commits, written as `Name <email>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Author {
+    name: String,
+    email: String,
+}
+
+impl Author {
+    pub fn new(name: &str, email: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            email: email.to_string(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+}
+
+impl FromStr for Author {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("the author must be written as Name <email>, {}", s);
+        let (name, email) = s.trim().split_once('<').ok_or_else(invalid)?;
+        let email = email.strip_suffix('>').ok_or_else(invalid)?.trim();
+        let name = name.trim();
+        if name.is_empty() || email.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self::new(name, email))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let author: Author = "Alice Liddell <alice@example.com>".parse().unwrap();
+        assert_eq!(author, Author::new("Alice Liddell", "alice@example.com"));
+
+        assert!("Alice Liddell".parse::<Author>().is_err());
+        assert!("<alice@example.com>".parse::<Author>().is_err());
+    }
+}
@@ -0,0 +1,526 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use backlog::{Backlog, MigrationReport};
+use backlog_repo::{
+    BacklogLock, BacklogRepository, BacklogRepositoryError, BacklogRepositoryResult, HistoryEntry,
+    LockMode,
+};
+use chrono::{TimeZone, Utc};
+use fs::{lock_file, write_file, Codec, DataFormat};
+use git2::build::TreeUpdateBuilder;
+use git2::{
+    Commit, DiffFormat, DiffOptions, ErrorCode, FileMode, Oid, Repository, Signature, Sort, Status,
+};
+
+use crate::Author;
+
+/// Stores the backlog as a file in a local git repository, and commits every save.
+///
+/// The backlog is read from the commit at `HEAD`, and the file in the working tree
+/// is overwritten by each save, so that it is always what has been committed.
+/// The save is refused while the file has the edits which are not committed.
+#[derive(Debug, Clone)]
+pub struct GitBacklogRepository {
+    path: PathBuf,
+    codec: Arc<dyn Codec>,
+    author: Option<Author>,
+    lock_timeout: Duration,
+}
+
+/// The repository and the path of the data file in its tree.
+struct Opened {
+    repo: Repository,
+    file: PathBuf,
+}
+
+/// The stored backlog as it is committed at `HEAD`, if any.
+struct Stored {
+    head: Option<Oid>,
+    backlog: Option<Backlog>,
+}
+
+impl GitBacklogRepository {
+    /// How long to wait for the lock by default.
+    pub const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+    /// The author of the commits when neither it is configured nor git knows the user.
+    const AUTHOR: (&'static str, &'static str) = ("rjira", "rjira@localhost");
+    /// The operation of the save which is not named.
+    const OPERATION: &'static str = "Update the backlog";
+
+    /// The repository which contains `path` is used, or is made in its directory.
+    /// The format is chosen by the extension, and the file of any other extension is YAML.
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            codec: DataFormat::from_path(&path)
+                .unwrap_or(DataFormat::Yaml)
+                .codec(),
+            path,
+            author: None,
+            lock_timeout: Self::LOCK_TIMEOUT,
+        }
+    }
+
+    pub fn with_format(self, format: DataFormat) -> Self {
+        Self {
+            codec: format.codec(),
+            ..self
+        }
+    }
+
+    /// Commit as `author`, instead of the user configured in git.
+    pub fn with_author(self, author: Author) -> Self {
+        Self {
+            author: Some(author),
+            ..self
+        }
+    }
+
+    /// Give up locking after `lock_timeout`, or at once with zero.
+    pub fn with_lock_timeout(self, lock_timeout: Duration) -> Self {
+        Self {
+            lock_timeout,
+            ..self
+        }
+    }
+
+    fn open(&self) -> BacklogRepositoryResult<Opened> {
+        let dir = match self.path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        std::fs::create_dir_all(dir)?;
+        let dir = dir.canonicalize()?;
+        let repo = match Repository::discover(&dir) {
+            Err(err) if err.code() == ErrorCode::NotFound => Repository::init(&dir),
+            repo => repo,
+        }
+        .map_err(BacklogRepositoryError::version_control)?;
+        let workdir = repo
+            .workdir()
+            .ok_or_else(|| {
+                BacklogRepositoryError::not_found(format!(
+                    "the working tree of the repository, {}",
+                    repo.path().display()
+                ))
+            })?
+            .canonicalize()?;
+        let file = dir
+            .join(self.path.file_name().unwrap_or_default())
+            .strip_prefix(&workdir)
+            .map(Path::to_path_buf)
+            .map_err(|_| {
+                BacklogRepositoryError::not_found(format!(
+                    "the data file in the working tree, {}",
+                    self.path.display()
+                ))
+            })?;
+        Ok(Opened { repo, file })
+    }
+
+    /// The commit at `HEAD`, which is `None` before the first commit.
+    fn head(repo: &Repository) -> BacklogRepositoryResult<Option<Commit<'_>>> {
+        match repo.head() {
+            Ok(head) => head
+                .peel_to_commit()
+                .map(Some)
+                .map_err(BacklogRepositoryError::version_control),
+            Err(err) if matches!(err.code(), ErrorCode::UnbornBranch | ErrorCode::NotFound) => {
+                Ok(None)
+            }
+            Err(err) => Err(BacklogRepositoryError::version_control(err)),
+        }
+    }
+
+    /// The content of the data file in the commit, which is `None` if it has no such file.
+    fn content(
+        repo: &Repository,
+        commit: &Commit<'_>,
+        file: &Path,
+    ) -> BacklogRepositoryResult<Option<(Oid, String)>> {
+        let tree = commit
+            .tree()
+            .map_err(BacklogRepositoryError::version_control)?;
+        let entry = match tree.get_path(file) {
+            Ok(entry) => entry,
+            Err(err) if err.code() == ErrorCode::NotFound => return Ok(None),
+            Err(err) => return Err(BacklogRepositoryError::version_control(err)),
+        };
+        let blob = repo
+            .find_blob(entry.id())
+            .map_err(BacklogRepositoryError::version_control)?;
+        let content = String::from_utf8_lossy(blob.content()).to_string();
+        Ok(Some((entry.id(), content)))
+    }
+
+    fn decode(&self, content: &str) -> BacklogRepositoryResult<Backlog> {
+        let mut document = self.codec.document(content)?;
+        let report = backlog::migrate(&mut document)?;
+        if report.is_up_to_date() {
+            return self.codec.decode(content);
+        }
+        serde_json::from_value(document)
+            .map_err(|err| BacklogRepositoryError::parse(None, None, err.to_string()))
+    }
+
+    fn stored(&self, opened: &Opened) -> BacklogRepositoryResult<Stored> {
+        let head = match Self::head(&opened.repo)? {
+            None => {
+                return Ok(Stored {
+                    head: None,
+                    backlog: None,
+                })
+            }
+            Some(head) => head,
+        };
+        let backlog = match Self::content(&opened.repo, &head, &opened.file)? {
+            None => None,
+            Some((_, content)) => Some(self.decode(&content)?),
+        };
+        Ok(Stored {
+            head: Some(head.id()),
+            backlog,
+        })
+    }
+
+    fn signature(&self, repo: &Repository) -> BacklogRepositoryResult<Signature<'static>> {
+        let signature = match &self.author {
+            Some(author) => Signature::now(author.name(), author.email()),
+            None => repo
+                .signature()
+                .or_else(|_| Signature::now(Self::AUTHOR.0, Self::AUTHOR.1)),
+        };
+        signature.map_err(BacklogRepositoryError::version_control)
+    }
+
+    /// Fail if the data file in the working tree or the index differs from `HEAD`,
+    /// since the save would overwrite the edits which are not committed.
+    fn ensure_clean(opened: &Opened) -> BacklogRepositoryResult<()> {
+        let status = match opened.repo.status_file(&opened.file) {
+            Ok(status) => status,
+            Err(err) if err.code() == ErrorCode::NotFound => return Ok(()),
+            Err(err) => return Err(BacklogRepositoryError::version_control(err)),
+        };
+        let edited = Status::INDEX_NEW
+            | Status::INDEX_MODIFIED
+            | Status::INDEX_TYPECHANGE
+            | Status::WT_NEW
+            | Status::WT_MODIFIED
+            | Status::WT_TYPECHANGE
+            | Status::CONFLICTED;
+        if status.intersects(edited) {
+            return Err(BacklogRepositoryError::version_control(
+                git2::Error::from_str(&format!(
+                    "the data file has the edits which are not committed yet, {}",
+                    opened.file.display()
+                )),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Write the data file in the working tree and the index as it has been committed,
+    /// leaving the other files as they are.
+    fn check_out(opened: &Opened, content: &str) -> BacklogRepositoryResult<()> {
+        let workdir = match opened.repo.workdir() {
+            None => return Ok(()),
+            Some(workdir) => workdir,
+        };
+        write_file(&workdir.join(&opened.file), content)?;
+        let mut index = opened
+            .repo
+            .index()
+            .map_err(BacklogRepositoryError::version_control)?;
+        index
+            .add_path(&opened.file)
+            .and_then(|_| index.write())
+            .map_err(BacklogRepositoryError::version_control)
+    }
+
+    fn resolve<'r>(repo: &'r Repository, spec: &str) -> BacklogRepositoryResult<git2::Tree<'r>> {
+        repo.revparse_single(spec)
+            .and_then(|object| object.peel_to_tree())
+            .map_err(|err| match err.code() {
+                ErrorCode::NotFound | ErrorCode::InvalidSpec | ErrorCode::Ambiguous => {
+                    BacklogRepositoryError::not_found(format!("the revision, {}", spec))
+                }
+                _ => BacklogRepositoryError::version_control(err),
+            })
+    }
+}
+
+/// The commit message, whose summary is the only change, e.g. `Estimate "Login page": 3 → 5`,
+/// or else the operation, e.g. `Split the item`, followed by the changes.
+fn message(operation: &str, changes: &[String]) -> String {
+    match changes {
+        [] => operation.to_string(),
+        [change] => change.clone(),
+        _ => {
+            let lines: Vec<String> = changes
+                .iter()
+                .map(|change| format!("- {}", change))
+                .collect();
+            format!("{}\n\n{}", operation, lines.join("\n"))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl BacklogRepository for GitBacklogRepository {
+    /// The lock is taken on `rjira.lock` in the git directory, out of the working tree.
+    async fn lock(&self, mode: LockMode) -> BacklogRepositoryResult<BacklogLock> {
+        let path = self.open()?.repo.path().join("rjira.lock");
+        lock_file(&path, mode, self.lock_timeout).await
+    }
+
+    async fn get(&self) -> BacklogRepositoryResult<Backlog> {
+        let opened = self.open()?;
+        Ok(self.stored(&opened)?.backlog.unwrap_or_default())
+    }
+
+    async fn save(&self, backlog: Backlog) -> BacklogRepositoryResult<()> {
+        self.save_as(backlog, Self::OPERATION).await
+    }
+
+    /// The commit fails when another has moved `HEAD` since it was read,
+    /// which is a conflict as well as the stale revision.
+    async fn save_as(&self, backlog: Backlog, operation: &str) -> BacklogRepositoryResult<()> {
+        let opened = self.open()?;
+        let Stored {
+            head,
+            backlog: stored,
+        } = self.stored(&opened)?;
+        let stored = stored.unwrap_or_default();
+        let loaded = backlog.revision();
+        if stored.revision() != loaded {
+            return Err(BacklogRepositoryError::Conflict {
+                loaded,
+                stored: stored.revision(),
+            });
+        }
+        Self::ensure_clean(&opened)?;
+
+        let message = message(operation, &backlog.changelog(&stored));
+        let backlog = backlog.with_revision(stored.revision() + 1);
+        let content = self.codec.encode(&backlog)?;
+        let repo = &opened.repo;
+        let vcs = BacklogRepositoryError::version_control;
+        let blob = repo.blob(content.as_bytes()).map_err(vcs)?;
+        let parent = match head {
+            Some(head) => Some(repo.find_commit(head).map_err(vcs)?),
+            None => None,
+        };
+        let baseline = match &parent {
+            Some(parent) => parent.tree().map_err(vcs)?,
+            None => {
+                let empty = repo.treebuilder(None).and_then(|tree| tree.write());
+                repo.find_tree(empty.map_err(vcs)?).map_err(vcs)?
+            }
+        };
+        let tree = TreeUpdateBuilder::new()
+            .upsert(opened.file.as_path(), blob, FileMode::Blob)
+            .create_updated(repo, &baseline)
+            .and_then(|tree| repo.find_tree(tree))
+            .map_err(vcs)?;
+        let signature = self.signature(repo)?;
+        let parents: Vec<&Commit<'_>> = parent.iter().collect();
+        match repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            &message,
+            &tree,
+            &parents,
+        ) {
+            Err(err) if err.code() == ErrorCode::Modified => {
+                let stored = self.stored(&opened)?.backlog.unwrap_or_default();
+                return Err(BacklogRepositoryError::Conflict {
+                    loaded,
+                    stored: stored.revision(),
+                });
+            }
+            committed => committed.map_err(vcs)?,
+        };
+        Self::check_out(&opened, &content)
+    }
+
+    async fn migrate(&self, dry_run: bool) -> BacklogRepositoryResult<MigrationReport> {
+        // the repository is not shared across the save below.
+        let content = {
+            let opened = self.open()?;
+            let content = match Self::head(&opened.repo)? {
+                None => None,
+                Some(head) => Self::content(&opened.repo, &head, &opened.file)?,
+            };
+            content
+        };
+        let content = match content {
+            None => return Ok(MigrationReport::up_to_date()),
+            Some((_, content)) => content,
+        };
+        let mut document = self.codec.document(&content)?;
+        let report = backlog::migrate(&mut document)?;
+        if !dry_run && !report.is_up_to_date() {
+            let backlog = serde_json::from_value(document)
+                .map_err(|err| BacklogRepositoryError::parse(None, None, err.to_string()))?;
+            self.save_as(backlog, "Migrate the backlog").await?;
+        }
+        Ok(report)
+    }
+
+    /// Only the commits which have changed the data file are listed.
+    async fn history(&self, limit: usize) -> BacklogRepositoryResult<Vec<HistoryEntry>> {
+        let opened = self.open()?;
+        let repo = &opened.repo;
+        let vcs = BacklogRepositoryError::version_control;
+        if Self::head(repo)?.is_none() {
+            return Ok(Vec::new());
+        }
+        let mut walk = repo.revwalk().map_err(vcs)?;
+        walk.push_head().map_err(vcs)?;
+        walk.set_sorting(Sort::TOPOLOGICAL | Sort::TIME)
+            .map_err(vcs)?;
+
+        let mut history = Vec::new();
+        for id in walk {
+            if history.len() >= limit {
+                break;
+            }
+            let commit = repo.find_commit(id.map_err(vcs)?).map_err(vcs)?;
+            let content = Self::content(repo, &commit, &opened.file)?.map(|(blob, _)| blob);
+            let parent = match commit.parent(0) {
+                Ok(parent) => Self::content(repo, &parent, &opened.file)?.map(|(blob, _)| blob),
+                Err(_) => None,
+            };
+            if content.is_none() || content == parent {
+                continue;
+            }
+            let saved_at = Utc
+                .timestamp_opt(commit.time().seconds(), 0)
+                .single()
+                .unwrap_or_else(Utc::now);
+            history.push(HistoryEntry::new(
+                &commit.id().to_string(),
+                commit.message().unwrap_or_default().trim_end(),
+                commit.author().name().unwrap_or_default(),
+                saved_at,
+            ));
+        }
+        Ok(history)
+    }
+
+    /// The revisions are anything git can resolve, e.g. the ids, `HEAD~1` or the branches.
+    async fn diff(&self, from: &str, to: &str) -> BacklogRepositoryResult<String> {
+        let opened = self.open()?;
+        let repo = &opened.repo;
+        let (from, to) = (Self::resolve(repo, from)?, Self::resolve(repo, to)?);
+        let mut options = DiffOptions::new();
+        options.pathspec(opened.file.as_path());
+        let diff = repo
+            .diff_tree_to_tree(Some(&from), Some(&to), Some(&mut options))
+            .map_err(BacklogRepositoryError::version_control)?;
+
+        let mut patch = String::new();
+        diff.print(DiffFormat::Patch, |_, _, line| {
+            if matches!(line.origin(), '+' | '-' | ' ') {
+                patch.push(line.origin());
+            }
+            patch.push_str(&String::from_utf8_lossy(line.content()));
+            true
+        })
+        .map_err(BacklogRepositoryError::version_control)?;
+        Ok(patch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use backlog::{AddItem, Entity, EstimatableFromCollection, Story, StoryPoint};
+    use backlog_repo::contract;
+
+    use super::*;
+
+    fn repository() -> GitBacklogRepository {
+        let dir = std::env::temp_dir().join(format!("git-{}", Backlog::new().id()));
+        GitBacklogRepository::new(dir.join("backlog.yaml"))
+            .with_author(Author::new("Alice Liddell", "alice@example.com"))
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_contract() {
+        contract::run(repository).await;
+    }
+
+    #[tokio::test]
+    async fn test_commit_every_save() {
+        let repo = repository();
+        let mut backlog = repo.get().await.unwrap();
+        let story = Story::new("Login page", Some(StoryPoint::new(3).unwrap()), None);
+        let id = story.id();
+        backlog.add_item(Box::new(story));
+        repo.save(backlog).await.unwrap();
+        let mut backlog = repo.get().await.unwrap();
+        backlog
+            .estimate_item(&id, StoryPoint::new(5).unwrap())
+            .unwrap();
+        repo.save(backlog).await.unwrap();
+
+        let history = repo.history(10).await.unwrap();
+        let summaries: Vec<&str> = history.iter().map(|entry| entry.summary()).collect();
+        assert_eq!(
+            summaries,
+            ["Estimate \"Login page\": 3 → 5", "Add Story \"Login page\""]
+        );
+        assert_eq!(history[0].author(), "Alice Liddell");
+        assert_eq!(repo.history(1).await.unwrap().len(), 1);
+
+        let patch = repo.diff(history[1].id(), history[0].id()).await.unwrap();
+        assert!(patch.contains("-revision: 1\n+revision: 2\n"));
+        assert!(repo.diff("no-such-revision", "HEAD").await.is_err());
+
+        // the working tree has the committed file.
+        let content = std::fs::read_to_string(&repo.path).unwrap();
+        assert!(content.contains("revision: 2"));
+        std::fs::remove_dir_all(repo.path.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_name_the_commit_by_the_operation() {
+        let repo = repository();
+        let mut backlog = repo.get().await.unwrap();
+        backlog.add_item(Box::new(Story::new("Login page", None, None)));
+        backlog.add_item(Box::new(Story::new("Logout", None, None)));
+        repo.save_as(backlog, "Update the items in bulk")
+            .await
+            .unwrap();
+        let backlog = repo.get().await.unwrap();
+        repo.save_as(backlog, "Configure the board").await.unwrap();
+
+        let history = repo.history(10).await.unwrap();
+        let summaries: Vec<&str> = history.iter().map(|entry| entry.summary()).collect();
+        assert_eq!(
+            summaries,
+            ["Configure the board", "Update the items in bulk"]
+        );
+        std::fs::remove_dir_all(repo.path.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_refuse_to_overwrite_the_edited_file() {
+        let repo = repository();
+        let backlog = repo.get().await.unwrap();
+        repo.save(backlog).await.unwrap();
+        let edited = std::fs::read_to_string(&repo.path).unwrap() + "# edited\n";
+        std::fs::write(&repo.path, &edited).unwrap();
+
+        let backlog = repo.get().await.unwrap();
+        assert!(matches!(
+            repo.save(backlog).await,
+            Err(BacklogRepositoryError::VersionControl(_))
+        ));
+        assert_eq!(std::fs::read_to_string(&repo.path).unwrap(), edited);
+        assert_eq!(repo.history(10).await.unwrap().len(), 1);
+        std::fs::remove_dir_all(repo.path.parent().unwrap()).unwrap();
+    }
+}
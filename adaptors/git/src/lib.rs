@@ -0,0 +1,5 @@
+mod author;
+mod git_backlog_repository;
+
+pub use author::Author;
+pub use git_backlog_repository::GitBacklogRepository;
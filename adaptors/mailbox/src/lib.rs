@@ -0,0 +1,3 @@
+mod mailbox_notifier;
+
+pub use mailbox_notifier::MailboxNotifier;
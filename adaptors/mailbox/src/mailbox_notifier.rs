@@ -0,0 +1,99 @@
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use backlog::Notification;
+use notifier::{Notifier, NotifierError, NotifierResult};
+
+/// Write each notification as a JSON file into the mailbox of each recipient.
+///
+/// The mailbox of the recipient is the directory `<dir>/<handle>`,
+/// and the files are named after the time of the change, so they are listed in order.
+#[derive(Debug, Clone)]
+pub struct MailboxNotifier {
+    dir: PathBuf,
+}
+
+impl MailboxNotifier {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn deliver(&self, mailbox: &Path, name: &str, content: &[u8]) -> NotifierResult<PathBuf> {
+        std::fs::create_dir_all(mailbox)?;
+        for n in 0.. {
+            let path = match n {
+                0 => mailbox.join(format!("{}.json", name)),
+                n => mailbox.join(format!("{}-{}.json", name, n)),
+            };
+            // never overwrite the notification which has not been read yet.
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut file) => {
+                    file.write_all(content)?;
+                    return Ok(path);
+                }
+                Err(err) if err.kind() == ErrorKind::AlreadyExists => continue,
+                Err(err) => return Err(err.into()),
+            }
+        }
+        unreachable!()
+    }
+}
+
+#[async_trait::async_trait]
+impl Notifier for MailboxNotifier {
+    async fn notify(&self, notification: &Notification) -> NotifierResult<()> {
+        let content = serde_json::to_vec_pretty(notification)
+            .map_err(|err| NotifierError::serialize(err.to_string()))?;
+        let name = format!(
+            "{}-{}",
+            notification.event().at().format("%Y%m%dT%H%M%S%.9fZ"),
+            notification.item()
+        );
+        for recipient in notification.recipients() {
+            let mailbox = self.dir.join(recipient.name());
+            self.deliver(&mailbox, &name, &content)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use backlog::{
+        Assignee, Backlog, BacklogFixture, Entity, ProgressableFromCollection, Status,
+        WatchableFromCollection,
+    };
+
+    use super::*;
+
+    fn notification() -> Notification {
+        let (id, mut backlog) = Backlog::specific_id();
+        backlog.watch_item(&id, Assignee::new("dummy")).unwrap();
+        let before = backlog.clone();
+        backlog.change_item_status(&id, Status::Done).unwrap();
+        backlog.notifications(&before).remove(0)
+    }
+
+    #[tokio::test]
+    async fn test_notify() {
+        let dir = std::env::temp_dir().join(format!("mailbox-{}", Backlog::new().id()));
+        let notifier = MailboxNotifier::new(dir.clone());
+        let notification = notification();
+        notifier.notify(&notification).await.unwrap();
+        notifier.notify(&notification).await.unwrap();
+
+        let mut files: Vec<PathBuf> = std::fs::read_dir(dir.join("dummy"))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        files.sort();
+        // the same notification is delivered twice without overwriting.
+        assert_eq!(files.len(), 2);
+        let content = std::fs::read_to_string(&files[0]).unwrap();
+        let delivered: Notification = serde_json::from_str(&content).unwrap();
+        assert_eq!(delivered, notification);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
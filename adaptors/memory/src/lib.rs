@@ -0,0 +1,3 @@
+mod memory_backlog_repository;
+
+pub use memory_backlog_repository::MemoryBacklogRepository;
@@ -0,0 +1,88 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use backlog::Backlog;
+use backlog_repo::{BacklogRepository, BacklogRepositoryError, BacklogRepositoryResult};
+
+/// Keeps the backlog in memory, e.g. for the tests of the use cases.
+///
+/// The clones share the same backlog, and each save checks and advances
+/// the revision at once, so it needs no lock.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryBacklogRepository {
+    backlog: Arc<Mutex<Option<Backlog>>>,
+}
+
+impl MemoryBacklogRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The repository in which the backlog has already been saved as it is.
+    pub fn with_backlog(backlog: Backlog) -> Self {
+        Self {
+            backlog: Arc::new(Mutex::new(Some(backlog))),
+        }
+    }
+
+    /// No save panics halfway, so the backlog of the poisoned lock is still whole.
+    fn stored(&self) -> MutexGuard<'_, Option<Backlog>> {
+        self.backlog
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait::async_trait]
+impl BacklogRepository for MemoryBacklogRepository {
+    /// The new backlog is kept on the first access, so that every get returns the same one.
+    async fn get(&self) -> BacklogRepositoryResult<Backlog> {
+        Ok(self.stored().get_or_insert_with(Backlog::default).clone())
+    }
+
+    async fn save(&self, backlog: Backlog) -> BacklogRepositoryResult<()> {
+        let mut stored = self.stored();
+        let revision = stored.as_ref().map(Backlog::revision).unwrap_or_default();
+        if revision != backlog.revision() {
+            return Err(BacklogRepositoryError::Conflict {
+                loaded: backlog.revision(),
+                stored: revision,
+            });
+        }
+        *stored = Some(backlog.with_revision(revision + 1));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use backlog::{AddItem, Entity, Story};
+    use backlog_repo::contract;
+
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_contract() {
+        contract::run(MemoryBacklogRepository::new).await;
+    }
+
+    #[tokio::test]
+    async fn test_clones_share_backlog() {
+        let repo = MemoryBacklogRepository::with_backlog(Backlog::new().with_revision(4));
+        let clone = repo.clone();
+        let mut backlog = repo.get().await.unwrap();
+        backlog.add_item(Box::new(Story::new("Login page", None, None)));
+        clone.save(backlog).await.unwrap();
+
+        let saved = repo.get().await.unwrap();
+        assert_eq!(saved.revision(), 5);
+        assert_eq!(saved.items().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_same_new_backlog() {
+        let repo = MemoryBacklogRepository::new();
+        let backlog = repo.get().await.unwrap();
+        assert_eq!(repo.get().await.unwrap().id(), backlog.id());
+        assert_eq!(repo.clone().get().await.unwrap().id(), backlog.id());
+    }
+}
@@ -1,5 +1,5 @@
 use axum::{extract::Extension, Json};
-use backlog::{Assignee, Backlog, BacklogItem, Story, StoryPoint, Task};
+use backlog::{AddedItem, Assignee, BacklogItem, ItemKind, StoryPoint};
 use backlog_service::{AddItemCmd, BacklogUseCase, Command, IncommingError, IncommingResult};
 use serde::Deserialize;
 
@@ -9,7 +9,7 @@ use super::{RestAdaptor, RestError, RestResult};
 pub async fn add_item_handler(
     Extension(ctx): Extension<RestAdaptor>,
     Json(payload): Json<AddItemRequest>,
-) -> RestResult<Json<Backlog>> {
+) -> RestResult<Json<AddedItem>> {
     ctx.add_item(payload)
         .await
         .map(Json)
@@ -22,24 +22,26 @@ pub struct AddItemRequest {
     title: String,
     point: Option<u8>,
     assignee: Option<String>,
+    /// add the item even if it looks like any existing item.
+    #[serde(default)]
+    force: bool,
 }
 
 impl Command for AddItemRequest {}
 
 impl AddItemCmd for AddItemRequest {
     fn item(&self) -> IncommingResult<Box<dyn BacklogItem>> {
-        let point = self.point.map(StoryPoint::new).transpose().unwrap();
+        let point = self
+            .point
+            .map(StoryPoint::new)
+            .transpose()
+            .map_err(|err| IncommingError::invalid_value("StoryPoint", err.to_string()))?;
         let assignee = self.assignee.as_ref().map(|v| Assignee::new(v));
-        let item: Box<dyn BacklogItem> = match self.item_type.as_str() {
-            "Story" => Box::new(Story::new(&self.title, point, assignee)),
-            "Task" => Box::new(Task::new(&self.title, point, assignee)),
-            _ => {
-                return Err(IncommingError::invalid_value(
-                    "item_type",
-                    format!("the field does not take the value, {}", self.item_type),
-                ))
-            }
-        };
-        Ok(item)
+        ItemKind::create(&self.item_type, &self.title, point, assignee)
+            .map_err(|err| IncommingError::invalid_value("item_type", err.to_string()))
+    }
+
+    fn force(&self) -> IncommingResult<bool> {
+        Ok(self.force)
     }
 }
@@ -0,0 +1,171 @@
+use axum::{
+    extract::{Extension, Multipart, Path},
+    http::header,
+    response::Headers,
+    Json,
+};
+use backlog::{Assignee, Attachment, Backlog, Uuid};
+use backlog_service::{
+    AttachItemCmd, AttachmentCmd, AttachmentsCmd, BacklogUseCase, Command, IncommingError,
+    IncommingResult,
+};
+
+use super::{RestAdaptor, RestError, RestResult};
+
+/// Upload the file with the multipart form, whose fields are `file` and `uploader`.
+#[tracing::instrument(skip(multipart))]
+pub async fn attach_item_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path(item_id): Path<Uuid>,
+    mut multipart: Multipart,
+) -> RestResult<Json<Attachment>> {
+    let mut req = AttachItemRequest {
+        id: item_id,
+        filename: None,
+        content: None,
+        uploader: None,
+    };
+    while let Some(field) = multipart.next_field().await.map_err(invalid_form)? {
+        match field.name() {
+            Some("file") => {
+                req.filename = field.file_name().map(|name| name.to_string());
+                req.content = Some(field.bytes().await.map_err(invalid_form)?.to_vec());
+            }
+            Some("uploader") => req.uploader = Some(field.text().await.map_err(invalid_form)?),
+            _ => {}
+        }
+    }
+    ctx.attach_item(req)
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+#[tracing::instrument]
+pub async fn attachments_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path(item_id): Path<Uuid>,
+) -> RestResult<Json<Vec<Attachment>>> {
+    ctx.get_attachments(AttachmentsRequest { id: item_id })
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+#[tracing::instrument]
+pub async fn download_attachment_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path((item_id, attachment_id)): Path<(Uuid, Uuid)>,
+) -> RestResult<(Headers<Vec<(header::HeaderName, String)>>, Vec<u8>)> {
+    let req = AttachmentRequest {
+        id: item_id,
+        attachment: attachment_id,
+    };
+    let (attachment, content) = ctx
+        .download_attachment(req)
+        .await
+        .map_err(RestError::from)?;
+    // the header value must be visible ascii.
+    let filename: String = attachment
+        .filename()
+        .chars()
+        .map(|c| match c {
+            '"' | '\\' => '_',
+            c if c.is_ascii_graphic() || c == ' ' => c,
+            _ => '_',
+        })
+        .collect();
+    let headers = Headers(vec![
+        (header::CONTENT_TYPE, "application/octet-stream".to_string()),
+        (
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}\"", filename),
+        ),
+    ]);
+    Ok((headers, content))
+}
+
+#[tracing::instrument]
+pub async fn delete_attachment_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path((item_id, attachment_id)): Path<(Uuid, Uuid)>,
+) -> RestResult<Json<Backlog>> {
+    let req = AttachmentRequest {
+        id: item_id,
+        attachment: attachment_id,
+    };
+    ctx.delete_attachment(req)
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+fn invalid_form(err: impl std::fmt::Display) -> RestError {
+    RestError::from(eyre::Report::new(IncommingError::invalid_value(
+        "multipart",
+        err.to_string(),
+    )))
+}
+
+struct AttachItemRequest {
+    id: Uuid,
+    filename: Option<String>,
+    content: Option<Vec<u8>>,
+    uploader: Option<String>,
+}
+
+impl Command for AttachItemRequest {}
+
+impl AttachItemCmd for AttachItemRequest {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn filename(&self) -> IncommingResult<String> {
+        self.filename
+            .clone()
+            .ok_or_else(|| IncommingError::invalid_value("file", "the file has no filename"))
+    }
+
+    fn content(&self) -> IncommingResult<Vec<u8>> {
+        self.content
+            .clone()
+            .ok_or_else(|| IncommingError::invalid_value("file", "the field is required"))
+    }
+
+    fn uploader(&self) -> IncommingResult<Assignee> {
+        self.uploader
+            .as_ref()
+            .map(|uploader| Assignee::new(uploader))
+            .ok_or_else(|| IncommingError::invalid_value("uploader", "the field is required"))
+    }
+}
+
+struct AttachmentsRequest {
+    id: Uuid,
+}
+
+impl Command for AttachmentsRequest {}
+
+impl AttachmentsCmd for AttachmentsRequest {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+}
+
+struct AttachmentRequest {
+    id: Uuid,
+    attachment: Uuid,
+}
+
+impl Command for AttachmentRequest {}
+
+impl AttachmentCmd for AttachmentRequest {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn attachment(&self) -> IncommingResult<Uuid> {
+        Ok(self.attachment)
+    }
+}
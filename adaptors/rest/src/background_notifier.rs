@@ -0,0 +1,31 @@
+use backlog::Notification;
+use notifier::{Notifier, NotifierResult, Notifiers};
+
+/// Deliver the notifications in the background, so the response does not wait for the watchers.
+///
+/// The failure to deliver is only logged, because the change has already been saved.
+#[derive(Clone, Debug, Default)]
+pub struct BackgroundNotifier(Notifiers);
+
+impl BackgroundNotifier {
+    pub fn new(notifiers: Notifiers) -> Self {
+        Self(notifiers)
+    }
+}
+
+#[async_trait::async_trait]
+impl Notifier for BackgroundNotifier {
+    async fn notify(&self, notification: &Notification) -> NotifierResult<()> {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        let notifiers = self.0.clone();
+        let notification = notification.clone();
+        tokio::spawn(async move {
+            if let Err(err) = notifiers.notify(&notification).await {
+                tracing::warn!("fail to notify the watchers, {}", err);
+            }
+        });
+        Ok(())
+    }
+}
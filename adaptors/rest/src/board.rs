@@ -0,0 +1,71 @@
+use axum::{
+    extract::{Extension, Path, Query},
+    Json,
+};
+use backlog::{BacklogError, Board, Swimlane, Uuid};
+use backlog_service::{
+    BacklogUseCase, BoardCmd, Command, IncommingError, IncommingResult, MoveItemCmd,
+};
+use serde::Deserialize;
+
+use super::{RestAdaptor, RestError, RestResult};
+
+#[tracing::instrument]
+pub async fn board_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Query(query): Query<BoardRequest>,
+) -> RestResult<Json<Board>> {
+    ctx.board(query).await.map(Json).map_err(RestError::from)
+}
+
+#[tracing::instrument]
+pub async fn move_item_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path(item_id): Path<Uuid>,
+    Json(payload): Json<MoveItemPayload>,
+) -> RestResult<Json<Board>> {
+    let req = MoveItemRequest {
+        id: item_id,
+        column: payload.column,
+    };
+    ctx.move_item(req).await.map(Json).map_err(RestError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BoardRequest {
+    swimlane: Option<String>,
+}
+
+impl Command for BoardRequest {}
+
+impl BoardCmd for BoardRequest {
+    fn swimlane(&self) -> IncommingResult<Option<Swimlane>> {
+        self.swimlane
+            .as_ref()
+            .map(|swimlane| swimlane.parse())
+            .transpose()
+            .map_err(|err: BacklogError| IncommingError::invalid_value("swimlane", err.to_string()))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MoveItemPayload {
+    column: String,
+}
+
+struct MoveItemRequest {
+    id: Uuid,
+    column: String,
+}
+
+impl Command for MoveItemRequest {}
+
+impl MoveItemCmd for MoveItemRequest {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn column(&self) -> IncommingResult<String> {
+        Ok(self.column.clone())
+    }
+}
@@ -0,0 +1,104 @@
+use axum::{
+    body::Bytes,
+    extract::Extension,
+    http::{Method, StatusCode, Uri},
+    response::{IntoResponse, Response},
+    Json,
+};
+use backlog::{Assignee, BacklogError, BulkChange, BulkReport, StoryPoint, Uuid};
+use backlog_service::{BacklogUseCase, BulkCmd, Command, IncommingError, IncommingResult};
+use serde::Deserialize;
+
+use super::{RestAdaptor, RestError, RestResult};
+
+/// The router can not match `:` inside a path segment,
+/// so the custom methods such as `POST /backlog/items:bulk` are dispatched from the fallback.
+#[tracing::instrument]
+pub async fn custom_method_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    method: Method,
+    uri: Uri,
+    body: Bytes,
+) -> Response {
+    match (method, uri.path()) {
+        (Method::POST, "/backlog/items:bulk") => bulk(ctx, &body).await.into_response(),
+        _ => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// `POST /backlog/bulk`, the alias of `POST /backlog/items:bulk`.
+#[tracing::instrument]
+pub async fn bulk_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    body: Bytes,
+) -> RestResult<Json<BulkReport>> {
+    bulk(ctx, &body).await
+}
+
+async fn bulk(ctx: RestAdaptor, body: &[u8]) -> RestResult<Json<BulkReport>> {
+    let req: BulkRequest = serde_json::from_slice(body)
+        .map_err(|err| eyre::Error::new(IncommingError::invalid_value("body", err.to_string())))?;
+    ctx.bulk_update(req)
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+/// Exactly one of the changes must be given.
+#[derive(Debug, Deserialize)]
+pub struct BulkRequest {
+    ids: Vec<Uuid>,
+    assignee: Option<String>,
+    point: Option<u8>,
+    sprint: Option<Uuid>,
+    #[serde(default)]
+    unplan: bool,
+    status: Option<String>,
+    priority: Option<String>,
+}
+
+impl Command for BulkRequest {}
+
+impl BulkCmd for BulkRequest {
+    fn ids(&self) -> IncommingResult<Vec<Uuid>> {
+        Ok(self.ids.clone())
+    }
+
+    fn change(&self) -> IncommingResult<BulkChange> {
+        let invalid = |field: &'static str| {
+            move |err: BacklogError| IncommingError::invalid_value(field, err.to_string())
+        };
+        let mut changes = Vec::new();
+        if let Some(assignee) = &self.assignee {
+            changes.push(BulkChange::Assign(Assignee::new(assignee)));
+        }
+        if let Some(point) = self.point {
+            changes.push(BulkChange::Estimate(
+                StoryPoint::new(point).map_err(invalid("point"))?,
+            ));
+        }
+        if let Some(sprint) = self.sprint {
+            changes.push(BulkChange::Plan(Some(sprint)));
+        }
+        if self.unplan {
+            changes.push(BulkChange::Plan(None));
+        }
+        if let Some(status) = &self.status {
+            changes.push(BulkChange::ChangeStatus(
+                status.parse().map_err(invalid("status"))?,
+            ));
+        }
+        if let Some(priority) = &self.priority {
+            changes.push(BulkChange::Prioritize(
+                priority.parse().map_err(invalid("priority"))?,
+            ));
+        }
+        match changes.len() {
+            1 => Ok(changes.remove(0)),
+            _ => Err(IncommingError::invalid_value(
+                "change",
+                "exactly one of assignee, point, sprint, unplan, status or priority is required",
+            )),
+        }
+    }
+}
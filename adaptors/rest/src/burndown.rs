@@ -0,0 +1,29 @@
+use axum::{
+    extract::{Extension, Path},
+    Json,
+};
+use backlog::{Burndown, Uuid};
+use backlog_service::{BacklogUseCase, BurndownCmd, Command, IncommingResult};
+
+use super::{RestAdaptor, RestError, RestResult};
+
+#[tracing::instrument]
+pub async fn burndown_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path(sprint_id): Path<Uuid>,
+) -> RestResult<Json<Burndown>> {
+    let req = BurndownRequest { sprint: sprint_id };
+    ctx.burndown(req).await.map(Json).map_err(RestError::from)
+}
+
+struct BurndownRequest {
+    sprint: Uuid,
+}
+
+impl Command for BurndownRequest {}
+
+impl BurndownCmd for BurndownRequest {
+    fn sprint(&self) -> IncommingResult<Uuid> {
+        Ok(self.sprint)
+    }
+}
@@ -0,0 +1,142 @@
+use axum::{
+    extract::{Extension, Path},
+    Json,
+};
+use backlog::{Directory, TeamProfile, User};
+use backlog_service::{
+    AddTeamCmd, AddUserCmd, BacklogUseCase, Command, IncommingError, IncommingResult,
+    UpdateTeamCmd, UpdateUserCmd,
+};
+use serde::Deserialize;
+
+use super::{RestAdaptor, RestError, RestResult};
+
+#[tracing::instrument]
+pub async fn directory_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+) -> RestResult<Json<Directory>> {
+    ctx.get_directory().await.map(Json).map_err(RestError::from)
+}
+
+#[tracing::instrument]
+pub async fn add_user_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Json(payload): Json<AddProfileRequest>,
+) -> RestResult<Json<Directory>> {
+    ctx.add_user(payload)
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+#[tracing::instrument]
+pub async fn update_user_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path(handle): Path<String>,
+    Json(payload): Json<UpdateProfileRequest>,
+) -> RestResult<Json<Directory>> {
+    let req = UpdateRequest { handle, payload };
+    ctx.update_user(req)
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+#[tracing::instrument]
+pub async fn add_team_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Json(payload): Json<AddProfileRequest>,
+) -> RestResult<Json<Directory>> {
+    ctx.add_team(payload)
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+#[tracing::instrument]
+pub async fn update_team_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path(handle): Path<String>,
+    Json(payload): Json<UpdateProfileRequest>,
+) -> RestResult<Json<Directory>> {
+    let req = UpdateRequest { handle, payload };
+    ctx.update_team(req)
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AddProfileRequest {
+    handle: String,
+    display_name: String,
+}
+
+impl Command for AddProfileRequest {}
+
+impl AddUserCmd for AddProfileRequest {
+    fn user(&self) -> IncommingResult<User> {
+        User::new(&self.handle, &self.display_name)
+            .map_err(|err| IncommingError::invalid_value("handle", err.to_string()))
+    }
+}
+
+impl AddTeamCmd for AddProfileRequest {
+    fn team(&self) -> IncommingResult<TeamProfile> {
+        TeamProfile::new(&self.handle, &self.display_name)
+            .map_err(|err| IncommingError::invalid_value("handle", err.to_string()))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateProfileRequest {
+    /// only users can be renamed.
+    handle: Option<String>,
+    display_name: Option<String>,
+    active: Option<bool>,
+}
+
+struct UpdateRequest {
+    handle: String,
+    payload: UpdateProfileRequest,
+}
+
+impl Command for UpdateRequest {}
+
+impl UpdateUserCmd for UpdateRequest {
+    fn handle(&self) -> IncommingResult<String> {
+        Ok(self.handle.clone())
+    }
+
+    fn new_handle(&self) -> IncommingResult<Option<String>> {
+        Ok(self.payload.handle.clone())
+    }
+
+    fn display_name(&self) -> IncommingResult<Option<String>> {
+        Ok(self.payload.display_name.clone())
+    }
+
+    fn active(&self) -> IncommingResult<Option<bool>> {
+        Ok(self.payload.active)
+    }
+}
+
+impl UpdateTeamCmd for UpdateRequest {
+    fn handle(&self) -> IncommingResult<String> {
+        Ok(self.handle.clone())
+    }
+
+    fn display_name(&self) -> IncommingResult<Option<String>> {
+        Ok(self.payload.display_name.clone())
+    }
+
+    fn active(&self) -> IncommingResult<Option<bool>> {
+        if self.payload.handle.is_some() {
+            return Err(IncommingError::invalid_value(
+                "handle",
+                "the team can not be renamed",
+            ));
+        }
+        Ok(self.payload.active)
+    }
+}
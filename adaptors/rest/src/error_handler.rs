@@ -21,6 +21,9 @@ impl IntoResponse for RestError {
         let (status, msg) = if err.downcast_ref::<IncommingError>().is_some() {
             tracing::error!("BAD REQUEST: {:?}", err);
             (StatusCode::BAD_REQUEST, format!("{:?}", err))
+        } else if matches!(err.downcast_ref::<OutcommingError>(), Some(e) if e.is_conflict()) {
+            tracing::error!("CONFLICT: {:?}", err);
+            (StatusCode::CONFLICT, format!("{:?}", err))
         } else if err.downcast_ref::<OutcommingError>().is_some() {
             tracing::error!("INTERNAL_SERVER_ERROR: {:?}", err);
             (StatusCode::INTERNAL_SERVER_ERROR, format!("{:?}", err))
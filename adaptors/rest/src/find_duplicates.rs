@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Extension, Query},
+    Json,
+};
+use backlog::DuplicateCluster;
+use backlog_service::{BacklogUseCase, Command, FindDuplicatesCmd, IncommingResult};
+use serde::Deserialize;
+
+use super::{RestAdaptor, RestError, RestResult};
+
+#[tracing::instrument]
+pub async fn find_duplicates_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Query(query): Query<FindDuplicatesRequest>,
+) -> RestResult<Json<Vec<DuplicateCluster>>> {
+    ctx.find_duplicates(query)
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FindDuplicatesRequest {
+    /// from 0 to 1
+    threshold: Option<f64>,
+}
+
+impl Command for FindDuplicatesRequest {}
+
+impl FindDuplicatesCmd for FindDuplicatesRequest {
+    fn threshold(&self) -> IncommingResult<Option<f64>> {
+        Ok(self.threshold)
+    }
+}
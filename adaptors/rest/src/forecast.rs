@@ -0,0 +1,87 @@
+use axum::{
+    extract::{Extension, Query},
+    Json,
+};
+use backlog::{BacklogError, Forecast, ForecastBasis, ItemsForecast, NaiveDate, Simulation, Team};
+use backlog_service::{
+    BacklogUseCase, Command, ForecastCmd, ForecastItemsCmd, IncommingError, IncommingResult,
+};
+use serde::Deserialize;
+
+use super::{RestAdaptor, RestError, RestResult};
+
+const DEFAULT_TRIALS: usize = 10000;
+
+#[tracing::instrument]
+pub async fn forecast_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Query(query): Query<ForecastRequest>,
+) -> RestResult<Json<Forecast>> {
+    ctx.forecast(query).await.map(Json).map_err(RestError::from)
+}
+
+#[tracing::instrument]
+pub async fn forecast_items_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Query(query): Query<ForecastItemsRequest>,
+) -> RestResult<Json<ItemsForecast>> {
+    ctx.forecast_items(query)
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ForecastRequest {
+    basis: Option<String>,
+    seed: Option<u64>,
+    trials: Option<usize>,
+    team: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ForecastItemsRequest {
+    date: NaiveDate,
+    seed: Option<u64>,
+    trials: Option<usize>,
+    team: Option<String>,
+}
+
+fn simulation(
+    seed: Option<u64>,
+    trials: Option<usize>,
+    team: &Option<String>,
+) -> IncommingResult<Simulation> {
+    let team = team.as_ref().map(|team| Team::new(team));
+    Simulation::new(seed, trials.unwrap_or(DEFAULT_TRIALS), team)
+        .map_err(|err| IncommingError::invalid_value("trials", err.to_string()))
+}
+
+impl Command for ForecastRequest {}
+
+impl ForecastCmd for ForecastRequest {
+    fn basis(&self) -> IncommingResult<ForecastBasis> {
+        match &self.basis {
+            None => Ok(ForecastBasis::Velocity),
+            Some(basis) => basis.parse().map_err(|err: BacklogError| {
+                IncommingError::invalid_value("basis", err.to_string())
+            }),
+        }
+    }
+
+    fn simulation(&self) -> IncommingResult<Simulation> {
+        simulation(self.seed, self.trials, &self.team)
+    }
+}
+
+impl Command for ForecastItemsRequest {}
+
+impl ForecastItemsCmd for ForecastItemsRequest {
+    fn date(&self) -> IncommingResult<NaiveDate> {
+        Ok(self.date)
+    }
+
+    fn simulation(&self) -> IncommingResult<Simulation> {
+        simulation(self.seed, self.trials, &self.team)
+    }
+}
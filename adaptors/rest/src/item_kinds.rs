@@ -0,0 +1,7 @@
+use axum::Json;
+use backlog::ItemKind;
+
+#[tracing::instrument]
+pub async fn item_kinds_handler() -> Json<&'static [ItemKind]> {
+    Json(ItemKind::all())
+}
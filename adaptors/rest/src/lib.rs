@@ -1,37 +1,122 @@
 mod add_item;
+mod attachment;
+mod background_notifier;
 mod backlog;
+mod board;
+mod bulk;
+mod burndown;
+mod directory;
 mod error_handler;
+mod find_duplicates;
+mod forecast;
+mod item_kinds;
+mod list_items;
+mod rank_item;
+mod release;
+mod router;
+mod split_item;
+mod sprint;
 mod update_item;
+mod velocity;
+mod watch_item;
 
 pub use crate::backlog::backlog_handler;
 pub use add_item::add_item_handler;
+pub use attachment::{
+    attach_item_handler, attachments_handler, delete_attachment_handler,
+    download_attachment_handler,
+};
 pub use axum;
+pub use background_notifier::BackgroundNotifier;
+pub use board::{board_handler, move_item_handler};
+pub use bulk::{bulk_handler, custom_method_handler};
+pub use burndown::burndown_handler;
+pub use directory::{
+    add_team_handler, add_user_handler, directory_handler, update_team_handler, update_user_handler,
+};
 pub use error_handler::{RestError, RestResult};
+pub use find_duplicates::find_duplicates_handler;
+pub use forecast::{forecast_handler, forecast_items_handler};
+pub use item_kinds::item_kinds_handler;
+pub use list_items::list_items_handler;
+pub use notifier::Notifiers;
+pub use rank_item::rank_item_handler;
+pub use release::{
+    add_release_handler, release_notes_handler, releases_handler, set_fix_version_handler,
+    ship_release_handler, unresolved_items_handler,
+};
+pub use router::router;
+pub use split_item::split_item_handler;
+pub use sprint::{add_sprint_handler, plan_item_handler, sprints_handler};
 pub use update_item::update_item_handler;
+pub use velocity::velocity_handler;
+pub use watch_item::{unwatch_item_handler, watch_item_handler};
 
-use backlog_repo::ProvideBacklogRepository;
+use std::sync::Arc;
+
+use backlog_repo::{BacklogRepository, ProvideBacklogRepository};
 use backlog_service::BacklogUseCase;
-use fs::FsBacklogRepository;
+use blob_storage::ProvideBlobStorage;
+use fs::FsBlobStorage;
+use notifier::ProvideNotifier;
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct RestAdaptor {
-    fs: FsBacklogRepository,
+    repo: Arc<dyn BacklogRepository + Send + Sync>,
+    blobs: FsBlobStorage,
+    notifiers: BackgroundNotifier,
 }
 
 impl RestAdaptor {
-    pub fn new(path: &str) -> Self {
+    /// `repo` stores the backlog in `path`, and the attachments are kept beside it.
+    pub fn new(path: &str, repo: Arc<dyn BacklogRepository + Send + Sync>) -> Self {
+        Self {
+            repo,
+            blobs: FsBlobStorage::beside(std::path::Path::new(path)),
+            notifiers: BackgroundNotifier::default(),
+        }
+    }
+
+    /// the watchers are notified in the background, after the response.
+    pub fn with_notifiers(self, notifiers: Notifiers) -> Self {
         Self {
-            fs: FsBacklogRepository::new(path.into()),
+            notifiers: BackgroundNotifier::new(notifiers),
+            ..self
         }
     }
 }
 
+impl std::fmt::Debug for RestAdaptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RestAdaptor")
+            .field("blobs", &self.blobs)
+            .field("notifiers", &self.notifiers)
+            .finish_non_exhaustive()
+    }
+}
+
 impl BacklogUseCase for RestAdaptor {}
 
 impl ProvideBacklogRepository for RestAdaptor {
-    type Repository = FsBacklogRepository;
+    type Repository = Arc<dyn BacklogRepository + Send + Sync>;
 
     fn provide(&self) -> &Self::Repository {
-        &self.fs
+        &self.repo
+    }
+}
+
+impl ProvideBlobStorage for RestAdaptor {
+    type BlobStorage = FsBlobStorage;
+
+    fn blob_storage(&self) -> &Self::BlobStorage {
+        &self.blobs
+    }
+}
+
+impl ProvideNotifier for RestAdaptor {
+    type Notifier = BackgroundNotifier;
+
+    fn notifier(&self) -> &Self::Notifier {
+        &self.notifiers
     }
 }
@@ -0,0 +1,39 @@
+use axum::{
+    extract::{Extension, Query},
+    Json,
+};
+use backlog::{BacklogError, BacklogItem, ItemOrder};
+use backlog_service::{BacklogUseCase, Command, IncommingError, IncommingResult, ListItemsCmd};
+use serde::Deserialize;
+
+use super::{RestAdaptor, RestError, RestResult};
+
+#[tracing::instrument]
+pub async fn list_items_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Query(query): Query<ListItemsRequest>,
+) -> RestResult<Json<Vec<Box<dyn BacklogItem>>>> {
+    ctx.list_items(query)
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListItemsRequest {
+    /// rank or priority
+    sort: Option<String>,
+}
+
+impl Command for ListItemsRequest {}
+
+impl ListItemsCmd for ListItemsRequest {
+    fn order(&self) -> IncommingResult<ItemOrder> {
+        match &self.sort {
+            None => Ok(ItemOrder::Rank),
+            Some(sort) => sort.parse().map_err(|err: BacklogError| {
+                IncommingError::invalid_value("sort", err.to_string())
+            }),
+        }
+    }
+}
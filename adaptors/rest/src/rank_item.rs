@@ -0,0 +1,60 @@
+use axum::{
+    extract::{Extension, Path},
+    Json,
+};
+use backlog::{Backlog, Placement, Uuid};
+use backlog_service::{BacklogUseCase, Command, IncommingError, IncommingResult, RankItemCmd};
+use serde::Deserialize;
+
+use super::{RestAdaptor, RestError, RestResult};
+
+#[tracing::instrument]
+pub async fn rank_item_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path(item_id): Path<Uuid>,
+    Json(payload): Json<RankItemPayload>,
+) -> RestResult<Json<Backlog>> {
+    let req = RankItemRequest {
+        id: item_id,
+        payload,
+    };
+    ctx.rank_item(req).await.map(Json).map_err(RestError::from)
+}
+
+/// Exactly one of the fields must be given.
+#[derive(Debug, Deserialize)]
+pub struct RankItemPayload {
+    #[serde(default)]
+    top: bool,
+    #[serde(default)]
+    bottom: bool,
+    after: Option<Uuid>,
+    before: Option<Uuid>,
+}
+
+struct RankItemRequest {
+    id: Uuid,
+    payload: RankItemPayload,
+}
+
+impl Command for RankItemRequest {}
+
+impl RankItemCmd for RankItemRequest {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn placement(&self) -> IncommingResult<Placement> {
+        let payload = &self.payload;
+        match (payload.top, payload.bottom, payload.after, payload.before) {
+            (true, false, None, None) => Ok(Placement::Top),
+            (false, true, None, None) => Ok(Placement::Bottom),
+            (false, false, Some(after), None) => Ok(Placement::After(after)),
+            (false, false, None, Some(before)) => Ok(Placement::Before(before)),
+            _ => Err(IncommingError::invalid_value(
+                "placement",
+                "exactly one of top, bottom, after or before is required",
+            )),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use axum::{
+    extract::{Extension, Path},
+    Json,
+};
+use backlog::{Backlog, NaiveDate, Release, ReleaseNotes, UnresolvedItems, Uuid};
+use backlog_service::{
+    AddReleaseCmd, BacklogUseCase, Command, IncommingError, IncommingResult, ReleaseCmd,
+    SetFixVersionCmd,
+};
+use serde::Deserialize;
+
+use super::{RestAdaptor, RestError, RestResult};
+
+#[tracing::instrument]
+pub async fn releases_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+) -> RestResult<Json<Vec<Release>>> {
+    ctx.get_releases().await.map(Json).map_err(RestError::from)
+}
+
+#[tracing::instrument]
+pub async fn add_release_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Json(payload): Json<AddReleaseRequest>,
+) -> RestResult<Json<Release>> {
+    ctx.add_release(payload)
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+#[tracing::instrument]
+pub async fn set_fix_version_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path(item_id): Path<Uuid>,
+    Json(payload): Json<SetFixVersionPayload>,
+) -> RestResult<Json<Backlog>> {
+    let req = SetFixVersionRequest {
+        id: item_id,
+        payload,
+    };
+    ctx.set_fix_version(req)
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+#[tracing::instrument]
+pub async fn release_notes_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path(name): Path<String>,
+) -> RestResult<Json<ReleaseNotes>> {
+    ctx.release_notes(ReleaseRequest { name })
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+#[tracing::instrument]
+pub async fn unresolved_items_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path(name): Path<String>,
+) -> RestResult<Json<UnresolvedItems>> {
+    ctx.unresolved_items(ReleaseRequest { name })
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+#[tracing::instrument]
+pub async fn ship_release_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path(name): Path<String>,
+) -> RestResult<Json<Release>> {
+    ctx.ship_release(ReleaseRequest { name })
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AddReleaseRequest {
+    name: String,
+    target: NaiveDate,
+}
+
+impl Command for AddReleaseRequest {}
+
+impl AddReleaseCmd for AddReleaseRequest {
+    fn release(&self) -> IncommingResult<Release> {
+        Release::new(&self.name, self.target)
+            .map_err(|err| IncommingError::invalid_value("name", err.to_string()))
+    }
+}
+
+/// `null` release clears the fix version.
+#[derive(Debug, Deserialize)]
+pub struct SetFixVersionPayload {
+    release: Option<String>,
+}
+
+struct SetFixVersionRequest {
+    id: Uuid,
+    payload: SetFixVersionPayload,
+}
+
+impl Command for SetFixVersionRequest {}
+
+impl SetFixVersionCmd for SetFixVersionRequest {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn release(&self) -> IncommingResult<Option<String>> {
+        Ok(self.payload.release.clone())
+    }
+}
+
+struct ReleaseRequest {
+    name: String,
+}
+
+impl Command for ReleaseRequest {}
+
+impl ReleaseCmd for ReleaseRequest {
+    fn name(&self) -> IncommingResult<String> {
+        Ok(self.name.clone())
+    }
+}
@@ -0,0 +1,235 @@
+use axum::{
+    handler::Handler,
+    routing::{get, post, put},
+    Router,
+};
+
+use super::*;
+
+/// Route every endpoint to its handler, which takes the `RestAdaptor` from the extension.
+pub fn router() -> Router {
+    Router::new()
+        .route("/backlog", get(backlog_handler))
+        .route(
+            "/backlog/items",
+            get(list_items_handler).post(add_item_handler),
+        )
+        .route("/backlog/bulk", post(bulk_handler))
+        .route("/backlog/items/:item_id", put(update_item_handler))
+        .route("/backlog/items/:item_id/rank", put(rank_item_handler))
+        .route("/backlog/items/:item_id/split", post(split_item_handler))
+        .route(
+            "/backlog/items/:item_id/fix-version",
+            put(set_fix_version_handler),
+        )
+        .route("/backlog/items/:item_id/sprint", put(plan_item_handler))
+        .route(
+            "/backlog/items/:item_id/attachments",
+            get(attachments_handler).post(attach_item_handler),
+        )
+        .route(
+            "/backlog/items/:item_id/attachments/:attachment_id",
+            get(download_attachment_handler).delete(delete_attachment_handler),
+        )
+        .route(
+            "/backlog/items/:item_id/watchers/:handle",
+            put(watch_item_handler).delete(unwatch_item_handler),
+        )
+        .route("/backlog/item-kinds", get(item_kinds_handler))
+        .route("/backlog/duplicates", get(find_duplicates_handler))
+        .route("/board", get(board_handler))
+        .route("/board/items/:item_id", put(move_item_handler))
+        .route("/sprints", get(sprints_handler).post(add_sprint_handler))
+        .route("/releases", get(releases_handler).post(add_release_handler))
+        .route("/releases/:name/notes", get(release_notes_handler))
+        .route("/releases/:name/unresolved", get(unresolved_items_handler))
+        .route("/releases/:name/ship", post(ship_release_handler))
+        .route("/directory", get(directory_handler))
+        .route("/directory/users", post(add_user_handler))
+        .route("/directory/users/:handle", put(update_user_handler))
+        .route("/directory/teams", post(add_team_handler))
+        .route("/directory/teams/:handle", put(update_team_handler))
+        .route("/reports/velocity", get(velocity_handler))
+        .route("/reports/burndown/:sprint_id", get(burndown_handler))
+        .route("/reports/forecast", get(forecast_handler))
+        .route("/reports/forecast/items", get(forecast_items_handler))
+        .fallback(custom_method_handler.into_service())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use axum::{
+        http::{header, Method, Request, StatusCode},
+        AddExtensionLayer,
+    };
+    use backlog::{AddItem, Backlog, Entity, Story, Uuid};
+    use backlog_repo::BacklogRepository;
+    use fs::FsBacklogRepository;
+    use hyper::Body;
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    /// Serve the backlog which has a story in the temporary directory, and return the story.
+    async fn adaptor() -> (PathBuf, RestAdaptor, Uuid) {
+        let dir = std::env::temp_dir().join(format!("rest-{}", Backlog::new().id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("data.yaml");
+        let repo = FsBacklogRepository::new(path.clone());
+
+        let mut backlog = Backlog::new();
+        let story = Story::new("Login page", None, None);
+        let id = story.id();
+        backlog.add_item(Box::new(story));
+        repo.save(backlog).await.unwrap();
+
+        let ctx = RestAdaptor::new(path.to_str().unwrap(), Arc::new(repo));
+        (dir, ctx, id)
+    }
+
+    async fn send(
+        ctx: &RestAdaptor,
+        method: Method,
+        uri: &str,
+        content_type: &str,
+        body: Body,
+    ) -> (StatusCode, Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, content_type)
+            .body(body)
+            .unwrap();
+        let response = router()
+            .layer(AddExtensionLayer::new(ctx.clone()))
+            .oneshot(request)
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+    }
+
+    async fn get_json(ctx: &RestAdaptor, uri: &str) -> (StatusCode, Value) {
+        send(ctx, Method::GET, uri, "application/json", Body::empty()).await
+    }
+
+    async fn post_json(ctx: &RestAdaptor, uri: &str, body: Value) -> (StatusCode, Value) {
+        let body = Body::from(body.to_string());
+        send(ctx, Method::POST, uri, "application/json", body).await
+    }
+
+    #[tokio::test]
+    async fn test_bulk() {
+        let (dir, ctx, id) = adaptor().await;
+
+        let body = json!({ "ids": [id], "assignee": "bob" });
+        let (status, report) = post_json(&ctx, "/backlog/items:bulk", body).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(report["results"][0]["id"], json!(id));
+        assert_eq!(report["results"][0]["outcome"]["type"], "Changed");
+
+        let body = json!({ "ids": [id], "assignee": "bob" });
+        let (status, report) = post_json(&ctx, "/backlog/bulk", body).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(report["results"][0]["outcome"]["type"], "Unchanged");
+
+        let body = json!({ "ids": [id], "assignee": "bob", "point": 3 });
+        let (status, error) = post_json(&ctx, "/backlog/items:bulk", body).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(error["error"].is_string());
+
+        let (status, _) = post_json(&ctx, "/backlog/items:unknown", json!({})).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_split_item() {
+        let (dir, ctx, id) = adaptor().await;
+
+        let uri = format!("/backlog/items/{}/split", id);
+        let body = json!({ "titles": ["Login form", "Login API"] });
+        let (status, backlog) = post_json(&ctx, &uri, body).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(backlog["items"].as_object().unwrap().len(), 3);
+
+        let (status, _) = post_json(&ctx, &uri, json!({})).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_board() {
+        let (dir, ctx, _) = adaptor().await;
+
+        let (status, board) = get_json(&ctx, "/board").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(board["columns"].is_array());
+
+        let (status, _) = get_json(&ctx, "/board?swimlane=unknown").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_releases() {
+        let (dir, ctx, _) = adaptor().await;
+
+        let body = json!({ "name": "1.0.0", "target": "2022-03-31" });
+        let (status, release) = post_json(&ctx, "/releases", body).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(release["name"], "1.0.0");
+
+        let (status, releases) = get_json(&ctx, "/releases").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(releases, json!([release]));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_reports() {
+        let (dir, ctx, _) = adaptor().await;
+
+        let (status, report) = get_json(&ctx, "/reports/velocity?window=2").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(report["window"], 2);
+
+        let uri = format!("/reports/burndown/{}", Uuid::new_v4());
+        let (status, error) = get_json(&ctx, &uri).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(error["error"].is_string());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_attachments() {
+        let (dir, ctx, id) = adaptor().await;
+
+        let uri = format!("/backlog/items/{}/attachments", id);
+        let body = concat!(
+            "--boundary\r\n",
+            "Content-Disposition: form-data; name=\"uploader\"\r\n\r\n",
+            "alice\r\n",
+            "--boundary\r\n",
+            "Content-Disposition: form-data; name=\"file\"; filename=\"notes.txt\"\r\n",
+            "Content-Type: text/plain\r\n\r\n",
+            "hello\r\n",
+            "--boundary--\r\n",
+        );
+        let content_type = "multipart/form-data; boundary=boundary";
+        let (status, attachment) =
+            send(&ctx, Method::POST, &uri, content_type, Body::from(body)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(attachment["filename"], "notes.txt");
+        assert_eq!(attachment["size"], 5);
+
+        let (status, attachments) = get_json(&ctx, &uri).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(attachments, json!([attachment]));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
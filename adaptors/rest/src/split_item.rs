@@ -0,0 +1,53 @@
+use axum::{
+    extract::{Extension, Path},
+    Json,
+};
+use backlog::{Backlog, SplitInto, Uuid};
+use backlog_service::{BacklogUseCase, Command, IncommingError, IncommingResult, SplitItemCmd};
+use serde::Deserialize;
+
+use super::{RestAdaptor, RestError, RestResult};
+
+#[tracing::instrument]
+pub async fn split_item_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path(item_id): Path<Uuid>,
+    Json(payload): Json<SplitItemPayload>,
+) -> RestResult<Json<Backlog>> {
+    let req = SplitItemRequest {
+        id: item_id,
+        payload,
+    };
+    ctx.split_item(req).await.map(Json).map_err(RestError::from)
+}
+
+/// Exactly one of the fields must be given.
+#[derive(Debug, Deserialize)]
+pub struct SplitItemPayload {
+    titles: Option<Vec<String>>,
+    count: Option<usize>,
+}
+
+struct SplitItemRequest {
+    id: Uuid,
+    payload: SplitItemPayload,
+}
+
+impl Command for SplitItemRequest {}
+
+impl SplitItemCmd for SplitItemRequest {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn split_into(&self) -> IncommingResult<SplitInto> {
+        match (&self.payload.titles, self.payload.count) {
+            (Some(titles), None) => Ok(SplitInto::Titles(titles.clone())),
+            (None, Some(count)) => Ok(SplitInto::Count(count)),
+            _ => Err(IncommingError::invalid_value(
+                "into",
+                "exactly one of titles or count is required",
+            )),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use axum::{
+    extract::{Extension, Path},
+    Json,
+};
+use backlog::{Backlog, NaiveDate, Sprint, Team, Uuid};
+use backlog_service::{
+    AddSprintCmd, BacklogUseCase, Command, IncommingError, IncommingResult, PlanItemCmd,
+};
+use serde::Deserialize;
+
+use super::{RestAdaptor, RestError, RestResult};
+
+#[tracing::instrument]
+pub async fn sprints_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+) -> RestResult<Json<Vec<Sprint>>> {
+    ctx.get_sprints().await.map(Json).map_err(RestError::from)
+}
+
+#[tracing::instrument]
+pub async fn add_sprint_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Json(payload): Json<AddSprintRequest>,
+) -> RestResult<Json<Sprint>> {
+    ctx.add_sprint(payload)
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+#[tracing::instrument]
+pub async fn plan_item_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path(item_id): Path<Uuid>,
+    Json(payload): Json<PlanItemPayload>,
+) -> RestResult<Json<Backlog>> {
+    let req = PlanItemRequest {
+        id: item_id,
+        payload,
+    };
+    ctx.plan_item(req).await.map(Json).map_err(RestError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AddSprintRequest {
+    name: String,
+    team: String,
+    start: NaiveDate,
+    /// the last day of the sprint.
+    end: NaiveDate,
+}
+
+impl Command for AddSprintRequest {}
+
+impl AddSprintCmd for AddSprintRequest {
+    fn sprint(&self) -> IncommingResult<Sprint> {
+        Sprint::new(&self.name, Team::new(&self.team), self.start, self.end)
+            .map_err(|err| IncommingError::invalid_value("end", err.to_string()))
+    }
+}
+
+/// `null` sprint moves the item out of any sprint.
+#[derive(Debug, Deserialize)]
+pub struct PlanItemPayload {
+    sprint: Option<Uuid>,
+}
+
+struct PlanItemRequest {
+    id: Uuid,
+    payload: PlanItemPayload,
+}
+
+impl Command for PlanItemRequest {}
+
+impl PlanItemCmd for PlanItemRequest {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn sprint(&self) -> IncommingResult<Option<Uuid>> {
+        Ok(self.payload.sprint)
+    }
+}
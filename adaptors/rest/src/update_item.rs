@@ -2,11 +2,12 @@ use axum::{
     extract::{Extension, Path},
     Json,
 };
-use backlog::{Assignee, Backlog, StoryPoint, Uuid};
+use backlog::{Assignee, Backlog, BacklogError, Priority, StoryPoint, Uuid};
 use backlog_service::{
     AssignItemCmd, BacklogUseCase, Command, EstimateItemCmd, IncommingError, IncommingResult,
+    LabelItemCmd, LinkItemCmd, PrioritizeItemCmd, SetEpicCmd,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 
 use super::{RestAdaptor, RestError, RestResult};
 
@@ -36,6 +37,36 @@ pub async fn update_item_handler(
             .map(Json)
             .map_err(RestError::from);
     }
+
+    if let Some(priority) = payload.priority {
+        let req = PrioritizeRequest {
+            id: item_id,
+            priority,
+        };
+        return ctx
+            .prioritize_item(req)
+            .await
+            .map(Json)
+            .map_err(RestError::from);
+    }
+
+    if let Some(labels) = payload.labels {
+        let req = LabelRequest {
+            id: item_id,
+            labels,
+        };
+        return ctx.label_item(req).await.map(Json).map_err(RestError::from);
+    }
+
+    if let Some(epic) = payload.epic {
+        let req = SetEpicRequest { id: item_id, epic };
+        return ctx.set_epic(req).await.map(Json).map_err(RestError::from);
+    }
+
+    if let Some(links) = payload.links {
+        let req = LinkRequest { id: item_id, links };
+        return ctx.link_item(req).await.map(Json).map_err(RestError::from);
+    }
     ctx.get_backlog().await.map(Json).map_err(RestError::from)
 }
 
@@ -43,6 +74,21 @@ pub async fn update_item_handler(
 pub struct UpdateItemRequest {
     point: Option<u8>,
     assignee: Option<String>,
+    priority: Option<String>,
+    labels: Option<Vec<String>>,
+    /// `null` removes the epic, while the missing field leaves it.
+    #[serde(default, deserialize_with = "present")]
+    epic: Option<Option<Uuid>>,
+    links: Option<Vec<Uuid>>,
+}
+
+/// Tell the field which is `null` from the missing one.
+fn present<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(deserializer).map(Some)
 }
 
 struct EstimateRequest {
@@ -79,3 +125,73 @@ impl AssignItemCmd for AssignRequest {
         Ok(Assignee::new(&self.assignee))
     }
 }
+
+struct PrioritizeRequest {
+    id: Uuid,
+    priority: String,
+}
+
+impl Command for PrioritizeRequest {}
+
+impl PrioritizeItemCmd for PrioritizeRequest {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn priority(&self) -> IncommingResult<Priority> {
+        self.priority
+            .parse()
+            .map_err(|err: BacklogError| IncommingError::invalid_value("priority", err.to_string()))
+    }
+}
+
+struct LabelRequest {
+    id: Uuid,
+    labels: Vec<String>,
+}
+
+impl Command for LabelRequest {}
+
+impl LabelItemCmd for LabelRequest {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn labels(&self) -> IncommingResult<Vec<String>> {
+        Ok(self.labels.clone())
+    }
+}
+
+struct SetEpicRequest {
+    id: Uuid,
+    epic: Option<Uuid>,
+}
+
+impl Command for SetEpicRequest {}
+
+impl SetEpicCmd for SetEpicRequest {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn epic(&self) -> IncommingResult<Option<Uuid>> {
+        Ok(self.epic)
+    }
+}
+
+struct LinkRequest {
+    id: Uuid,
+    links: Vec<Uuid>,
+}
+
+impl Command for LinkRequest {}
+
+impl LinkItemCmd for LinkRequest {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn links(&self) -> IncommingResult<Vec<Uuid>> {
+        Ok(self.links.clone())
+    }
+}
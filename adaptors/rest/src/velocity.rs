@@ -0,0 +1,30 @@
+use axum::{
+    extract::{Extension, Query},
+    Json,
+};
+use backlog::VelocityReport;
+use backlog_service::{BacklogUseCase, Command, IncommingResult, VelocityCmd};
+use serde::Deserialize;
+
+use super::{RestAdaptor, RestError, RestResult};
+
+#[tracing::instrument]
+pub async fn velocity_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Query(query): Query<VelocityRequest>,
+) -> RestResult<Json<VelocityReport>> {
+    ctx.velocity(query).await.map(Json).map_err(RestError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VelocityRequest {
+    window: Option<usize>,
+}
+
+impl Command for VelocityRequest {}
+
+impl VelocityCmd for VelocityRequest {
+    fn window(&self) -> IncommingResult<usize> {
+        Ok(self.window.unwrap_or(3))
+    }
+}
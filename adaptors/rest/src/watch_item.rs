@@ -0,0 +1,52 @@
+use axum::{
+    extract::{Extension, Path},
+    Json,
+};
+use backlog::{Assignee, Backlog, Uuid};
+use backlog_service::{BacklogUseCase, Command, IncommingResult, WatchItemCmd};
+
+use super::{RestAdaptor, RestError, RestResult};
+
+#[tracing::instrument]
+pub async fn watch_item_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path((item_id, handle)): Path<(Uuid, String)>,
+) -> RestResult<Json<Backlog>> {
+    let req = WatchItemRequest {
+        id: item_id,
+        handle,
+    };
+    ctx.watch_item(req).await.map(Json).map_err(RestError::from)
+}
+
+#[tracing::instrument]
+pub async fn unwatch_item_handler(
+    Extension(ctx): Extension<RestAdaptor>,
+    Path((item_id, handle)): Path<(Uuid, String)>,
+) -> RestResult<Json<Backlog>> {
+    let req = WatchItemRequest {
+        id: item_id,
+        handle,
+    };
+    ctx.unwatch_item(req)
+        .await
+        .map(Json)
+        .map_err(RestError::from)
+}
+
+struct WatchItemRequest {
+    id: Uuid,
+    handle: String,
+}
+
+impl Command for WatchItemRequest {}
+
+impl WatchItemCmd for WatchItemRequest {
+    fn id(&self) -> IncommingResult<Uuid> {
+        Ok(self.id)
+    }
+
+    fn watcher(&self) -> IncommingResult<Assignee> {
+        Ok(Assignee::new(&self.handle))
+    }
+}
@@ -0,0 +1,378 @@
+//! The items are stored normalized, a row per item with a column per field,
+//! and a row per entry of each list of the item in its own table.
+//! The fields which have no column, e.g. of a new kind of item, are kept together as JSON.
+use std::io::ErrorKind;
+
+use backlog_repo::{BacklogRepositoryError, BacklogRepositoryResult};
+use rusqlite::types::Value as SqlValue;
+use rusqlite::{params, params_from_iter, Connection};
+use serde_json::{Map, Value};
+
+/// How the field is kept in its column.
+#[derive(Clone, Copy)]
+enum Affinity {
+    Text,
+    Integer,
+    /// the value whose fields vary by its kind, e.g. a change of the history, as JSON text.
+    Json,
+}
+
+/// The fields of each item in the columns of `items`, as (column, field, affinity).
+const COLUMNS: [(&str, &str, Affinity); 11] = [
+    ("kind", "type", Affinity::Text),
+    ("title", "title", Affinity::Text),
+    ("point", "point", Affinity::Integer),
+    ("assignee", "assignee", Affinity::Text),
+    ("status", "status", Affinity::Text),
+    ("sprint", "sprint", Affinity::Text),
+    ("priority", "priority", Affinity::Text),
+    ("rank", "rank", Affinity::Text),
+    ("epic", "epic", Affinity::Text),
+    ("split_from", "split_from", Affinity::Text),
+    ("fix_version", "fix_version", Affinity::Text),
+];
+
+/// The column of the fields which are neither in `COLUMNS` nor in `LISTS`, as a JSON object.
+const EXTRA: &str = "extra";
+
+/// A list of each item, which is stored as a table with a row per entry.
+struct List {
+    table: &'static str,
+    field: &'static str,
+    /// the columns of each entry as (column, field, affinity), where no field is the entry itself.
+    columns: &'static [(&'static str, Option<&'static str>, Affinity)],
+}
+
+const LISTS: [List; 6] = [
+    List {
+        table: "item_labels",
+        field: "labels",
+        columns: &[("label", None, Affinity::Text)],
+    },
+    List {
+        table: "item_links",
+        field: "links",
+        columns: &[("link", None, Affinity::Text)],
+    },
+    List {
+        table: "item_split_into",
+        field: "split_into",
+        columns: &[("part", None, Affinity::Text)],
+    },
+    List {
+        table: "item_watchers",
+        field: "watchers",
+        columns: &[("watcher", None, Affinity::Text)],
+    },
+    List {
+        table: "item_history",
+        field: "history",
+        columns: &[
+            ("at", Some("at"), Affinity::Text),
+            ("change", Some("change"), Affinity::Json),
+        ],
+    },
+    List {
+        table: "item_attachments",
+        field: "attachments",
+        columns: &[
+            ("id", Some("id"), Affinity::Text),
+            ("filename", Some("filename"), Affinity::Text),
+            ("size", Some("size"), Affinity::Integer),
+            ("hash", Some("hash"), Affinity::Text),
+            ("uploader", Some("uploader"), Affinity::Text),
+            ("uploaded_at", Some("uploaded_at"), Affinity::Text),
+        ],
+    },
+];
+
+fn unsupported(msg: String) -> BacklogRepositoryError {
+    BacklogRepositoryError::database(std::io::Error::new(ErrorKind::InvalidData, msg))
+}
+
+fn to_sql(value: Value, affinity: Affinity, field: &str) -> BacklogRepositoryResult<SqlValue> {
+    match (affinity, value) {
+        (_, Value::Null) => Ok(SqlValue::Null),
+        (Affinity::Json, value) => Ok(SqlValue::Text(value.to_string())),
+        (Affinity::Text, Value::String(value)) => Ok(SqlValue::Text(value)),
+        (Affinity::Integer, Value::Number(value)) if value.is_i64() => {
+            Ok(SqlValue::Integer(value.as_i64().unwrap_or_default()))
+        }
+        (_, value) => Err(unsupported(format!(
+            "the field {} of the item can not be stored in its column, {}",
+            field, value
+        ))),
+    }
+}
+
+fn from_sql(value: SqlValue, affinity: Affinity) -> BacklogRepositoryResult<Value> {
+    match (affinity, value) {
+        (Affinity::Json, SqlValue::Text(value)) => Ok(serde_json::from_str(&value)?),
+        (_, SqlValue::Text(value)) => Ok(Value::from(value)),
+        (_, SqlValue::Integer(value)) => Ok(Value::from(value)),
+        (_, SqlValue::Real(value)) => Ok(Value::from(value)),
+        (_, SqlValue::Null) | (_, SqlValue::Blob(_)) => Ok(Value::Null),
+    }
+}
+
+/// Leave out the fields which are null or empty lists, since the rows can not tell them
+/// from the fields which the kind of the item does not have, and they are the defaults alike.
+fn compact(item: &mut Map<String, Value>) {
+    item.retain(|_, value| match value {
+        Value::Null => false,
+        Value::Array(entries) => !entries.is_empty(),
+        _ => true,
+    });
+}
+
+/// Read the items in their order, each as its document.
+pub fn read(conn: &Connection) -> BacklogRepositoryResult<Map<String, Value>> {
+    let columns: Vec<&str> = COLUMNS.iter().map(|(column, _, _)| *column).collect();
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT id, {}, {} FROM items ORDER BY position",
+            columns.join(", "),
+            EXTRA
+        ))
+        .map_err(BacklogRepositoryError::database)?;
+    let rows = stmt
+        .query_map([], |row| {
+            let mut values = Vec::new();
+            for i in 0..=COLUMNS.len() {
+                values.push(row.get::<_, SqlValue>(i)?);
+            }
+            Ok((values, row.get::<_, SqlValue>(COLUMNS.len() + 1)?))
+        })
+        .map_err(BacklogRepositoryError::database)?;
+    let mut items = Map::new();
+    for row in rows {
+        let (values, extra) = row.map_err(BacklogRepositoryError::database)?;
+        let mut values = values.into_iter();
+        let id = from_sql(values.next().unwrap_or(SqlValue::Null), Affinity::Text)?;
+        let mut item = Map::new();
+        if let Value::Object(extra) = from_sql(extra, Affinity::Json)? {
+            item.extend(extra);
+        }
+        item.insert("id".to_string(), id.clone());
+        for ((_, field, affinity), value) in COLUMNS.iter().zip(values) {
+            item.insert(field.to_string(), from_sql(value, *affinity)?);
+        }
+        if let Value::String(id) = id {
+            items.insert(id, Value::Object(item));
+        }
+    }
+    for list in &LISTS {
+        read_list(conn, list, &mut items)?;
+    }
+    for item in items.values_mut() {
+        if let Value::Object(item) = item {
+            compact(item);
+        }
+    }
+    Ok(items)
+}
+
+fn read_list(
+    conn: &Connection,
+    list: &List,
+    items: &mut Map<String, Value>,
+) -> BacklogRepositoryResult<()> {
+    let columns: Vec<&str> = list.columns.iter().map(|(column, _, _)| *column).collect();
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT item, {} FROM {} ORDER BY item, position",
+            columns.join(", "),
+            list.table
+        ))
+        .map_err(BacklogRepositoryError::database)?;
+    let rows = stmt
+        .query_map([], |row| {
+            let mut values = Vec::new();
+            for i in 1..=list.columns.len() {
+                values.push(row.get::<_, SqlValue>(i)?);
+            }
+            Ok((row.get::<_, String>(0)?, values))
+        })
+        .map_err(BacklogRepositoryError::database)?;
+    for row in rows {
+        let (id, values) = row.map_err(BacklogRepositoryError::database)?;
+        let mut entry = Map::new();
+        let mut whole = Value::Null;
+        for ((_, field, affinity), value) in list.columns.iter().zip(values) {
+            let value = from_sql(value, *affinity)?;
+            match field {
+                Some(field) => {
+                    entry.insert(field.to_string(), value);
+                }
+                None => whole = value,
+            }
+        }
+        let entry = if entry.is_empty() {
+            whole
+        } else {
+            Value::Object(entry)
+        };
+        if let Some(item) = items.get_mut(&id).and_then(Value::as_object_mut) {
+            match item.get_mut(list.field) {
+                Some(Value::Array(entries)) => entries.push(entry),
+                _ => {
+                    item.insert(list.field.to_string(), Value::Array(vec![entry]));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Write the items in their order, and delete the rows of the removed ones.
+///
+/// Only the rows of the changed items are written, and the moved ones have only their positions.
+pub fn write(conn: &Connection, items: Map<String, Value>) -> BacklogRepositoryResult<()> {
+    let stored = read(conn)?;
+    for id in stored.keys().filter(|id| !items.contains_key(*id)) {
+        delete(conn, id)?;
+    }
+    for (position, (id, item)) in items.into_iter().enumerate() {
+        let mut item = match item {
+            Value::Object(item) => item,
+            item => {
+                return Err(unsupported(format!(
+                    "the item {} is not a mapping, {}",
+                    id, item
+                )))
+            }
+        };
+        compact(&mut item);
+        match stored.get(&id) {
+            Some(Value::Object(stored_item)) if *stored_item == item => {
+                let moved = stored.keys().position(|key| *key == id) != Some(position);
+                if moved {
+                    conn.execute(
+                        "UPDATE items SET position = ?1 WHERE id = ?2",
+                        params![position as i64, id],
+                    )
+                    .map_err(BacklogRepositoryError::database)?;
+                }
+            }
+            Some(_) => {
+                delete(conn, &id)?;
+                insert(conn, &id, position, item)?;
+            }
+            None => insert(conn, &id, position, item)?,
+        }
+    }
+    Ok(())
+}
+
+fn delete(conn: &Connection, id: &str) -> BacklogRepositoryResult<()> {
+    for list in &LISTS {
+        conn.execute(
+            &format!("DELETE FROM {} WHERE item = ?1", list.table),
+            params![id],
+        )
+        .map_err(BacklogRepositoryError::database)?;
+    }
+    conn.execute("DELETE FROM items WHERE id = ?1", params![id])
+        .map_err(BacklogRepositoryError::database)?;
+    Ok(())
+}
+
+/// Insert the row of the item and the rows of its lists.
+///
+/// The fields out of the schema are stored in the extra column, so that no field is lost.
+fn insert(
+    conn: &Connection,
+    id: &str,
+    position: usize,
+    mut item: Map<String, Value>,
+) -> BacklogRepositoryResult<()> {
+    item.remove("id");
+    let mut values = vec![
+        SqlValue::Text(id.to_string()),
+        SqlValue::Integer(position as i64),
+    ];
+    for (_, field, affinity) in &COLUMNS {
+        let value = item.remove(*field).unwrap_or(Value::Null);
+        values.push(to_sql(value, *affinity, field)?);
+    }
+    let mut lists = Vec::new();
+    for list in &LISTS {
+        match item.remove(list.field) {
+            None => lists.push(Vec::new()),
+            Some(Value::Array(entries)) => lists.push(entries),
+            Some(value) => {
+                return Err(unsupported(format!(
+                    "the field {} of the item is not a list, {}",
+                    list.field, value
+                )))
+            }
+        }
+    }
+    let extra = if item.is_empty() {
+        Value::Null
+    } else {
+        Value::Object(item)
+    };
+    values.push(to_sql(extra, Affinity::Json, EXTRA)?);
+
+    let mut columns: Vec<&str> = COLUMNS.iter().map(|(column, _, _)| *column).collect();
+    columns.push(EXTRA);
+    let placeholders: Vec<String> = (1..=values.len()).map(|i| format!("?{}", i)).collect();
+    conn.execute(
+        &format!(
+            "INSERT INTO items (id, position, {}) VALUES ({})",
+            columns.join(", "),
+            placeholders.join(", ")
+        ),
+        params_from_iter(values),
+    )
+    .map_err(BacklogRepositoryError::database)?;
+
+    for (list, entries) in LISTS.iter().zip(lists) {
+        insert_list(conn, list, id, entries)?;
+    }
+    Ok(())
+}
+
+fn insert_list(
+    conn: &Connection,
+    list: &List,
+    id: &str,
+    entries: Vec<Value>,
+) -> BacklogRepositoryResult<()> {
+    let columns: Vec<&str> = list.columns.iter().map(|(column, _, _)| *column).collect();
+    let placeholders: Vec<String> = (1..=columns.len() + 2).map(|i| format!("?{}", i)).collect();
+    let mut stmt = conn
+        .prepare(&format!(
+            "INSERT INTO {} (item, position, {}) VALUES ({})",
+            list.table,
+            columns.join(", "),
+            placeholders.join(", ")
+        ))
+        .map_err(BacklogRepositoryError::database)?;
+    for (position, mut entry) in entries.into_iter().enumerate() {
+        let mut values = vec![
+            SqlValue::Text(id.to_string()),
+            SqlValue::Integer(position as i64),
+        ];
+        for (column, field, affinity) in list.columns {
+            let value = match field {
+                None => entry.take(),
+                Some(field) => entry
+                    .as_object_mut()
+                    .and_then(|entry| entry.remove(*field))
+                    .unwrap_or(Value::Null),
+            };
+            values.push(to_sql(value, *affinity, column)?);
+        }
+        if let Some(field) = entry.as_object().and_then(|entry| entry.keys().next()) {
+            return Err(unsupported(format!(
+                "the field {} of the {} of the item is out of the schema",
+                field, list.field
+            )));
+        }
+        stmt.execute(params_from_iter(values))
+            .map_err(BacklogRepositoryError::database)?;
+    }
+    Ok(())
+}
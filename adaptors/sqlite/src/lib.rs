@@ -0,0 +1,5 @@
+mod items;
+mod schema;
+mod sqlite_backlog_repository;
+
+pub use sqlite_backlog_repository::SqliteBacklogRepository;
@@ -0,0 +1,244 @@
+use backlog_repo::{BacklogRepositoryError, BacklogRepositoryResult};
+use rusqlite::{Connection, TransactionBehavior};
+
+/// The steps to the current schema, the n-th of which upgrades the version n to n + 1.
+///
+/// The version is kept in `PRAGMA user_version`. Never change the released steps, add a new one.
+const MIGRATIONS: [&str; 3] = [
+    // 0 -> 1: a row per entry of each collection, and the rest of the backlog as a document.
+    "CREATE TABLE backlog (
+        id TEXT PRIMARY KEY,
+        revision INTEGER NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE TABLE items (
+        id TEXT PRIMARY KEY,
+        position INTEGER NOT NULL,
+        kind TEXT,
+        title TEXT,
+        status TEXT,
+        assignee TEXT,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX items_status ON items (status);
+    CREATE INDEX items_assignee ON items (assignee);
+    CREATE TABLE sprints (
+        id TEXT PRIMARY KEY,
+        position INTEGER NOT NULL,
+        name TEXT,
+        team TEXT,
+        data TEXT NOT NULL
+    );
+    CREATE TABLE releases (
+        id TEXT PRIMARY KEY,
+        position INTEGER NOT NULL,
+        name TEXT,
+        data TEXT NOT NULL
+    );
+    CREATE TABLE users (
+        handle TEXT PRIMARY KEY,
+        position INTEGER NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE TABLE teams (
+        handle TEXT PRIMARY KEY,
+        position INTEGER NOT NULL,
+        data TEXT NOT NULL
+    );",
+    // 1 -> 2: the fields of each item in the columns, and the lists of each item in the tables
+    // with a row per entry, which are copied out of the documents.
+    "ALTER TABLE items ADD COLUMN point INTEGER;
+    ALTER TABLE items ADD COLUMN sprint TEXT;
+    ALTER TABLE items ADD COLUMN priority TEXT;
+    ALTER TABLE items ADD COLUMN rank TEXT;
+    ALTER TABLE items ADD COLUMN epic TEXT;
+    ALTER TABLE items ADD COLUMN split_from TEXT;
+    ALTER TABLE items ADD COLUMN fix_version TEXT;
+    UPDATE items SET
+        kind = json_extract(data, '$.type'),
+        title = json_extract(data, '$.title'),
+        point = json_extract(data, '$.point'),
+        assignee = json_extract(data, '$.assignee'),
+        status = json_extract(data, '$.status'),
+        sprint = json_extract(data, '$.sprint'),
+        priority = json_extract(data, '$.priority'),
+        rank = json_extract(data, '$.rank'),
+        epic = json_extract(data, '$.epic'),
+        split_from = json_extract(data, '$.split_from'),
+        fix_version = json_extract(data, '$.fix_version');
+    CREATE INDEX items_sprint ON items (sprint);
+    CREATE TABLE item_labels (
+        item TEXT NOT NULL REFERENCES items (id) ON DELETE CASCADE,
+        position INTEGER NOT NULL,
+        label TEXT NOT NULL,
+        PRIMARY KEY (item, position)
+    );
+    INSERT INTO item_labels (item, position, label)
+        SELECT items.id, entry.key, entry.value FROM items, json_each(items.data, '$.labels') AS entry;
+    CREATE TABLE item_links (
+        item TEXT NOT NULL REFERENCES items (id) ON DELETE CASCADE,
+        position INTEGER NOT NULL,
+        link TEXT NOT NULL,
+        PRIMARY KEY (item, position)
+    );
+    INSERT INTO item_links (item, position, link)
+        SELECT items.id, entry.key, entry.value FROM items, json_each(items.data, '$.links') AS entry;
+    CREATE TABLE item_split_into (
+        item TEXT NOT NULL REFERENCES items (id) ON DELETE CASCADE,
+        position INTEGER NOT NULL,
+        part TEXT NOT NULL,
+        PRIMARY KEY (item, position)
+    );
+    INSERT INTO item_split_into (item, position, part)
+        SELECT items.id, entry.key, entry.value
+        FROM items, json_each(items.data, '$.split_into') AS entry;
+    CREATE TABLE item_watchers (
+        item TEXT NOT NULL REFERENCES items (id) ON DELETE CASCADE,
+        position INTEGER NOT NULL,
+        watcher TEXT NOT NULL,
+        PRIMARY KEY (item, position)
+    );
+    INSERT INTO item_watchers (item, position, watcher)
+        SELECT items.id, entry.key, entry.value
+        FROM items, json_each(items.data, '$.watchers') AS entry;
+    CREATE TABLE item_history (
+        item TEXT NOT NULL REFERENCES items (id) ON DELETE CASCADE,
+        position INTEGER NOT NULL,
+        at TEXT NOT NULL,
+        change TEXT NOT NULL,
+        PRIMARY KEY (item, position)
+    );
+    INSERT INTO item_history (item, position, at, change)
+        SELECT items.id, entry.key,
+            json_extract(entry.value, '$.at'), json_extract(entry.value, '$.change')
+        FROM items, json_each(items.data, '$.history') AS entry;
+    CREATE TABLE item_attachments (
+        item TEXT NOT NULL REFERENCES items (id) ON DELETE CASCADE,
+        position INTEGER NOT NULL,
+        id TEXT NOT NULL,
+        filename TEXT NOT NULL,
+        size INTEGER NOT NULL,
+        hash TEXT NOT NULL,
+        uploader TEXT NOT NULL,
+        uploaded_at TEXT NOT NULL,
+        PRIMARY KEY (item, position)
+    );
+    CREATE INDEX item_attachments_hash ON item_attachments (hash);
+    INSERT INTO item_attachments (item, position, id, filename, size, hash, uploader, uploaded_at)
+        SELECT items.id, entry.key,
+            json_extract(entry.value, '$.id'), json_extract(entry.value, '$.filename'),
+            json_extract(entry.value, '$.size'), json_extract(entry.value, '$.hash'),
+            json_extract(entry.value, '$.uploader'), json_extract(entry.value, '$.uploaded_at')
+        FROM items, json_each(items.data, '$.attachments') AS entry;
+    ALTER TABLE items DROP COLUMN data;",
+    // 2 -> 3: the fields of each item which have no column, as a JSON object.
+    "ALTER TABLE items ADD COLUMN extra TEXT;",
+];
+
+/// The version of the schema which this adaptor reads and writes.
+pub const SCHEMA_VERSION: usize = MIGRATIONS.len();
+
+/// Upgrade the schema of the database to the current version.
+///
+/// The database of a newer version is refused rather than broken.
+pub fn migrate(conn: &mut Connection) -> BacklogRepositoryResult<()> {
+    if user_version(conn)? == SCHEMA_VERSION {
+        return Ok(());
+    }
+    let tx = conn
+        .transaction_with_behavior(TransactionBehavior::Immediate)
+        .map_err(BacklogRepositoryError::database)?;
+    // another connection may have upgraded it in the meantime.
+    let version = user_version(&tx)?;
+    if version > SCHEMA_VERSION {
+        return Err(BacklogRepositoryError::database(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "the schema version {} is newer than the supported version {}",
+                version, SCHEMA_VERSION
+            ),
+        )));
+    }
+    for step in &MIGRATIONS[version..] {
+        tx.execute_batch(step)
+            .map_err(BacklogRepositoryError::database)?;
+    }
+    tx.pragma_update(None, "user_version", SCHEMA_VERSION)
+        .map_err(BacklogRepositoryError::database)?;
+    tx.commit().map_err(BacklogRepositoryError::database)
+}
+
+fn user_version(conn: &Connection) -> BacklogRepositoryResult<usize> {
+    conn.query_row("PRAGMA user_version", [], |row| row.get(0))
+        .map_err(BacklogRepositoryError::database)
+}
+
+#[cfg(test)]
+mod tests {
+    use backlog::{
+        AddItem, Assignee, AttachableFromCollection, Attachment, Backlog, Entity, Story,
+        WatchableFromCollection,
+    };
+    use backlog_repo::BacklogRepository;
+    use rusqlite::params;
+    use serde_json::Value;
+
+    use super::*;
+    use crate::SqliteBacklogRepository;
+
+    /// The items stored as the documents by the first schema are copied into the columns.
+    #[tokio::test]
+    async fn test_migrate_documents_into_columns() {
+        let dir = std::env::temp_dir().join(format!("sqlite-{}", Backlog::new().id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("data.db");
+
+        let mut backlog = Backlog::new();
+        let story = Story::new("Login page", None, Some(Assignee::new("alice")));
+        let id = story.id();
+        backlog.add_item(Box::new(story));
+        let attachment = Attachment::new("login.png", b"png", Assignee::new("bob")).unwrap();
+        backlog.attach_item(&id, attachment).unwrap();
+        backlog
+            .add_user(backlog::User::new("carol", "Carol").unwrap())
+            .unwrap();
+        backlog.watch_item(&id, Assignee::new("carol")).unwrap();
+        let mut document = serde_json::to_value(&backlog).unwrap();
+        let item = document["items"][id.to_string()].take();
+        let users = document["directory"]["users"].take();
+        let fields = document.as_object_mut().unwrap();
+        for field in ["id", "revision", "items", "sprints", "releases"] {
+            fields.remove(field);
+        }
+
+        let conn = Connection::open(&path).unwrap();
+        conn.execute_batch(MIGRATIONS[0]).unwrap();
+        conn.pragma_update(None, "user_version", 1).unwrap();
+        conn.execute(
+            "INSERT INTO backlog (id, revision, data) VALUES (?1, 0, ?2)",
+            params![backlog.id().to_string(), document.to_string()],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO items (id, position, data) VALUES (?1, 0, ?2)",
+            params![id.to_string(), item.to_string()],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO users (handle, position, data) VALUES ('carol', 0, ?1)",
+            params![users["carol"].to_string()],
+        )
+        .unwrap();
+        drop(conn);
+
+        let migrated = SqliteBacklogRepository::new(path).get().await.unwrap();
+        assert_eq!(
+            serde_json::to_value(&migrated).unwrap()["items"],
+            serde_json::to_value(&backlog).unwrap()["items"]
+        );
+        assert_eq!(migrated.items()[&id].watchers().len(), 1);
+        let stored: Value = serde_json::to_value(&migrated).unwrap();
+        assert_eq!(stored["items"][id.to_string()]["assignee"], "alice");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,477 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use backlog::{Backlog, MigrationReport};
+use backlog_repo::{
+    changed, BacklogRepository, BacklogRepositoryError, BacklogRepositoryResult, Committed,
+    UnitOfWork,
+};
+use rusqlite::types::Value as SqlValue;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, TransactionBehavior};
+use serde_json::{Map, Value};
+
+use crate::{items, schema};
+
+/// A collection of the backlog but the items, which is stored as a table with a row per entry.
+struct Collection {
+    table: &'static str,
+    /// the path to the collection in the document.
+    path: &'static [&'static str],
+    key: &'static str,
+    /// the columns copied from the fields of each entry to query them, as (column, field).
+    columns: &'static [(&'static str, &'static str)],
+}
+
+const COLLECTIONS: [Collection; 4] = [
+    Collection {
+        table: "sprints",
+        path: &["sprints"],
+        key: "id",
+        columns: &[("name", "name"), ("team", "team")],
+    },
+    Collection {
+        table: "releases",
+        path: &["releases"],
+        key: "id",
+        columns: &[("name", "name")],
+    },
+    Collection {
+        table: "users",
+        path: &["directory", "users"],
+        key: "handle",
+        columns: &[],
+    },
+    Collection {
+        table: "teams",
+        path: &["directory", "teams"],
+        key: "handle",
+        columns: &[],
+    },
+];
+
+/// Stores the backlog in an embedded SQLite file, a row per item with a column per field,
+/// and a row per entry of each list of the items, e.g. the history and the attachments.
+///
+/// Only the rows which have changed are written by each save.
+#[derive(Debug, Clone)]
+pub struct SqliteBacklogRepository {
+    path: PathBuf,
+    busy_timeout: Duration,
+}
+
+impl SqliteBacklogRepository {
+    /// How long to wait for the other connections writing the database by default.
+    pub const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            busy_timeout: Self::BUSY_TIMEOUT,
+        }
+    }
+
+    pub fn with_busy_timeout(self, busy_timeout: Duration) -> Self {
+        Self {
+            busy_timeout,
+            ..self
+        }
+    }
+
+    /// Open the database, and upgrade its schema if it is older.
+    ///
+    /// The foreign keys are enforced, which SQLite leaves off for each new connection.
+    fn connect(&self) -> BacklogRepositoryResult<Connection> {
+        let mut conn = Connection::open(&self.path).map_err(BacklogRepositoryError::database)?;
+        conn.busy_timeout(self.busy_timeout)
+            .map_err(BacklogRepositoryError::database)?;
+        conn.pragma_update(None, "foreign_keys", true)
+            .map_err(BacklogRepositoryError::database)?;
+        schema::migrate(&mut conn)?;
+        Ok(conn)
+    }
+
+    /// Assemble the document as it is stored, before it is migrated.
+    ///
+    /// All the tables are read in one transaction, so that no save is seen halfway.
+    /// The database which has never been saved has no document.
+    fn document(conn: &mut Connection) -> BacklogRepositoryResult<Option<Value>> {
+        let tx = conn
+            .transaction()
+            .map_err(BacklogRepositoryError::database)?;
+        Self::read_document(&tx)
+    }
+
+    /// Assemble the document in the transaction which has been begun.
+    fn read_document(conn: &Connection) -> BacklogRepositoryResult<Option<Value>> {
+        let row: Option<(String, i64, String)> = conn
+            .query_row("SELECT id, revision, data FROM backlog", [], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+            })
+            .optional()
+            .map_err(BacklogRepositoryError::database)?;
+        let (id, revision, data) = match row {
+            None => return Ok(None),
+            Some(row) => row,
+        };
+        let mut document: Value = serde_json::from_str(&data)?;
+        document["id"] = Value::from(id);
+        document["revision"] = Value::from(revision);
+        for collection in &COLLECTIONS {
+            let mut value = &mut document;
+            for key in collection.path {
+                value = &mut value[*key];
+            }
+            *value = Value::Object(Self::read_collection(conn, collection)?);
+        }
+        document["items"] = Value::Object(items::read(conn)?);
+        Ok(Some(document))
+    }
+
+    /// Read the backlog in the transaction which has been begun, migrated to the current format.
+    fn read(conn: &Connection) -> BacklogRepositoryResult<Backlog> {
+        let mut document = match Self::read_document(conn)? {
+            None => return Ok(Backlog::new()),
+            Some(document) => document,
+        };
+        backlog::migrate(&mut document)?;
+        serde_json::from_value(document)
+            .map_err(|err| BacklogRepositoryError::parse(None, None, err.to_string()))
+    }
+
+    /// Write the backlog in the transaction which has been begun, and advance its revision.
+    ///
+    /// It fails with `Conflict` if the stored revision differs from the loaded one.
+    fn write(conn: &Connection, backlog: &Backlog) -> BacklogRepositoryResult<()> {
+        let stored: i64 = conn
+            .query_row("SELECT revision FROM backlog", [], |row| row.get(0))
+            .optional()
+            .map_err(BacklogRepositoryError::database)?
+            .unwrap_or_default();
+        let stored = stored as u64;
+        if stored != backlog.revision() {
+            return Err(BacklogRepositoryError::Conflict {
+                loaded: backlog.revision(),
+                stored,
+            });
+        }
+
+        let mut document = serde_json::to_value(backlog)?;
+        for collection in &COLLECTIONS {
+            let entries = match take(&mut document, collection.path) {
+                Value::Object(entries) => entries,
+                _ => Map::new(),
+            };
+            Self::write_collection(conn, collection, entries)?;
+        }
+        match take(&mut document, &["items"]) {
+            Value::Object(entries) => items::write(conn, entries)?,
+            _ => items::write(conn, Map::new())?,
+        }
+        take(&mut document, &["revision"]);
+        let id = take(&mut document, &["id"]);
+        conn.execute("DELETE FROM backlog", [])
+            .map_err(BacklogRepositoryError::database)?;
+        conn.execute(
+            "INSERT INTO backlog (id, revision, data) VALUES (?1, ?2, ?3)",
+            params![
+                id.as_str().unwrap_or_default(),
+                (stored + 1) as i64,
+                document.to_string()
+            ],
+        )
+        .map_err(BacklogRepositoryError::database)?;
+        Ok(())
+    }
+
+    fn read_collection(
+        conn: &Connection,
+        collection: &Collection,
+    ) -> BacklogRepositoryResult<Map<String, Value>> {
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT {}, data FROM {} ORDER BY position",
+                collection.key, collection.table
+            ))
+            .map_err(BacklogRepositoryError::database)?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(BacklogRepositoryError::database)?;
+        let mut entries = Map::new();
+        for row in rows {
+            let (key, data) = row.map_err(BacklogRepositoryError::database)?;
+            entries.insert(key, serde_json::from_str(&data)?);
+        }
+        Ok(entries)
+    }
+
+    /// Write the entries in their order, and delete the rows of the removed ones.
+    fn write_collection(
+        conn: &Connection,
+        collection: &Collection,
+        entries: Map<String, Value>,
+    ) -> BacklogRepositoryResult<()> {
+        let stored: Vec<String> = {
+            let mut stmt = conn
+                .prepare(&format!(
+                    "SELECT {} FROM {}",
+                    collection.key, collection.table
+                ))
+                .map_err(BacklogRepositoryError::database)?;
+            let keys = stmt
+                .query_map([], |row| row.get(0))
+                .map_err(BacklogRepositoryError::database)?;
+            keys.collect::<Result<_, _>>()
+                .map_err(BacklogRepositoryError::database)?
+        };
+        for key in stored.iter().filter(|key| !entries.contains_key(*key)) {
+            conn.execute(
+                &format!(
+                    "DELETE FROM {} WHERE {} = ?1",
+                    collection.table, collection.key
+                ),
+                params![key],
+            )
+            .map_err(BacklogRepositoryError::database)?;
+        }
+
+        let columns: Vec<&str> = collection
+            .columns
+            .iter()
+            .map(|(column, _)| *column)
+            .collect();
+        let names: Vec<&str> = [collection.key, "position"]
+            .iter()
+            .chain(columns.iter())
+            .chain(["data"].iter())
+            .copied()
+            .collect();
+        let placeholders: Vec<String> = (1..=names.len()).map(|i| format!("?{}", i)).collect();
+        let updates: Vec<String> = names[1..]
+            .iter()
+            .map(|name| format!("{} = excluded.{}", name, name))
+            .collect();
+        let mut stmt = conn
+            .prepare(&format!(
+                "INSERT INTO {table} ({names}) VALUES ({placeholders}) \
+                 ON CONFLICT ({key}) DO UPDATE SET {updates} \
+                 WHERE position != excluded.position OR data != excluded.data",
+                table = collection.table,
+                names = names.join(", "),
+                placeholders = placeholders.join(", "),
+                key = collection.key,
+                updates = updates.join(", "),
+            ))
+            .map_err(BacklogRepositoryError::database)?;
+        for (position, (key, entry)) in entries.iter().enumerate() {
+            let mut values = vec![
+                SqlValue::Text(key.clone()),
+                SqlValue::Integer(position as i64),
+            ];
+            for (_, field) in collection.columns {
+                values.push(match entry.get(*field) {
+                    None | Some(Value::Null) => SqlValue::Null,
+                    Some(Value::String(value)) => SqlValue::Text(value.clone()),
+                    Some(value) => SqlValue::Text(value.to_string()),
+                });
+            }
+            values.push(SqlValue::Text(entry.to_string()));
+            stmt.execute(params_from_iter(values))
+                .map_err(BacklogRepositoryError::database)?;
+        }
+        Ok(())
+    }
+}
+
+/// Take the value out of the document, e.g. `directory.users`.
+fn take(document: &mut Value, path: &[&str]) -> Value {
+    let (last, parents) = match path.split_last() {
+        None => return Value::Null,
+        Some(split) => split,
+    };
+    let mut value = Some(document);
+    for key in parents {
+        value = value.and_then(|value| value.get_mut(*key));
+    }
+    value
+        .and_then(Value::as_object_mut)
+        .and_then(|object| object.remove(*last))
+        .unwrap_or(Value::Null)
+}
+
+#[async_trait::async_trait]
+impl BacklogRepository for SqliteBacklogRepository {
+    async fn get(&self) -> BacklogRepositoryResult<Backlog> {
+        let mut conn = self.connect()?;
+        let tx = conn
+            .transaction()
+            .map_err(BacklogRepositoryError::database)?;
+        Self::read(&tx)
+    }
+
+    /// The revision is checked and advanced in the same transaction as the rows are written.
+    async fn save(&self, backlog: Backlog) -> BacklogRepositoryResult<()> {
+        let mut conn = self.connect()?;
+        let tx = conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .map_err(BacklogRepositoryError::database)?;
+        Self::write(&tx, &backlog)?;
+        tx.commit().map_err(BacklogRepositoryError::database)
+    }
+
+    /// The unit of work is run in a transaction of the database, which is begun for writing,
+    /// so that the other connections wait for it instead of conflicting with it.
+    ///
+    /// The transaction is rolled back when it is dropped uncommitted.
+    async fn commit(
+        &self,
+        _operation: &str,
+        work: &mut UnitOfWork<'_>,
+    ) -> BacklogRepositoryResult<Option<Committed<()>>> {
+        let mut conn = self.connect()?;
+        let tx = conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .map_err(BacklogRepositoryError::database)?;
+        let before = Self::read(&tx)?;
+        let mut backlog = before.clone();
+        if !work(&mut backlog) {
+            return Ok(None);
+        }
+        if !changed(&before, &backlog)? {
+            return Ok(Some(Committed::new((), before, backlog, false)));
+        }
+        Self::write(&tx, &backlog)?;
+        tx.commit().map_err(BacklogRepositoryError::database)?;
+        let revision = backlog.revision() + 1;
+        Ok(Some(Committed::new(
+            (),
+            before,
+            backlog.with_revision(revision),
+            true,
+        )))
+    }
+
+    async fn migrate(&self, dry_run: bool) -> BacklogRepositoryResult<MigrationReport> {
+        let mut conn = self.connect()?;
+        let mut document = match Self::document(&mut conn)? {
+            None => return Ok(MigrationReport::up_to_date()),
+            Some(document) => document,
+        };
+        let report = backlog::migrate(&mut document)?;
+        if !dry_run && !report.is_up_to_date() {
+            let backlog = serde_json::from_value(document)
+                .map_err(|err| BacklogRepositoryError::parse(None, None, err.to_string()))?;
+            self.save(backlog).await?;
+        }
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use backlog::{
+        AddItem, Assignee, AttachableFromCollection, Attachment, Entity, Story, StoryPoint,
+    };
+    use backlog_repo::contract;
+
+    use super::*;
+
+    fn repository() -> SqliteBacklogRepository {
+        let dir = std::env::temp_dir().join(format!("sqlite-{}", Backlog::new().id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        SqliteBacklogRepository::new(dir.join("data.db"))
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_contract() {
+        contract::run(repository).await;
+    }
+
+    #[tokio::test]
+    async fn test_save_writes_rows() {
+        let repo = repository();
+        let mut backlog = repo.get().await.unwrap();
+        let story = Story::new("Login page", StoryPoint::new(3).ok(), None);
+        let id = story.id();
+        backlog.add_item(Box::new(story));
+        let attachment = Attachment::new("login.png", b"png", Assignee::new("alice")).unwrap();
+        backlog.attach_item(&id, attachment).unwrap();
+        repo.save(backlog).await.unwrap();
+
+        let conn = repo.connect().unwrap();
+        let (kind, title, point): (String, String, i64) = conn
+            .query_row(
+                "SELECT kind, title, point FROM items WHERE id = ?1",
+                params![id.to_string()],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(
+            (kind.as_str(), title.as_str(), point),
+            ("Story", "Login page", 3)
+        );
+        let (filename, size): (String, i64) = conn
+            .query_row(
+                "SELECT filename, size FROM item_attachments WHERE item = ?1",
+                params![id.to_string()],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!((filename.as_str(), size), ("login.png", 3));
+        let changes: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM item_history WHERE item = ?1",
+                params![id.to_string()],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(changes, 2);
+
+        // the removed item loses its row.
+        let mut backlog = repo.get().await.unwrap();
+        let older = backlog.clone();
+        backlog.add_item(Box::new(Story::new("Signup page", None, None)));
+        repo.save(backlog).await.unwrap();
+        repo.save(older.with_revision(2)).await.unwrap();
+        let rows: i64 = conn
+            .query_row("SELECT COUNT(*) FROM items", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(rows, 1);
+        let changes: i64 = conn
+            .query_row("SELECT COUNT(*) FROM item_history", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(changes, 2);
+        std::fs::remove_dir_all(repo.path.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_save_keeps_fields_out_of_columns() {
+        let repo = repository();
+        let mut conn = repo.connect().unwrap();
+        let id = Backlog::new().id().to_string();
+        let item = serde_json::json!({
+            "id": id,
+            "type": "Bug",
+            "title": "Login fails",
+            "severity": "critical",
+            "steps": ["open the login page", "submit"],
+            "labels": ["login"],
+        });
+        let mut items = Map::new();
+        items.insert(id, item);
+
+        let tx = conn.transaction().unwrap();
+        items::write(&tx, items.clone()).unwrap();
+        assert_eq!(items::read(&tx).unwrap(), items);
+
+        // the foreign keys delete the rows of the lists along with the item.
+        tx.execute("DELETE FROM items", []).unwrap();
+        let labels: i64 = tx
+            .query_row("SELECT COUNT(*) FROM item_labels", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(labels, 0);
+        drop(tx);
+        std::fs::remove_dir_all(repo.path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,3 @@
+mod webhook_notifier;
+
+pub use webhook_notifier::WebhookNotifier;
@@ -0,0 +1,179 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use backlog::Notification;
+use hyper::client::HttpConnector;
+use hyper::{header, Body, Client, Method, Request, Uri};
+use notifier::{Notifier, NotifierError, NotifierResult};
+
+/// POST each notification as JSON to the url.
+#[derive(Debug, Clone)]
+pub struct WebhookNotifier {
+    url: Uri,
+    timeout: Duration,
+    client: Client<HttpConnector>,
+}
+
+impl WebhookNotifier {
+    pub const TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// only `http` is supported.
+    pub fn new(url: &str) -> NotifierResult<Self> {
+        let url: Uri = url.parse().map_err(|err| {
+            NotifierError::config(format!("invalid webhook url {}, {}", url, err))
+        })?;
+        if url.scheme_str() != Some("http") || url.host().is_none() {
+            return Err(NotifierError::config(format!(
+                "the webhook url must be http://host/..., {}",
+                url
+            )));
+        }
+        Ok(Self {
+            url,
+            timeout: Self::TIMEOUT,
+            client: Client::new(),
+        })
+    }
+
+    pub fn timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+
+    pub fn url(&self) -> &Uri {
+        &self.url
+    }
+}
+
+impl FromStr for WebhookNotifier {
+    type Err = NotifierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+#[async_trait::async_trait]
+impl Notifier for WebhookNotifier {
+    async fn notify(&self, notification: &Notification) -> NotifierResult<()> {
+        let body = serde_json::to_vec(notification)
+            .map_err(|err| NotifierError::serialize(err.to_string()))?;
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(self.url.clone())
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body))
+            .map_err(|err| NotifierError::delivery(err.to_string()))?;
+
+        let response = tokio::time::timeout(self.timeout, self.client.request(request))
+            .await
+            .map_err(|_| {
+                NotifierError::delivery(format!(
+                    "{} did not respond in {:?}",
+                    self.url, self.timeout
+                ))
+            })?
+            .map_err(|err| NotifierError::delivery(format!("{}, {}", self.url, err)))?;
+        if !response.status().is_success() {
+            return Err(NotifierError::delivery(format!(
+                "{} responded {}",
+                self.url,
+                response.status()
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    use backlog::{
+        Assignee, Backlog, BacklogFixture, ProgressableFromCollection, Status,
+        WatchableFromCollection,
+    };
+
+    use super::*;
+
+    fn notification() -> Notification {
+        let (id, mut backlog) = Backlog::specific_id();
+        backlog.watch_item(&id, Assignee::new("dummy")).unwrap();
+        let before = backlog.clone();
+        backlog.change_item_status(&id, Status::Done).unwrap();
+        backlog.notifications(&before).remove(0)
+    }
+
+    /// stand in for the receiver of the webhook, which answers a request with the status.
+    /// return the url and the received request line and body.
+    fn stand_in(status: &'static str) -> (String, mpsc::Receiver<(String, Vec<u8>)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hooks/rjira", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                status
+            )
+            .unwrap();
+            tx.send((request_line.trim_end().to_string(), body))
+                .unwrap();
+        });
+        (url, rx)
+    }
+
+    #[tokio::test]
+    async fn test_notify() {
+        let (url, rx) = stand_in("204 No Content");
+        let notification = notification();
+        WebhookNotifier::new(&url)
+            .unwrap()
+            .notify(&notification)
+            .await
+            .unwrap();
+
+        let (request_line, body) = rx.recv().unwrap();
+        assert_eq!(request_line, "POST /hooks/rjira HTTP/1.1");
+        let delivered: Notification = serde_json::from_slice(&body).unwrap();
+        assert_eq!(delivered, notification);
+    }
+
+    #[tokio::test]
+    async fn test_notify_expect_fail() {
+        let (url, _rx) = stand_in("500 Internal Server Error");
+        let res = WebhookNotifier::new(&url)
+            .unwrap()
+            .notify(&notification())
+            .await;
+        assert!(matches!(res, Err(NotifierError::Delivery(_))));
+    }
+
+    #[test]
+    fn test_new_expect_fail() {
+        assert!(WebhookNotifier::new("not a url").is_err());
+        assert!(WebhookNotifier::new("https://example.com/hooks").is_err());
+        assert!(WebhookNotifier::new("/hooks").is_err());
+    }
+}
@@ -1,18 +1,112 @@
 use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use fs::{DirBacklogRepository, FsBacklogRepository, JournalBacklogRepository};
+use git::{Author, GitBacklogRepository};
 
 use hyper::{header::HeaderValue, http::Request, Body};
+use mailbox::MailboxNotifier;
 use rest::{
-    add_item_handler,
-    axum::{
-        self,
-        routing::{get, post, put},
-        AddExtensionLayer, Router,
-    },
-    backlog_handler, update_item_handler, RestAdaptor,
+    axum::{self, AddExtensionLayer},
+    router, Notifiers, RestAdaptor,
 };
+use sqlite::SqliteBacklogRepository;
 use tower_http::trace::{DefaultOnRequest, DefaultOnResponse, TraceLayer};
 use tracing::Level;
 use tracing_subscriber::{self, fmt};
+use webhook::WebhookNotifier;
+
+/// configure the notifiers to the watchers by `RJIRA_MAILBOX` and `RJIRA_WEBHOOK`.
+fn notifiers() -> Result<Notifiers, String> {
+    let mut notifiers = Notifiers::new();
+    if let Ok(dir) = std::env::var("RJIRA_MAILBOX") {
+        notifiers = notifiers.with(MailboxNotifier::new(dir.into()));
+    }
+    if let Ok(url) = std::env::var("RJIRA_WEBHOOK") {
+        let webhook =
+            WebhookNotifier::new(&url).map_err(|err| format!("RJIRA_WEBHOOK, {}", err))?;
+        notifiers = notifiers.with(webhook);
+    }
+    Ok(notifiers)
+}
+
+/// The longest lock timeout, which keeps the seconds in the range of `Duration`.
+const MAX_LOCK_TIMEOUT_SECS: f64 = 24.0 * 60.0 * 60.0;
+
+/// configure the seconds to wait for the lock of the backlog by `RJIRA_LOCK_TIMEOUT`, up to a day.
+fn lock_timeout() -> Result<Option<Duration>, String> {
+    let value = match std::env::var("RJIRA_LOCK_TIMEOUT") {
+        Ok(value) => value,
+        Err(_) => return Ok(None),
+    };
+    let secs: f64 = value
+        .parse()
+        .map_err(|err| format!("RJIRA_LOCK_TIMEOUT must be the seconds, {}, {}", err, value))?;
+    if !secs.is_finite() || !(0.0..=MAX_LOCK_TIMEOUT_SECS).contains(&secs) {
+        return Err(format!(
+            "RJIRA_LOCK_TIMEOUT must be from 0 to {} seconds, {}",
+            MAX_LOCK_TIMEOUT_SECS, value
+        ));
+    }
+    Ok(Some(Duration::from_secs_f64(secs)))
+}
+
+/// configure the repository of the data file by `RJIRA_REPOSITORY` (fs, journal, dir, sqlite or git) and `RJIRA_DATA`.
+///
+/// The git repository commits as `RJIRA_GIT_AUTHOR`, e.g. `Alice Liddell <alice@example.com>`.
+fn adaptors() -> Result<RestAdaptor, String> {
+    let lock_timeout = lock_timeout()?;
+    let adaptors = match std::env::var("RJIRA_REPOSITORY").as_deref() {
+        Ok("sqlite") => {
+            let data = std::env::var("RJIRA_DATA").unwrap_or_else(|_| "./data.db".to_string());
+            let mut repo = SqliteBacklogRepository::new(data.clone().into());
+            if let Some(lock_timeout) = lock_timeout {
+                repo = repo.with_busy_timeout(lock_timeout);
+            }
+            RestAdaptor::new(&data, Arc::new(repo))
+        }
+        Ok("journal") => {
+            let data = std::env::var("RJIRA_DATA").unwrap_or_else(|_| "./data.yaml".to_string());
+            let mut repo = JournalBacklogRepository::new(data.clone().into());
+            if let Some(lock_timeout) = lock_timeout {
+                repo = repo.with_lock_timeout(lock_timeout);
+            }
+            RestAdaptor::new(&data, Arc::new(repo))
+        }
+        Ok("dir") => {
+            let data = std::env::var("RJIRA_DATA").unwrap_or_else(|_| "./backlog".to_string());
+            let mut repo = DirBacklogRepository::new(data.clone().into());
+            if let Some(lock_timeout) = lock_timeout {
+                repo = repo.with_lock_timeout(lock_timeout);
+            }
+            RestAdaptor::new(&data, Arc::new(repo))
+        }
+        Ok("git") => {
+            let data = std::env::var("RJIRA_DATA").unwrap_or_else(|_| "./data.yaml".to_string());
+            let mut repo = GitBacklogRepository::new(data.clone().into());
+            if let Some(lock_timeout) = lock_timeout {
+                repo = repo.with_lock_timeout(lock_timeout);
+            }
+            if let Ok(author) = std::env::var("RJIRA_GIT_AUTHOR") {
+                let author = author
+                    .parse::<Author>()
+                    .map_err(|err| format!("RJIRA_GIT_AUTHOR, {}", err))?;
+                repo = repo.with_author(author);
+            }
+            RestAdaptor::new(&data, Arc::new(repo))
+        }
+        _ => {
+            let data = std::env::var("RJIRA_DATA").unwrap_or_else(|_| "./data.yaml".to_string());
+            let mut repo = FsBacklogRepository::new(data.clone().into());
+            if let Some(lock_timeout) = lock_timeout {
+                repo = repo.with_lock_timeout(lock_timeout);
+            }
+            RestAdaptor::new(&data, Arc::new(repo))
+        }
+    };
+    Ok(adaptors)
+}
 
 #[tokio::main]
 async fn main() {
@@ -26,11 +120,16 @@ async fn main() {
         )
         .fmt_fields(fmt::format::JsonFields::default())
         .init();
-    let adaptors = RestAdaptor::new("./data.yaml");
-    let app = Router::new()
-        .route("/backlog", get(backlog_handler))
-        .route("/backlog/items", post(add_item_handler))
-        .route("/backlog/items/:item_id", put(update_item_handler))
+    let configured = adaptors()
+        .and_then(|adaptors| notifiers().map(|notifiers| adaptors.with_notifiers(notifiers)));
+    let adaptors = match configured {
+        Ok(adaptors) => adaptors,
+        Err(err) => {
+            eprintln!("rest-server: {}", err);
+            std::process::exit(1);
+        }
+    };
+    let app = router()
         .layer(
             TraceLayer::new_for_http()
                 .make_span_with(|req: &Request<Body>| {
@@ -3,6 +3,6 @@ use cli::{clap::Parser, Args, CliAdaptoer};
 #[tokio::main]
 async fn main() {
     let args = Args::parse();
-    let adaptor = CliAdaptoer::new(args.data());
+    let adaptor = CliAdaptoer::new(args.data(), args.repository()).with_notifiers(args.notifiers());
     args.run(adaptor).await
 }
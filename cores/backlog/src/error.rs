@@ -10,6 +10,12 @@ pub enum BacklogError {
     TypeError(String),
     #[error("NotFound: {0:?}")]
     NotFound(String),
+    #[error("LimitExceeded: {0:?}")]
+    LimitExceeded(String),
+    #[error("Duplicated: {0:?}")]
+    Duplicated(String),
+    #[error("Migration: {0:?}")]
+    Migration(String),
 }
 
 impl BacklogError {
@@ -19,4 +25,13 @@ impl BacklogError {
     pub fn not_found(msg: impl Into<String>) -> Self {
         Self::NotFound(msg.into())
     }
+    pub fn limit_exceeded(msg: impl Into<String>) -> Self {
+        Self::LimitExceeded(msg.into())
+    }
+    pub fn duplicated(msg: impl Into<String>) -> Self {
+        Self::Duplicated(msg.into())
+    }
+    pub fn migration(msg: impl Into<String>) -> Self {
+        Self::Migration(msg.into())
+    }
 }
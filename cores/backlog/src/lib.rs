@@ -1,11 +1,25 @@
 mod error;
+mod migration;
 mod models;
+mod text;
 mod types;
 
+pub use chrono::NaiveDate;
 pub use error::{BacklogError, BacklogResult};
+pub use migration::{migrate, MigrationReport, FORMAT_VERSION};
 pub use models::{
-    AddItem, Assignable, AssignableFromCollection, Backlog, BacklogFixture, BacklogItem, Entity,
-    Estimatable, EstimatableFromCollection, FindFromCollection, Story, Task,
+    content_hash, AddItem, AddedItem, Assignable, AssignableFromCollection, Attachable,
+    AttachableFromCollection, Attachment, Backlog, BacklogFixture, BacklogItem, Board, BoardColumn,
+    BoardConfig, BoardSwimlane, BulkChange, BulkOutcome, BulkReport, BulkResult, Burndown,
+    BurndownDay, Card, Change, Column, Completion, Describable, Directory, Duplicate,
+    DuplicateCluster, Entity, Estimatable, EstimatableFromCollection, FindFromCollection, Forecast,
+    ForecastBasis, Historical, ItemConstructor, ItemEvent, ItemKind, ItemOrder, ItemsForecast,
+    ItemsOutcome, Notification, Placement, Plannable, PlannableFromCollection, Prioritizable,
+    PrioritizableFromCollection, Progressable, ProgressableFromCollection, Rank, Relatable,
+    RelatableFromCollection, Releasable, ReleasableFromCollection, Release, ReleaseItem,
+    ReleaseNotes, ReleaseSection, Simulation, SplitInto, Splittable, Sprint, SprintVelocity, Story,
+    Swimlane, Task, TeamProfile, TeamVelocity, UnresolvedItems, User, VelocityReport, Watchable,
+    WatchableFromCollection, WipPolicy, CONFIDENCES,
 };
-pub use types::{Assignee, StoryPoint, Title};
+pub use types::{Assignee, Priority, Status, StoryPoint, Team, Title};
 pub use uuid::Uuid;
@@ -0,0 +1,231 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{BacklogError, BacklogResult};
+
+/// The format of the backlog document written by this version.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// The document without the version field is the first format.
+const UNVERSIONED: u32 = 0;
+
+const VERSION_FIELD: &str = "format_version";
+
+/// The upgrade of the document from a version to the next.
+struct Migration {
+    description: &'static str,
+    migrate: fn(&mut serde_json::Map<String, Value>) -> BacklogResult<()>,
+}
+
+/// `MIGRATIONS[n]` upgrades the version `n` to `n + 1`.
+const MIGRATIONS: [Migration; FORMAT_VERSION as usize] = [Migration {
+    description: "record the format version",
+    migrate: |_| Ok(()),
+}];
+
+/// What the migration has changed, or would change.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct MigrationReport {
+    from: u32,
+    to: u32,
+    steps: Vec<String>,
+    changes: Vec<String>,
+}
+
+impl MigrationReport {
+    /// The report of the document which is already in the current format.
+    pub fn up_to_date() -> Self {
+        Self {
+            from: FORMAT_VERSION,
+            to: FORMAT_VERSION,
+            steps: Vec::new(),
+            changes: Vec::new(),
+        }
+    }
+
+    pub fn from(&self) -> u32 {
+        self.from
+    }
+
+    pub fn to(&self) -> u32 {
+        self.to
+    }
+
+    /// the descriptions of the applied migrations, in order.
+    pub fn steps(&self) -> &[String] {
+        &self.steps
+    }
+
+    /// the added, removed and modified fields, one per line.
+    pub fn changes(&self) -> &[String] {
+        &self.changes
+    }
+
+    pub fn is_up_to_date(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+impl std::fmt::Display for MigrationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_up_to_date() {
+            return write!(f, "the backlog is up to date, format version {}", self.to);
+        }
+        write!(f, "format version {} → {}", self.from, self.to)?;
+        for (i, step) in self.steps.iter().enumerate() {
+            let from = self.from as usize + i;
+            write!(f, "\n  {} → {}: {}", from, from + 1, step)?;
+        }
+        write!(f, "\nchanges:")?;
+        for change in &self.changes {
+            write!(f, "\n  {}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// Upgrade the backlog document to the current format step by step, before it is deserialized.
+pub fn migrate(document: &mut Value) -> BacklogResult<MigrationReport> {
+    run(document, &MIGRATIONS)
+}
+
+fn run(document: &mut Value, migrations: &[Migration]) -> BacklogResult<MigrationReport> {
+    let before = document.clone();
+    let fields = document
+        .as_object_mut()
+        .ok_or_else(|| BacklogError::migration("the backlog document must be a mapping"))?;
+    let from = match fields.get(VERSION_FIELD) {
+        None => UNVERSIONED,
+        Some(version) => version
+            .as_u64()
+            .map(|version| version as u32)
+            .ok_or_else(|| {
+                BacklogError::migration(format!("the format version is invalid, {}", version))
+            })?,
+    };
+    let to = migrations.len() as u32;
+    if from > to {
+        return Err(BacklogError::migration(format!(
+            "the format version {} is newer than {}, upgrade rjira",
+            from, to
+        )));
+    }
+
+    let mut steps = Vec::new();
+    for (version, migration) in migrations.iter().enumerate().skip(from as usize) {
+        (migration.migrate)(fields).map_err(|err| {
+            BacklogError::migration(format!(
+                "fail to migrate from the format version {}, {}",
+                version, err
+            ))
+        })?;
+        fields.insert(VERSION_FIELD.to_string(), Value::from(version as u32 + 1));
+        steps.push(migration.description.to_string());
+    }
+
+    let mut changes = Vec::new();
+    diff("", &before, document, &mut changes);
+    Ok(MigrationReport {
+        from,
+        to,
+        steps,
+        changes,
+    })
+}
+
+fn diff(path: &str, before: &Value, after: &Value, changes: &mut Vec<String>) {
+    let join = |key: &str| match path {
+        "" => key.to_string(),
+        _ => format!("{}.{}", path, key),
+    };
+    match (before, after) {
+        (Value::Object(before), Value::Object(after)) => {
+            for (key, value) in before {
+                match after.get(key) {
+                    None => changes.push(format!("- {}", join(key))),
+                    Some(after) => diff(&join(key), value, after, changes),
+                }
+            }
+            for (key, value) in after {
+                if !before.contains_key(key) {
+                    changes.push(format!("+ {}: {}", join(key), value));
+                }
+            }
+        }
+        _ if before != after => changes.push(format!("~ {}: {} → {}", path, before, after)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    /// the chain to check that each step runs in order from the version of the document.
+    const CHAIN: [Migration; 3] = [
+        Migration {
+            description: "record the format version",
+            migrate: |_| Ok(()),
+        },
+        Migration {
+            description: "rename points to point",
+            migrate: |fields| {
+                if let Some(points) = fields.remove("points") {
+                    fields.insert("point".to_string(), points);
+                }
+                Ok(())
+            },
+        },
+        Migration {
+            description: "require the id",
+            migrate: |fields| match fields.contains_key("id") {
+                true => Ok(()),
+                false => Err(BacklogError::type_error("no id")),
+            },
+        },
+    ];
+
+    #[test]
+    fn test_migrate() {
+        let mut document = json!({ "id": "a", "points": 3 });
+        let report = run(&mut document, &CHAIN).unwrap();
+        assert_eq!(
+            document,
+            json!({ "id": "a", "point": 3, "format_version": 3 })
+        );
+        assert_eq!((report.from(), report.to()), (0, 3));
+        assert_eq!(report.steps().len(), 3);
+        assert_eq!(
+            report.changes(),
+            &["- points", "+ format_version: 3", "+ point: 3"]
+        );
+
+        // only the steps after the version of the document run.
+        let mut document = json!({ "id": "a", "points": 3, "format_version": 2 });
+        let report = run(&mut document, &CHAIN).unwrap();
+        assert_eq!(report.steps(), &["require the id"]);
+        assert_eq!(document["points"], json!(3));
+
+        let report = run(&mut document, &CHAIN).unwrap();
+        assert!(report.is_up_to_date());
+        assert!(report.changes().is_empty());
+    }
+
+    #[test]
+    fn test_migrate_expect_fail() {
+        assert!(run(&mut json!({ "points": 3 }), &CHAIN).is_err());
+        assert!(run(&mut json!({ "format_version": 4 }), &CHAIN).is_err());
+        assert!(run(&mut json!({ "format_version": "1" }), &CHAIN).is_err());
+        assert!(run(&mut json!([]), &CHAIN).is_err());
+    }
+
+    #[test]
+    fn test_migrate_current() {
+        let mut document = json!({ "id": "a" });
+        let report = migrate(&mut document).unwrap();
+        assert_eq!(report.to(), FORMAT_VERSION);
+        assert_eq!(document[VERSION_FIELD], json!(FORMAT_VERSION));
+    }
+}
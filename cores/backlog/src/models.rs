@@ -1,19 +1,70 @@
 mod add_item;
 mod assignable;
+mod attachable;
+mod attachment;
 mod backlog;
 mod backlog_item;
+mod board;
+mod bulk;
+mod burndown;
+mod changelog;
+mod describable;
+mod directory;
+mod duplicate;
 mod entity;
 mod estimatable;
 mod find_from_collection;
+mod forecast;
+mod historical;
+mod item_kind;
+mod notification;
+mod plannable;
+mod prioritizable;
+mod progressable;
+mod rank;
+mod relatable;
+mod releasable;
+mod release;
+mod splittable;
+mod sprint;
 mod story;
 mod task;
+mod velocity;
+mod watchable;
 
 pub use self::backlog::{Backlog, BacklogFixture};
 pub use add_item::AddItem;
 pub use assignable::{Assignable, AssignableFromCollection};
+pub use attachable::{Attachable, AttachableFromCollection};
+pub use attachment::{content_hash, Attachment};
 pub use backlog_item::BacklogItem;
+pub use board::{
+    Board, BoardColumn, BoardConfig, BoardSwimlane, Card, Column, Swimlane, WipPolicy,
+};
+pub use bulk::{BulkChange, BulkOutcome, BulkReport, BulkResult};
+pub use burndown::{Burndown, BurndownDay};
+pub use describable::Describable;
+pub use directory::{Directory, TeamProfile, User};
+pub use duplicate::{AddedItem, Duplicate, DuplicateCluster};
 pub use entity::Entity;
 pub use estimatable::{Estimatable, EstimatableFromCollection};
 pub use find_from_collection::FindFromCollection;
+pub use forecast::{
+    Completion, Forecast, ForecastBasis, ItemsForecast, ItemsOutcome, Simulation, CONFIDENCES,
+};
+pub use historical::{Change, Historical, ItemEvent};
+pub use item_kind::{ItemConstructor, ItemKind};
+pub use notification::Notification;
+pub use plannable::{Plannable, PlannableFromCollection};
+pub use prioritizable::{Prioritizable, PrioritizableFromCollection};
+pub use progressable::{Progressable, ProgressableFromCollection};
+pub use rank::{ItemOrder, Placement, Rank};
+pub use relatable::{Relatable, RelatableFromCollection};
+pub use releasable::{Releasable, ReleasableFromCollection};
+pub use release::{Release, ReleaseItem, ReleaseNotes, ReleaseSection, UnresolvedItems};
+pub use splittable::{SplitInto, Splittable};
+pub use sprint::Sprint;
 pub use story::Story;
 pub use task::Task;
+pub use velocity::{SprintVelocity, TeamVelocity, VelocityReport};
+pub use watchable::{Watchable, WatchableFromCollection};
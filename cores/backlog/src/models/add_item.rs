@@ -1,14 +1,24 @@
 use indexmap::IndexMap;
 use uuid::Uuid;
 
-use crate::BacklogItem;
+use super::rank::last_rank;
+use crate::{BacklogItem, Change};
 
 /// The collection can be added to the item.
 pub trait AddItem {
     fn mut_items(&mut self) -> &mut IndexMap<Uuid, Box<dyn BacklogItem>>;
 
     /// Add the specific item.
-    fn add_item(&mut self, item: Box<dyn BacklogItem>) {
+    ///
+    /// If the item has no history, it is recorded as added now.
+    /// If the item has no rank, it is ranked last.
+    fn add_item(&mut self, mut item: Box<dyn BacklogItem>) {
+        if item.history().is_empty() {
+            item.record(Change::Added);
+        }
+        if item.rank().is_empty() {
+            *item.mut_rank() = last_rank(self.mut_items());
+        }
         self.mut_items().insert(item.id(), item);
     }
 }
@@ -1,9 +1,12 @@
 use uuid::Uuid;
 
-use crate::{Assignee, BacklogError, BacklogItem, BacklogResult, FindFromCollection};
+use crate::{Assignee, BacklogError, BacklogItem, BacklogResult, Change, FindFromCollection};
 
 /// Represents that the item will be assigned to someone.
 pub trait Assignable {
+    /// accessor
+    fn assignee(&self) -> Option<&Assignee>;
+
     /// mutable accessor
     fn mut_assignee(&mut self) -> &mut Option<Assignee>;
 
@@ -17,15 +20,23 @@ pub trait Assignable {
 pub trait AssignableFromCollection:
     FindFromCollection<Key = Uuid, Ret = Box<dyn BacklogItem>>
 {
+    /// check that the item can be assigned to the assignee.
+    fn validate_assignee(&self, _assignee: &Assignee) -> BacklogResult<()> {
+        Ok(())
+    }
+
     /// assign the specific item to the assignee.
     fn assign_item(&mut self, id: &Uuid, assignee: Assignee) -> BacklogResult<()> {
+        self.validate_assignee(&assignee)?;
         match self.find_by_id_mut(id) {
             None => Err(BacklogError::not_found(format!(
                 "BacklogItem, id: {} does not found",
                 id
             ))),
             Some(item) => {
-                item.assign(assignee);
+                let from = item.assignee().cloned();
+                item.assign(assignee.clone());
+                item.record(Change::Assigned { from, to: assignee });
                 Ok(())
             }
         }
@@ -41,6 +52,10 @@ mod test_assignable {
     }
 
     impl Assignable for TestAssignable {
+        fn assignee(&self) -> Option<&Assignee> {
+            self.assignee.as_ref()
+        }
+
         fn mut_assignee(&mut self) -> &mut Option<Assignee> {
             &mut self.assignee
         }
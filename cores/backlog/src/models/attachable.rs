@@ -0,0 +1,117 @@
+use uuid::Uuid;
+
+use crate::{
+    Attachment, BacklogError, BacklogItem, BacklogResult, Change, Entity, FindFromCollection,
+};
+
+/// Represents that files can be attached to the item.
+pub trait Attachable {
+    /// accessor
+    fn attachments(&self) -> &[Attachment];
+
+    /// mutable accessor
+    fn mut_attachments(&mut self) -> &mut Vec<Attachment>;
+
+    /// find the specific attachment.
+    fn attachment(&self, id: &Uuid) -> Option<&Attachment> {
+        self.attachments().iter().find(|a| &a.id() == id)
+    }
+}
+
+/// The collection can search a specific item and change its attachments.
+pub trait AttachableFromCollection:
+    FindFromCollection<Key = Uuid, Ret = Box<dyn BacklogItem>>
+{
+    /// attach the file to the specific item.
+    fn attach_item(&mut self, id: &Uuid, attachment: Attachment) -> BacklogResult<()> {
+        match self.find_by_id_mut(id) {
+            None => Err(BacklogError::not_found(format!(
+                "BacklogItem, id: {} does not found",
+                id
+            ))),
+            Some(item) => {
+                item.record(Change::Attached {
+                    attachment: attachment.id(),
+                    filename: attachment.filename().to_string(),
+                });
+                item.mut_attachments().push(attachment);
+                Ok(())
+            }
+        }
+    }
+
+    /// remove the specific attachment from the item, and return it.
+    fn detach_item(&mut self, id: &Uuid, attachment: &Uuid) -> BacklogResult<Attachment> {
+        let item = self.find_by_id_mut(id).ok_or_else(|| {
+            BacklogError::not_found(format!("BacklogItem, id: {} does not found", id))
+        })?;
+        let index = item
+            .attachments()
+            .iter()
+            .position(|a| &a.id() == attachment)
+            .ok_or_else(|| {
+                BacklogError::not_found(format!("Attachment, id: {} does not found", attachment))
+            })?;
+        let detached = item.mut_attachments().remove(index);
+        item.record(Change::Detached {
+            attachment: detached.id(),
+            filename: detached.filename().to_string(),
+        });
+        Ok(detached)
+    }
+}
+
+#[cfg(test)]
+mod test_attachable_from_collection {
+    use indexmap::IndexMap;
+
+    use super::*;
+    use crate::{Assignee, Story};
+
+    struct TestCollection(IndexMap<Uuid, Box<dyn BacklogItem>>);
+
+    impl FindFromCollection for TestCollection {
+        type Key = Uuid;
+        type Ret = Box<dyn BacklogItem>;
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+
+        fn find_by_id_mut(&mut self, key: &Self::Key) -> Option<&mut Self::Ret> {
+            self.0.get_mut(key)
+        }
+    }
+
+    impl AttachableFromCollection for TestCollection {}
+
+    #[test]
+    fn test_attach_and_detach_item() {
+        let story: Box<dyn BacklogItem> = Box::new(Story::new("", None, None));
+        let id = story.id();
+        let mut collection = TestCollection(IndexMap::new());
+        collection.0.insert(id, story);
+
+        let attachment = Attachment::new("login.png", b"png", Assignee::new("alice")).unwrap();
+        let attachment_id = attachment.id();
+        collection.attach_item(&id, attachment.clone()).unwrap();
+        assert_eq!(
+            collection.0[&id].attachment(&attachment_id),
+            Some(&attachment)
+        );
+
+        assert!(collection.detach_item(&id, &Uuid::new_v4()).is_err());
+        assert_eq!(
+            collection.detach_item(&id, &attachment_id).unwrap(),
+            attachment
+        );
+        let item = &collection.0[&id];
+        assert!(item.attachments().is_empty());
+        assert_eq!(item.history().len(), 2);
+        assert!(collection.attach_item(&Uuid::new_v4(), attachment).is_err());
+    }
+}
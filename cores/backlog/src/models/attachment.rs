@@ -0,0 +1,99 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::{Assignee, BacklogError, BacklogResult, Entity};
+
+/// The metadata of the file attached to an item.
+///
+/// The content itself is stored apart from the backlog, addressed by its hash.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attachment {
+    id: Uuid,
+    filename: String,
+    size: u64,
+    /// the hex encoded sha256 of the content.
+    hash: String,
+    uploader: Assignee,
+    uploaded_at: DateTime<Utc>,
+}
+
+impl Attachment {
+    /// only the last component of the path is kept as the filename.
+    pub fn new(filename: &str, content: &[u8], uploader: Assignee) -> BacklogResult<Self> {
+        let filename = filename
+            .rsplit(&['/', '\\'][..])
+            .next()
+            .unwrap_or_default()
+            .trim();
+        if filename.is_empty() || filename == "." || filename == ".." {
+            return Err(BacklogError::type_error(format!(
+                "the filename of the attachment is invalid, {:?}",
+                filename
+            )));
+        }
+        Ok(Self {
+            id: Uuid::new_v4(),
+            filename: filename.to_string(),
+            size: content.len() as u64,
+            hash: content_hash(content),
+            uploader,
+            uploaded_at: Utc::now(),
+        })
+    }
+
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    pub fn uploader(&self) -> &Assignee {
+        &self.uploader
+    }
+
+    pub fn uploaded_at(&self) -> DateTime<Utc> {
+        self.uploaded_at
+    }
+}
+
+impl Entity for Attachment {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+/// return the hex encoded sha256 of the content, which addresses the stored content.
+pub fn content_hash(content: &[u8]) -> String {
+    Sha256::digest(content)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let attachment =
+            Attachment::new("../designs/login.png", b"abc", Assignee::new("alice")).unwrap();
+        assert_eq!(attachment.filename(), "login.png");
+        assert_eq!(attachment.size(), 3);
+        assert_eq!(
+            attachment.hash(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+
+        assert!(Attachment::new("designs/", b"abc", Assignee::new("alice")).is_err());
+        assert!(Attachment::new("..", b"abc", Assignee::new("alice")).is_err());
+    }
+}
@@ -1,26 +1,468 @@
+use chrono::NaiveDate;
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::{burndown, changelog, duplicate, notification, rank, release};
 use crate::{
-    AddItem, AssignableFromCollection, BacklogItem, Entity, EstimatableFromCollection,
-    FindFromCollection,
+    AddItem, AssignableFromCollection, Assignee, AttachableFromCollection, Attachment,
+    BacklogError, BacklogItem, BacklogResult, Board, BoardConfig, BulkChange, BulkReport, Burndown,
+    Change, Directory, Duplicate, DuplicateCluster, Entity, EstimatableFromCollection,
+    FindFromCollection, Forecast, ForecastBasis, ItemOrder, ItemsForecast, Notification, Placement,
+    PlannableFromCollection, PrioritizableFromCollection, ProgressableFromCollection, Rank,
+    RelatableFromCollection, ReleasableFromCollection, Release, ReleaseNotes, Simulation,
+    SplitInto, Sprint, Swimlane, TeamProfile, UnresolvedItems, User, VelocityReport,
+    WatchableFromCollection, FORMAT_VERSION,
 };
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, derive(getset::Setters), set = "pub")]
 pub struct Backlog {
+    /// the document is migrated to the current format before it is deserialized.
+    #[serde(default = "current_format_version")]
+    format_version: u32,
+    /// the number of the saves, which tells the stale copy of the backlog.
+    #[serde(default)]
+    revision: u64,
     id: Uuid,
     items: IndexMap<Uuid, Box<dyn BacklogItem>>,
+    #[serde(default)]
+    sprints: IndexMap<Uuid, Sprint>,
+    #[serde(default)]
+    directory: Directory,
+    #[serde(default)]
+    board: BoardConfig,
+    #[serde(default)]
+    releases: IndexMap<Uuid, Release>,
 }
 
 impl Backlog {
     pub fn new() -> Self {
         Self {
+            format_version: FORMAT_VERSION,
+            revision: 0,
             id: Uuid::new_v4(),
             items: IndexMap::new(),
+            sprints: IndexMap::new(),
+            directory: Directory::default(),
+            board: BoardConfig::default(),
+            releases: IndexMap::new(),
         }
     }
+
+    pub fn format_version(&self) -> u32 {
+        self.format_version
+    }
+
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// The repository gives the revision, e.g. the next one on save.
+    pub fn with_revision(self, revision: u64) -> Self {
+        Self { revision, ..self }
+    }
+
+    pub fn items(&self) -> &IndexMap<Uuid, Box<dyn BacklogItem>> {
+        &self.items
+    }
+
+    pub fn sprints(&self) -> &IndexMap<Uuid, Sprint> {
+        &self.sprints
+    }
+
+    pub fn directory(&self) -> &Directory {
+        &self.directory
+    }
+
+    pub fn board_config(&self) -> &BoardConfig {
+        &self.board
+    }
+
+    /// Replace the columns, swimlane and WIP policy of the board.
+    pub fn configure_board(&mut self, config: BoardConfig) {
+        self.board = config;
+    }
+
+    /// Build the board. `swimlane` overrides the configured swimlane.
+    pub fn board(&self, swimlane: Option<Swimlane>) -> Board {
+        self.board.board(self.items.values(), swimlane)
+    }
+
+    /// Move the specific item into the column of the board.
+    ///
+    /// If the column is full, it fails or succeeds according to the WIP policy.
+    pub fn move_item(&mut self, id: &Uuid, column: &str) -> BacklogResult<()> {
+        let column = self.board.column(column)?;
+        let status = match self.items.get(id) {
+            None => {
+                return Err(BacklogError::not_found(format!(
+                    "BacklogItem, id: {} does not found",
+                    id
+                )))
+            }
+            Some(item) if column.statuses().contains(&item.status()) => return Ok(()),
+            Some(_) => column.status(),
+        };
+        let count = self
+            .items
+            .values()
+            .filter(|item| column.statuses().contains(&item.status()))
+            .count();
+        self.board.check_wip_limit(column, count)?;
+        self.change_item_status(id, status)
+    }
+
+    /// Add the user to the directory.
+    pub fn add_user(&mut self, user: User) -> BacklogResult<()> {
+        self.directory.add_user(user)
+    }
+
+    /// Add the team to the directory.
+    pub fn add_team(&mut self, team: TeamProfile) -> BacklogResult<()> {
+        self.directory.add_team(team)
+    }
+
+    /// Update the display name and the active flag of the user.
+    pub fn update_user(
+        &mut self,
+        handle: &str,
+        display_name: Option<&str>,
+        active: Option<bool>,
+    ) -> BacklogResult<()> {
+        self.directory.update_user(handle, display_name, active)
+    }
+
+    /// Update the display name and the active flag of the team.
+    pub fn update_team(
+        &mut self,
+        handle: &str,
+        display_name: Option<&str>,
+        active: Option<bool>,
+    ) -> BacklogResult<()> {
+        self.directory.update_team(handle, display_name, active)
+    }
+
+    /// Rename the user, and reassign every item assigned to the user.
+    ///
+    /// Return the number of reassigned items.
+    pub fn rename_user(&mut self, handle: &str, new_handle: &str) -> BacklogResult<usize> {
+        self.directory.rename_user(handle, new_handle)?;
+
+        let from = Assignee::new(handle);
+        let to = Assignee::new(new_handle);
+        let mut count = 0;
+        for item in self.items.values_mut() {
+            if item.assignee() != Some(&from) {
+                continue;
+            }
+            item.assign(to.clone());
+            item.record(Change::Assigned {
+                from: Some(from.clone()),
+                to: to.clone(),
+            });
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Return copies of the items sorted in the specific order.
+    pub fn sorted_items(&self, order: ItemOrder) -> Vec<Box<dyn BacklogItem>> {
+        let mut items: Vec<Box<dyn BacklogItem>> = self.items.values().cloned().collect();
+        match order {
+            ItemOrder::Rank => items.sort_by(|a, b| a.rank().cmp(b.rank())),
+            ItemOrder::Priority => {
+                items.sort_by(|a, b| (a.priority(), a.rank()).cmp(&(b.priority(), b.rank())))
+            }
+        }
+        items
+    }
+
+    /// Move the specific item in the ranking.
+    ///
+    /// Only the rank of the item changes, unless the new rank gets too long and all ranks are rebalanced.
+    pub fn rank_item(&mut self, id: &Uuid, placement: Placement) -> BacklogResult<()> {
+        if !self.items.contains_key(id) {
+            return Err(BacklogError::not_found(format!(
+                "BacklogItem, id: {} does not found",
+                id
+            )));
+        }
+        if self.items.values().any(|item| item.rank().is_empty()) {
+            rank::rebalance(&mut self.items);
+        }
+        let mut rank = self.rank_at(id, placement)?;
+        if rank.value().len() > Rank::MAX_LENGTH {
+            rank::rebalance(&mut self.items);
+            rank = self.rank_at(id, placement)?;
+        }
+        if let Some(item) = self.items.get_mut(id) {
+            *item.mut_rank() = rank;
+        }
+        Ok(())
+    }
+
+    /// make the rank of the placement, ignoring the item itself.
+    fn rank_at(&self, id: &Uuid, placement: Placement) -> BacklogResult<Rank> {
+        let ids: Vec<Uuid> = rank::ranked_ids(&self.items)
+            .into_iter()
+            .filter(|other| other != id)
+            .collect();
+        let position = |target: &Uuid| {
+            if target == id {
+                return Err(BacklogError::type_error(
+                    "the item can not be placed relative to itself",
+                ));
+            }
+            ids.iter().position(|other| other == target).ok_or_else(|| {
+                BacklogError::not_found(format!("BacklogItem, id: {} does not found", target))
+            })
+        };
+        let (prev, next) = match placement {
+            Placement::Top => (None, ids.first()),
+            Placement::Bottom => (ids.last(), None),
+            Placement::After(target) => {
+                let i = position(&target)?;
+                (ids.get(i), ids.get(i + 1))
+            }
+            Placement::Before(target) => {
+                let i = position(&target)?;
+                (i.checked_sub(1).and_then(|i| ids.get(i)), ids.get(i))
+            }
+        };
+        let rank_of = |id: Option<&Uuid>| id.map(|id| self.items[id].rank());
+        Rank::between(rank_of(prev), rank_of(next))
+    }
+
+    /// Split the specific item into new items, and return their ids.
+    ///
+    /// The children are ranked right after the original, which keeps references to them.
+    pub fn split_item(&mut self, id: &Uuid, into: &SplitInto) -> BacklogResult<Vec<Uuid>> {
+        let item = self.items.get_mut(id).ok_or_else(|| {
+            BacklogError::not_found(format!("BacklogItem, id: {} does not found", id))
+        })?;
+        let children = item.split(into)?;
+        let ids: Vec<Uuid> = children.iter().map(|child| child.id()).collect();
+        item.mark_split(ids.clone());
+        item.record(Change::Split { into: ids.clone() });
+
+        let mut prev = *id;
+        for child in children {
+            let child_id = child.id();
+            self.add_item(child);
+            self.rank_item(&child_id, Placement::After(prev))?;
+            prev = child_id;
+        }
+        Ok(ids)
+    }
+
+    /// Add the item, and return the existing items which look like it.
+    ///
+    /// Unless it is forced, the item is refused when any item looks like it.
+    pub fn add_item_checked(
+        &mut self,
+        item: Box<dyn BacklogItem>,
+        force: bool,
+    ) -> BacklogResult<Vec<Duplicate>> {
+        duplicate::add_item_checked(self, item, force)
+    }
+
+    /// Find the items whose titles look like the title.
+    pub fn similar_items(&self, title: &str, threshold: f64) -> BacklogResult<Vec<Duplicate>> {
+        duplicate::similar_items(self, title, threshold)
+    }
+
+    /// Group the items which look like each other.
+    pub fn duplicate_clusters(&self, threshold: f64) -> BacklogResult<Vec<DuplicateCluster>> {
+        duplicate::duplicate_clusters(self, threshold)
+    }
+
+    /// Apply the change to every specific item.
+    ///
+    /// All ids and the change itself are validated first, so nothing is changed if any of them is invalid.
+    pub fn apply_bulk(&mut self, ids: &[Uuid], change: &BulkChange) -> BacklogResult<BulkReport> {
+        BulkReport::apply(self, ids, change)
+    }
+
+    pub fn releases(&self) -> &IndexMap<Uuid, Release> {
+        &self.releases
+    }
+
+    /// Add the release, whose name must be unique.
+    pub fn add_release(&mut self, release: Release) -> BacklogResult<()> {
+        if self.releases.values().any(|r| r.name() == release.name()) {
+            return Err(BacklogError::type_error(format!(
+                "the release already exists, {}",
+                release.name()
+            )));
+        }
+        self.releases.insert(release.id(), release);
+        Ok(())
+    }
+
+    /// Find the release by its name.
+    pub fn release(&self, name: &str) -> BacklogResult<&Release> {
+        self.releases
+            .values()
+            .find(|release| release.name() == name)
+            .ok_or_else(|| {
+                BacklogError::not_found(format!("Release, name: {} does not found", name))
+            })
+    }
+
+    /// Target the specific item at the release, or at no release with `None`.
+    ///
+    /// No item can be added to the shipped release.
+    pub fn set_fix_version(&mut self, id: &Uuid, release: Option<&str>) -> BacklogResult<()> {
+        let release = match release {
+            None => None,
+            Some(name) => {
+                let release = self.release(name)?;
+                if release.is_released() {
+                    return Err(BacklogError::type_error(format!(
+                        "the release has already been shipped, {}",
+                        name
+                    )));
+                }
+                Some(release.id())
+            }
+        };
+        self.fix_item_version(id, release)
+    }
+
+    /// Make the notes of the done items in the release, grouped by type.
+    pub fn release_notes(&self, name: &str) -> BacklogResult<ReleaseNotes> {
+        Ok(release::release_notes(self, self.release(name)?))
+    }
+
+    /// Report the items in the release which are not done yet.
+    pub fn unresolved_items(&self, name: &str) -> BacklogResult<UnresolvedItems> {
+        Ok(release::unresolved_items(self, self.release(name)?))
+    }
+
+    /// Mark the release as shipped. It is refused while any item is unresolved.
+    pub fn ship_release(&mut self, name: &str) -> BacklogResult<()> {
+        let mut release = self.release(name)?.clone();
+        release::ship(self, &mut release)?;
+        self.releases.insert(release.id(), release);
+        Ok(())
+    }
+
+    /// Make a notification for each change of the watched items since `before`.
+    pub fn notifications(&self, before: &Backlog) -> Vec<Notification> {
+        notification::notifications(self, before)
+    }
+
+    /// Describe each change since `before` in a line, e.g. `Estimate "Login page": 3 → 5`.
+    pub fn changelog(&self, before: &Backlog) -> Vec<String> {
+        changelog::changelog(self, before)
+    }
+
+    /// List the attachments of the specific item.
+    pub fn attachments(&self, item: &Uuid) -> BacklogResult<&[Attachment]> {
+        self.items
+            .get(item)
+            .map(|item| item.attachments())
+            .ok_or_else(|| {
+                BacklogError::not_found(format!("BacklogItem, id: {} does not found", item))
+            })
+    }
+
+    /// Find the specific attachment of the specific item.
+    pub fn attachment(&self, item: &Uuid, attachment: &Uuid) -> BacklogResult<&Attachment> {
+        self.attachments(item)?
+            .iter()
+            .find(|a| &a.id() == attachment)
+            .ok_or_else(|| {
+                BacklogError::not_found(format!("Attachment, id: {} does not found", attachment))
+            })
+    }
+
+    /// Whether any attachment still refers to the content of the hash.
+    pub fn refers_content(&self, hash: &str) -> bool {
+        self.items
+            .values()
+            .flat_map(|item| item.attachments())
+            .any(|attachment| attachment.hash() == hash)
+    }
+
+    /// Add the specific sprint.
+    ///
+    /// The team can not have two sprints of the same name.
+    pub fn add_sprint(&mut self, sprint: Sprint) -> BacklogResult<()> {
+        if self
+            .sprints
+            .values()
+            .any(|s| s.team() == sprint.team() && s.name() == sprint.name())
+        {
+            return Err(BacklogError::type_error(format!(
+                "the sprint already exists, {} of {}",
+                sprint.name(),
+                sprint.team().name()
+            )));
+        }
+        self.sprints.insert(sprint.id(), sprint);
+        Ok(())
+    }
+
+    /// Plan the specific item into the sprint, or out of any sprint with `None`.
+    pub fn plan_item_into_sprint(&mut self, id: &Uuid, sprint: Option<Uuid>) -> BacklogResult<()> {
+        if let Some(sprint) = &sprint {
+            if !self.sprints.contains_key(sprint) {
+                return Err(BacklogError::not_found(format!(
+                    "Sprint, id: {} does not found",
+                    sprint
+                )));
+            }
+        }
+        self.plan_item(id, sprint)
+    }
+
+    /// Sum the story points of the items completed within the specific sprint, by their history.
+    pub fn completed_points(&self, sprint: &Uuid) -> u32 {
+        self.sprints
+            .get(sprint)
+            .map(|sprint| burndown::completed_within(self, sprint).0)
+            .unwrap_or(0)
+    }
+
+    /// Count the items completed within the specific sprint, by their history.
+    pub fn completed_items(&self, sprint: &Uuid) -> u32 {
+        self.sprints
+            .get(sprint)
+            .map(|sprint| burndown::completed_within(self, sprint).1)
+            .unwrap_or(0)
+    }
+
+    /// Calculate the velocity of each team over the last `window` sprints.
+    pub fn velocity(&self, window: usize, today: NaiveDate) -> BacklogResult<VelocityReport> {
+        VelocityReport::new(self, window, today)
+    }
+
+    /// Rebuild the daily burndown of the specific sprint until `today`.
+    pub fn burndown(&self, sprint: &Uuid, today: NaiveDate) -> BacklogResult<Burndown> {
+        Burndown::new(self, sprint, today)
+    }
+
+    /// Forecast when the remaining items will be completed.
+    pub fn forecast(
+        &self,
+        basis: ForecastBasis,
+        simulation: Simulation,
+        today: NaiveDate,
+    ) -> BacklogResult<Forecast> {
+        Forecast::new(self, basis, simulation, today)
+    }
+
+    /// Forecast how many items will be done by the specific date.
+    pub fn forecast_items(
+        &self,
+        date: NaiveDate,
+        simulation: Simulation,
+        today: NaiveDate,
+    ) -> BacklogResult<ItemsForecast> {
+        ItemsForecast::new(self, date, simulation, today)
+    }
 }
 
 impl Default for Backlog {
@@ -58,10 +500,32 @@ impl FindFromCollection for Backlog {
     }
 }
 
-impl AssignableFromCollection for Backlog {}
+impl AssignableFromCollection for Backlog {
+    fn validate_assignee(&self, assignee: &Assignee) -> BacklogResult<()> {
+        self.directory.validate_assignee(assignee)
+    }
+}
 
 impl EstimatableFromCollection for Backlog {}
 
+fn current_format_version() -> u32 {
+    FORMAT_VERSION
+}
+
+impl PlannableFromCollection for Backlog {}
+
+impl PrioritizableFromCollection for Backlog {}
+
+impl ProgressableFromCollection for Backlog {}
+
+impl RelatableFromCollection for Backlog {}
+
+impl ReleasableFromCollection for Backlog {}
+
+impl WatchableFromCollection for Backlog {}
+
+impl AttachableFromCollection for Backlog {}
+
 pub trait BacklogFixture {
     fn empty_items() -> Self;
     fn specific_id() -> (Uuid, Self);
@@ -86,9 +550,173 @@ impl BacklogFixture for Backlog {
                     "point": null,
                     "assignee": null
                 }
+            },
+            "directory": {
+                "users": {
+                    "dummy": {
+                        "handle": "dummy",
+                        "display_name": "Dummy",
+                        "active": true
+                    }
+                }
             }
         });
         let backlog = serde_json::from_value(backlog).unwrap();
         (item_id, backlog)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AssignableFromCollection, Priority, Story, StoryPoint, Team};
+
+    #[test]
+    fn test_rank_item() {
+        let mut backlog = Backlog::new();
+        let ids: Vec<Uuid> = (0..3)
+            .map(|_| {
+                let story = Story::new("", None, None);
+                let id = story.id();
+                backlog.add_item(Box::new(story));
+                id
+            })
+            .collect();
+
+        backlog
+            .rank_item(&ids[2], Placement::After(ids[0]))
+            .unwrap();
+        backlog.rank_item(&ids[1], Placement::Top).unwrap();
+        backlog.prioritize_item(&ids[0], Priority::High).unwrap();
+
+        let order = |order| -> Vec<Uuid> {
+            backlog
+                .sorted_items(order)
+                .iter()
+                .map(|item| item.id())
+                .collect()
+        };
+        assert_eq!(order(ItemOrder::Rank), vec![ids[1], ids[0], ids[2]]);
+        assert_eq!(order(ItemOrder::Priority), vec![ids[0], ids[1], ids[2]]);
+        assert!(backlog
+            .rank_item(&ids[0], Placement::After(ids[0]))
+            .is_err());
+    }
+
+    #[test]
+    fn test_rank_item_rebalance() {
+        let mut backlog = Backlog::new();
+        let story = Story::new("", None, None);
+        let first = story.id();
+        backlog.add_item(Box::new(story));
+        let mut last = first;
+        for _ in 0..100 {
+            let story = Story::new("", None, None);
+            last = story.id();
+            backlog.add_item(Box::new(story));
+            // always insert right after the first item, so the rank gets longer.
+            backlog.rank_item(&last, Placement::After(first)).unwrap();
+        }
+
+        assert!(backlog
+            .items()
+            .values()
+            .all(|item| item.rank().value().len() <= Rank::MAX_LENGTH));
+        assert_eq!(backlog.sorted_items(ItemOrder::Rank)[1].id(), last);
+    }
+
+    #[test]
+    fn test_split_item() {
+        let mut backlog = Backlog::new();
+        let story = Story::new("big", Some(StoryPoint::new(13).unwrap()), None);
+        let id = story.id();
+        backlog.add_item(Box::new(story));
+        backlog.add_item(Box::new(Story::new("next", None, None)));
+
+        let children = backlog.split_item(&id, &SplitInto::Count(2)).unwrap();
+
+        assert_eq!(backlog.items()[&id].split_into(), children.as_slice());
+        let sorted = backlog.sorted_items(ItemOrder::Rank);
+        let titles: Vec<&str> = sorted.iter().map(|item| item.title().value()).collect();
+        assert_eq!(titles, vec!["big", "big (1/2)", "big (2/2)", "next"]);
+        assert!(backlog.items()[&children[0]].point().is_none());
+        assert!(backlog.split_item(&id, &SplitInto::Count(2)).is_err());
+    }
+
+    #[test]
+    fn test_split_item_inherits_relations() {
+        let mut backlog = Backlog::new();
+        let mut ids = Vec::new();
+        for title in ["big", "epic", "related"] {
+            let story = Story::new(title, None, None);
+            ids.push(story.id());
+            backlog.add_item(Box::new(story));
+        }
+        let (id, epic, related) = (ids[0], ids[1], ids[2]);
+        backlog
+            .label_item(&id, vec!["ui".to_string(), "login".to_string()])
+            .unwrap();
+        backlog.set_item_epic(&id, Some(epic)).unwrap();
+        backlog.link_item(&id, vec![related]).unwrap();
+
+        let children = backlog.split_item(&id, &SplitInto::Count(2)).unwrap();
+
+        for child in &children {
+            let child = &backlog.items()[child];
+            assert_eq!(child.labels(), ["ui", "login"]);
+            assert_eq!(child.epic(), Some(epic));
+            assert_eq!(child.links(), [related]);
+        }
+    }
+
+    #[test]
+    fn test_rename_user() {
+        let mut backlog = Backlog::new();
+        backlog
+            .add_user(User::new("alice", "Alice").unwrap())
+            .unwrap();
+        let story = Story::new("", None, Some(Assignee::new("alice")));
+        let id = story.id();
+        backlog.add_item(Box::new(story));
+
+        assert_eq!(backlog.rename_user("alice", "alicia").unwrap(), 1);
+        let item = &backlog.items()[&id];
+        assert_eq!(item.assignee(), Some(&Assignee::new("alicia")));
+        assert!(matches!(
+            item.history().last().unwrap().change(),
+            Change::Assigned { .. }
+        ));
+    }
+
+    #[test]
+    fn test_assign_item_validates_assignee() {
+        let (id, mut backlog) = Backlog::specific_id();
+        assert!(backlog.assign_item(&id, Assignee::new("dummy")).is_ok());
+        assert!(backlog.assign_item(&id, Assignee::new("dumy")).is_err());
+
+        backlog.update_user("dummy", None, Some(false)).unwrap();
+        assert!(backlog.assign_item(&id, Assignee::new("dummy")).is_err());
+    }
+
+    #[test]
+    fn test_plan_item_into_sprint() {
+        let (id, mut backlog) = Backlog::specific_id();
+        let start = NaiveDate::from_ymd_opt(2022, 1, 3).unwrap();
+        let end = NaiveDate::from_ymd_opt(2022, 1, 14).unwrap();
+        let sprint = Sprint::new("sprint 1", Team::new("team"), start, end).unwrap();
+        let sprint_id = sprint.id();
+        backlog.add_sprint(sprint).unwrap();
+        let same = Sprint::new("sprint 1", Team::new("team"), start, end).unwrap();
+        assert!(backlog.add_sprint(same).is_err());
+        let other = Sprint::new("sprint 1", Team::new("other"), start, end).unwrap();
+        assert!(backlog.add_sprint(other).is_ok());
+
+        backlog.plan_item_into_sprint(&id, Some(sprint_id)).unwrap();
+        assert_eq!(backlog.items()[&id].sprint(), Some(sprint_id));
+        assert!(backlog
+            .plan_item_into_sprint(&id, Some(Uuid::new_v4()))
+            .is_err());
+        backlog.plan_item_into_sprint(&id, None).unwrap();
+        assert_eq!(backlog.items()[&id].sprint(), None);
+    }
+}
@@ -2,10 +2,32 @@ use std::fmt::Debug;
 
 use dyn_clone::DynClone;
 
-use crate::{Assignable, Entity, Estimatable};
+use crate::{
+    Assignable, Attachable, Describable, Entity, Estimatable, Historical, Plannable, Prioritizable,
+    Progressable, Relatable, Releasable, Splittable, Watchable,
+};
 
 #[typetag::serde(tag = "type")]
-pub trait BacklogItem: DynClone + Debug + Send + Sync + Assignable + Estimatable + Entity {}
+pub trait BacklogItem:
+    DynClone
+    + Debug
+    + Send
+    + Sync
+    + Assignable
+    + Attachable
+    + Describable
+    + Estimatable
+    + Plannable
+    + Prioritizable
+    + Progressable
+    + Relatable
+    + Releasable
+    + Splittable
+    + Watchable
+    + Historical
+    + Entity
+{
+}
 
 dyn_clone::clone_trait_object!(BacklogItem);
 
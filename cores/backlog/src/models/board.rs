@@ -0,0 +1,460 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{BacklogError, BacklogItem, BacklogResult, Status};
+
+/// How the board is configured.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoardConfig {
+    columns: Vec<Column>,
+    #[serde(default)]
+    swimlane: Swimlane,
+    #[serde(default)]
+    wip_policy: WipPolicy,
+}
+
+/// The column of the board. Items whose status is one of `statuses` are shown in it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Column {
+    name: String,
+    statuses: Vec<Status>,
+    #[serde(default)]
+    wip_limit: Option<usize>,
+}
+
+/// How the items are grouped into rows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Swimlane {
+    None,
+    Assignee,
+    Type,
+}
+
+/// What happens when an item is moved into a full column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WipPolicy {
+    /// refuse to move the item.
+    Fail,
+    /// move the item and report the exceeded column.
+    Warn,
+}
+
+/// The snapshot of the board.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Board {
+    columns: Vec<BoardColumn>,
+    swimlanes: Vec<BoardSwimlane>,
+    warnings: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct BoardColumn {
+    name: String,
+    wip_limit: Option<usize>,
+    count: usize,
+}
+
+/// The row of the board. It has a cell for each column.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct BoardSwimlane {
+    name: Option<String>,
+    cells: Vec<Vec<Card>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Card {
+    id: Uuid,
+    kind: String,
+    title: String,
+    point: Option<u8>,
+    assignee: Option<String>,
+}
+
+impl BoardConfig {
+    /// Every status must be shown in exactly one column.
+    pub fn new(
+        columns: Vec<Column>,
+        swimlane: Swimlane,
+        wip_policy: WipPolicy,
+    ) -> BacklogResult<Self> {
+        for (i, column) in columns.iter().enumerate() {
+            if columns[..i].iter().any(|c| c.name == column.name) {
+                return Err(BacklogError::type_error(format!(
+                    "the column is duplicated, {}",
+                    column.name
+                )));
+            }
+        }
+        for status in [Status::Todo, Status::InProgress, Status::Done] {
+            let count = columns
+                .iter()
+                .filter(|c| c.statuses.contains(&status))
+                .count();
+            if count != 1 {
+                return Err(BacklogError::type_error(format!(
+                    "the status must be mapped to exactly one column, {:?}",
+                    status
+                )));
+            }
+        }
+        Ok(Self {
+            columns,
+            swimlane,
+            wip_policy,
+        })
+    }
+
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    pub fn swimlane(&self) -> Swimlane {
+        self.swimlane
+    }
+
+    pub fn wip_policy(&self) -> WipPolicy {
+        self.wip_policy
+    }
+
+    /// find the column by name.
+    pub fn column(&self, name: &str) -> BacklogResult<&Column> {
+        self.columns.iter().find(|c| c.name == name).ok_or_else(|| {
+            BacklogError::not_found(format!("Column, name: {} does not found", name))
+        })
+    }
+
+    /// check that the item can be moved into the column which already has `count` items.
+    pub fn check_wip_limit(&self, column: &Column, count: usize) -> BacklogResult<()> {
+        match (column.wip_limit, self.wip_policy) {
+            (Some(limit), WipPolicy::Fail) if count >= limit => {
+                Err(BacklogError::limit_exceeded(format!(
+                    "the column {} has reached the WIP limit of {}",
+                    column.name, limit
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// build the board from items.
+    pub fn board<'a>(
+        &self,
+        items: impl Iterator<Item = &'a Box<dyn BacklogItem>>,
+        swimlane: Option<Swimlane>,
+    ) -> Board {
+        let swimlane = swimlane.unwrap_or(self.swimlane);
+        let mut swimlanes: Vec<BoardSwimlane> = Vec::new();
+        let mut counts = vec![0; self.columns.len()];
+        for item in items {
+            let index = match self.column_index(item.status()) {
+                Some(index) => index,
+                None => continue,
+            };
+            counts[index] += 1;
+
+            let name = match swimlane {
+                Swimlane::None => None,
+                Swimlane::Assignee => item.assignee().map(|a| a.name().to_string()),
+                Swimlane::Type => Some(item.kind().to_string()),
+            };
+            let lane = match swimlanes.iter().position(|lane| lane.name == name) {
+                Some(lane) => lane,
+                None => {
+                    swimlanes.push(BoardSwimlane {
+                        name,
+                        cells: vec![Vec::new(); self.columns.len()],
+                    });
+                    swimlanes.len() - 1
+                }
+            };
+            swimlanes[lane].cells[index].push(Card::from(item.as_ref()));
+        }
+        // unassigned items come last.
+        swimlanes.sort_by(|a, b| match (&a.name, &b.name) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        });
+
+        let columns: Vec<BoardColumn> = self
+            .columns
+            .iter()
+            .zip(counts)
+            .map(|(column, count)| BoardColumn {
+                name: column.name.clone(),
+                wip_limit: column.wip_limit,
+                count,
+            })
+            .collect();
+        let warnings = columns
+            .iter()
+            .filter_map(|column| match column.wip_limit {
+                Some(limit) if column.count > limit => Some(format!(
+                    "the column {} exceeds the WIP limit, {} / {}",
+                    column.name, column.count, limit
+                )),
+                _ => None,
+            })
+            .collect();
+        Board {
+            columns,
+            swimlanes,
+            warnings,
+        }
+    }
+
+    fn column_index(&self, status: Status) -> Option<usize> {
+        self.columns
+            .iter()
+            .position(|c| c.statuses.contains(&status))
+    }
+}
+
+impl Default for BoardConfig {
+    fn default() -> Self {
+        let column = |name: &str, status| Column {
+            name: name.to_string(),
+            statuses: vec![status],
+            wip_limit: None,
+        };
+        Self {
+            columns: vec![
+                column("Todo", Status::Todo),
+                column("In Progress", Status::InProgress),
+                column("Done", Status::Done),
+            ],
+            swimlane: Swimlane::default(),
+            wip_policy: WipPolicy::default(),
+        }
+    }
+}
+
+impl Column {
+    pub fn new(name: &str, statuses: Vec<Status>, wip_limit: Option<usize>) -> BacklogResult<Self> {
+        if statuses.is_empty() {
+            return Err(BacklogError::type_error(format!(
+                "the column must have at least one status, {}",
+                name
+            )));
+        }
+        if wip_limit == Some(0) {
+            return Err(BacklogError::type_error(
+                "the WIP limit must be greater than 0",
+            ));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            statuses,
+            wip_limit,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn statuses(&self) -> &[Status] {
+        &self.statuses
+    }
+
+    pub fn wip_limit(&self) -> Option<usize> {
+        self.wip_limit
+    }
+
+    /// The status given to items moved into this column.
+    pub fn status(&self) -> Status {
+        self.statuses[0]
+    }
+}
+
+impl Default for Swimlane {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+impl std::str::FromStr for Swimlane {
+    type Err = BacklogError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "assignee" => Ok(Self::Assignee),
+            "type" => Ok(Self::Type),
+            _ => Err(BacklogError::type_error(format!(
+                "the swimlane must be none, assignee or type, {}",
+                s
+            ))),
+        }
+    }
+}
+
+impl Default for WipPolicy {
+    fn default() -> Self {
+        Self::Fail
+    }
+}
+
+impl std::str::FromStr for WipPolicy {
+    type Err = BacklogError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fail" => Ok(Self::Fail),
+            "warn" => Ok(Self::Warn),
+            _ => Err(BacklogError::type_error(format!(
+                "the WIP policy must be fail or warn, {}",
+                s
+            ))),
+        }
+    }
+}
+
+impl Board {
+    pub fn columns(&self) -> &[BoardColumn] {
+        &self.columns
+    }
+
+    pub fn swimlanes(&self) -> &[BoardSwimlane] {
+        &self.swimlanes
+    }
+
+    /// the columns which exceed their WIP limits.
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+}
+
+impl BoardColumn {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn wip_limit(&self) -> Option<usize> {
+        self.wip_limit
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl BoardSwimlane {
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn cells(&self) -> &[Vec<Card>] {
+        &self.cells
+    }
+}
+
+impl Card {
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn point(&self) -> Option<u8> {
+        self.point
+    }
+
+    pub fn assignee(&self) -> Option<&str> {
+        self.assignee.as_deref()
+    }
+}
+
+impl From<&dyn BacklogItem> for Card {
+    fn from(item: &dyn BacklogItem) -> Self {
+        Self {
+            id: item.id(),
+            kind: item.kind().to_string(),
+            title: item.title().value().to_string(),
+            point: item.point().map(|p| p.value()),
+            assignee: item.assignee().map(|a| a.name().to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AddItem, Assignee, Backlog, Entity, ProgressableFromCollection, Story, Task};
+
+    fn config(wip_policy: WipPolicy) -> BoardConfig {
+        BoardConfig::new(
+            vec![
+                Column::new("Todo", vec![Status::Todo], None).unwrap(),
+                Column::new("Doing", vec![Status::InProgress], Some(1)).unwrap(),
+                Column::new("Done", vec![Status::Done], None).unwrap(),
+            ],
+            Swimlane::None,
+            wip_policy,
+        )
+        .unwrap()
+    }
+
+    fn backlog(wip_policy: WipPolicy) -> (Backlog, Uuid, Uuid) {
+        let mut backlog = Backlog::new();
+        backlog.configure_board(config(wip_policy));
+        let story = Story::new("story", None, Some(Assignee::new("alice")));
+        let task = Task::new("task", None, None);
+        let (story_id, task_id) = (story.id(), task.id());
+        backlog.add_item(Box::new(story));
+        backlog.add_item(Box::new(task));
+        (backlog, story_id, task_id)
+    }
+
+    #[test]
+    fn test_new_expect_fail() {
+        let columns = vec![Column::new("Todo", vec![Status::Todo], None).unwrap()];
+        assert!(BoardConfig::new(columns, Swimlane::None, WipPolicy::Fail).is_err());
+    }
+
+    #[test]
+    fn test_move_item() {
+        let (mut backlog, story, task) = backlog(WipPolicy::Fail);
+        backlog.move_item(&story, "Doing").unwrap();
+        assert!(backlog.move_item(&task, "Doing").is_err());
+        // moving within the same column does not count the item twice.
+        backlog.move_item(&story, "Doing").unwrap();
+
+        let board = backlog.board(None);
+        assert_eq!(board.columns()[0].count(), 1);
+        assert_eq!(board.columns()[1].count(), 1);
+        assert!(board.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_move_item_warn() {
+        let (mut backlog, story, task) = backlog(WipPolicy::Warn);
+        backlog.move_item(&story, "Doing").unwrap();
+        backlog.move_item(&task, "Doing").unwrap();
+
+        let board = backlog.board(None);
+        assert_eq!(board.columns()[1].count(), 2);
+        assert_eq!(board.warnings().len(), 1);
+    }
+
+    #[test]
+    fn test_board_swimlanes() {
+        let (mut backlog, _, task) = backlog(WipPolicy::Fail);
+        backlog.change_item_status(&task, Status::Done).unwrap();
+
+        let board = backlog.board(Some(Swimlane::Assignee));
+        let names: Vec<_> = board.swimlanes().iter().map(|lane| lane.name()).collect();
+        assert_eq!(names, vec![Some("alice"), None]);
+        assert_eq!(board.swimlanes()[1].cells()[2][0].title(), "task");
+
+        let board = backlog.board(Some(Swimlane::Type));
+        assert_eq!(board.swimlanes().len(), 2);
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    AssignableFromCollection, Assignee, Backlog, BacklogError, BacklogResult,
+    EstimatableFromCollection, PlannableFromCollection, PrioritizableFromCollection, Priority,
+    ProgressableFromCollection, Status, StoryPoint,
+};
+
+/// The change applied to many items at once.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BulkChange {
+    Assign(Assignee),
+    Estimate(StoryPoint),
+    /// move into the sprint, or out of any sprint with `None`.
+    Plan(Option<Uuid>),
+    ChangeStatus(Status),
+    Prioritize(Priority),
+}
+
+/// The result of each item.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct BulkReport {
+    results: Vec<BulkResult>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct BulkResult {
+    id: Uuid,
+    outcome: BulkOutcome,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "type")]
+pub enum BulkOutcome {
+    Changed,
+    /// the item already had the value.
+    Unchanged,
+    Failed {
+        reason: String,
+    },
+}
+
+impl BulkReport {
+    /// apply the change to every specific item of the backlog.
+    pub fn apply(backlog: &mut Backlog, ids: &[Uuid], change: &BulkChange) -> BacklogResult<Self> {
+        if ids.is_empty() {
+            return Err(BacklogError::type_error("no item is specified"));
+        }
+        let unknown: Vec<String> = ids
+            .iter()
+            .filter(|id| !backlog.items().contains_key(*id))
+            .map(|id| id.to_string())
+            .collect();
+        if !unknown.is_empty() {
+            return Err(BacklogError::not_found(format!(
+                "BacklogItem, id: {} does not found",
+                unknown.join(", ")
+            )));
+        }
+        match change {
+            BulkChange::Assign(assignee) => backlog.validate_assignee(assignee)?,
+            BulkChange::Plan(Some(sprint)) if !backlog.sprints().contains_key(sprint) => {
+                return Err(BacklogError::not_found(format!(
+                    "Sprint, id: {} does not found",
+                    sprint
+                )))
+            }
+            _ => {}
+        }
+
+        let mut seen = HashSet::new();
+        let results = ids
+            .iter()
+            .filter(|id| seen.insert(**id))
+            .map(|&id| {
+                let before = backlog.items()[&id].history().len();
+                let outcome = match apply(backlog, &id, change) {
+                    Err(err) => BulkOutcome::Failed {
+                        reason: err.to_string(),
+                    },
+                    Ok(()) if backlog.items()[&id].history().len() > before => BulkOutcome::Changed,
+                    Ok(()) => BulkOutcome::Unchanged,
+                };
+                BulkResult { id, outcome }
+            })
+            .collect();
+        Ok(BulkReport { results })
+    }
+
+    pub fn results(&self) -> &[BulkResult] {
+        &self.results
+    }
+
+    /// whether any item has been changed.
+    pub fn has_changes(&self) -> bool {
+        self.results
+            .iter()
+            .any(|result| result.outcome == BulkOutcome::Changed)
+    }
+}
+
+impl BulkResult {
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn outcome(&self) -> &BulkOutcome {
+        &self.outcome
+    }
+}
+
+fn apply(backlog: &mut Backlog, id: &Uuid, change: &BulkChange) -> BacklogResult<()> {
+    match change {
+        BulkChange::Assign(assignee) => {
+            if backlog.items()[id].assignee() == Some(assignee) {
+                return Ok(());
+            }
+            backlog.assign_item(id, assignee.clone())
+        }
+        BulkChange::Estimate(point) => {
+            if backlog.items()[id].point() == Some(point) {
+                return Ok(());
+            }
+            backlog.estimate_item(id, point.clone())
+        }
+        BulkChange::Plan(sprint) => backlog.plan_item(id, *sprint),
+        BulkChange::ChangeStatus(status) => backlog.change_item_status(id, *status),
+        BulkChange::Prioritize(priority) => backlog.prioritize_item(id, *priority),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AddItem, BacklogFixture, Entity, Story};
+
+    #[test]
+    fn test_apply_bulk() {
+        let (id, mut backlog) = Backlog::specific_id();
+        let story = Story::new("", None, Some(Assignee::new("dummy")));
+        let assigned = story.id();
+        backlog.add_item(Box::new(story));
+
+        let change = BulkChange::Assign(Assignee::new("dummy"));
+        let report = backlog.apply_bulk(&[id, assigned], &change).unwrap();
+
+        assert_eq!(report.results()[0].outcome(), &BulkOutcome::Changed);
+        assert_eq!(report.results()[1].outcome(), &BulkOutcome::Unchanged);
+        assert!(report.has_changes());
+    }
+
+    #[test]
+    fn test_apply_bulk_expect_fail() {
+        let (id, mut backlog) = Backlog::specific_id();
+        let change = BulkChange::ChangeStatus(Status::Done);
+        assert!(backlog.apply_bulk(&[id, Uuid::new_v4()], &change).is_err());
+        // nothing is changed when any id is unknown.
+        assert_eq!(backlog.items()[&id].status(), Status::Todo);
+
+        let change = BulkChange::Assign(Assignee::new("unknown"));
+        assert!(backlog.apply_bulk(&[id], &change).is_err());
+    }
+}
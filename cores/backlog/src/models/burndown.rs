@@ -0,0 +1,338 @@
+use chrono::{Duration, NaiveDate};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{Backlog, BacklogError, BacklogItem, BacklogResult, Change, Entity, Sprint, Status};
+
+/// The daily series of story points in the sprint.
+///
+/// It can draw both the burndown chart (`remaining`, `ideal`)
+/// and the burnup chart (`scope`, `completed`).
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Burndown {
+    sprint: Uuid,
+    name: String,
+    start: NaiveDate,
+    end: NaiveDate,
+    days: Vec<BurndownDay>,
+}
+
+/// The story points at the end of the day.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct BurndownDay {
+    date: NaiveDate,
+    scope: u32,
+    completed: u32,
+    remaining: u32,
+    ideal: f64,
+    /// points added to (or removed from, if negative) the sprint on the day.
+    scope_change: i64,
+}
+
+impl Burndown {
+    /// rebuild the series from the history of items until `today`.
+    pub fn new(backlog: &Backlog, sprint: &Uuid, today: NaiveDate) -> BacklogResult<Self> {
+        let sprint = backlog.sprints().get(sprint).ok_or_else(|| {
+            BacklogError::not_found(format!("Sprint, id: {} does not found", sprint))
+        })?;
+
+        let last = std::cmp::min(sprint.end(), today);
+        let mut days: Vec<BurndownDay> = Vec::new();
+        let mut date = sprint.start();
+        while date <= last {
+            let (scope, completed) = points_at(backlog, sprint, date);
+            let scope_change = match days.last() {
+                Some(prev) => scope as i64 - prev.scope as i64,
+                None => 0,
+            };
+            let initial = days.first().map(|day| day.scope).unwrap_or(scope);
+            days.push(BurndownDay {
+                date,
+                scope,
+                completed,
+                remaining: scope - completed,
+                ideal: ideal(sprint, initial, date),
+                scope_change,
+            });
+            date += Duration::days(1);
+        }
+
+        Ok(Self {
+            sprint: sprint.id(),
+            name: sprint.name().to_string(),
+            start: sprint.start(),
+            end: sprint.end(),
+            days,
+        })
+    }
+
+    pub fn sprint(&self) -> Uuid {
+        self.sprint
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    pub fn days(&self) -> &[BurndownDay] {
+        &self.days
+    }
+}
+
+impl BurndownDay {
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    pub fn scope(&self) -> u32 {
+        self.scope
+    }
+
+    pub fn completed(&self) -> u32 {
+        self.completed
+    }
+
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    pub fn ideal(&self) -> f64 {
+        self.ideal
+    }
+
+    pub fn scope_change(&self) -> i64 {
+        self.scope_change
+    }
+}
+
+/// The ideal remaining points that burn linearly from the start to the end of the sprint.
+fn ideal(sprint: &Sprint, initial: u32, date: NaiveDate) -> f64 {
+    let length = (sprint.end() - sprint.start()).num_days();
+    if length == 0 {
+        return 0.0;
+    }
+    let left = (sprint.end() - date).num_days();
+    initial as f64 * left as f64 / length as f64
+}
+
+/// return the scope and the completed points of the sprint at the end of the day.
+fn points_at(backlog: &Backlog, sprint: &Sprint, date: NaiveDate) -> (u32, u32) {
+    backlog
+        .items()
+        .values()
+        .map(|item| Snapshot::at(item.as_ref(), date))
+        .filter(|snapshot| snapshot.exists && snapshot.sprint == Some(sprint.id()))
+        .fold((0, 0), |(scope, completed), snapshot| {
+            let done = if snapshot.status == Status::Done {
+                snapshot.point
+            } else {
+                0
+            };
+            (scope + snapshot.point, completed + done)
+        })
+}
+
+/// return the points and the number of the items completed within the sprint.
+///
+/// The items are rewound to the end of the sprint, so that the items done or re-planned
+/// after it do not count, nor do the items already done before it starts.
+pub(crate) fn completed_within(backlog: &Backlog, sprint: &Sprint) -> (u32, u32) {
+    let before = sprint.start() - Duration::days(1);
+    backlog
+        .items()
+        .values()
+        .filter(|item| {
+            let snapshot = Snapshot::at(item.as_ref(), before);
+            !(snapshot.exists && snapshot.status == Status::Done)
+        })
+        .map(|item| Snapshot::at(item.as_ref(), sprint.end()))
+        .filter(|snapshot| {
+            snapshot.exists
+                && snapshot.sprint == Some(sprint.id())
+                && snapshot.status == Status::Done
+        })
+        .fold((0, 0), |(points, items), snapshot| {
+            (points + snapshot.point, items + 1)
+        })
+}
+
+/// The state of the item at the specific day.
+struct Snapshot {
+    exists: bool,
+    point: u32,
+    sprint: Option<Uuid>,
+    status: Status,
+}
+
+impl Snapshot {
+    /// undo the changes after the day from the current state.
+    fn at(item: &dyn BacklogItem, date: NaiveDate) -> Self {
+        let mut snapshot = Self {
+            exists: true,
+            point: item.point().map(|p| p.value() as u32).unwrap_or(0),
+            sprint: item.sprint(),
+            status: item.status(),
+        };
+        for event in item.history().iter().rev() {
+            if event.at().naive_utc().date() <= date {
+                break;
+            }
+            match event.change() {
+                Change::Added => snapshot.exists = false,
+                Change::Estimated { from, .. } => {
+                    snapshot.point = from.as_ref().map(|p| p.value() as u32).unwrap_or(0)
+                }
+                Change::SprintChanged { from, .. } => snapshot.sprint = *from,
+                Change::StatusChanged { from, .. } => snapshot.status = *from,
+                Change::Assigned { .. }
+                | Change::Prioritized { .. }
+                | Change::Split { .. }
+                | Change::FixVersionChanged { .. }
+                | Change::Attached { .. }
+                | Change::Detached { .. }
+                | Change::Labelled { .. }
+                | Change::EpicChanged { .. }
+                | Change::Linked { .. } => {}
+            }
+        }
+        snapshot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone, Utc};
+
+    use super::*;
+    use crate::{AddItem, Historical, Plannable, Progressable, Story, StoryPoint, Team};
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2022, 1, day).unwrap()
+    }
+
+    fn at(day: u32) -> DateTime<Utc> {
+        Utc.from_utc_datetime(&date(day).and_hms_opt(12, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn test_burndown() {
+        let sprint = Sprint::new("sprint 1", Team::new("team"), date(3), date(5)).unwrap();
+        let mut backlog = Backlog::new();
+
+        // planned before the sprint, done on the 4th.
+        let mut story = Story::new("", Some(StoryPoint::new(5).unwrap()), None);
+        story.record_at(at(1), Change::Added);
+        story.plan(sprint.id());
+        story.record_at(
+            at(1),
+            Change::SprintChanged {
+                from: None,
+                to: Some(sprint.id()),
+            },
+        );
+        story.change_status(Status::Done);
+        story.record_at(
+            at(4),
+            Change::StatusChanged {
+                from: Status::Todo,
+                to: Status::Done,
+            },
+        );
+        backlog.add_item(Box::new(story));
+
+        // added to the sprint on the 5th.
+        let mut story = Story::new("", Some(StoryPoint::new(3).unwrap()), None);
+        story.record_at(at(5), Change::Added);
+        story.plan(sprint.id());
+        backlog.add_item(Box::new(story));
+
+        let id = sprint.id();
+        backlog.add_sprint(sprint).unwrap();
+
+        let burndown = Burndown::new(&backlog, &id, date(10)).unwrap();
+        let remaining: Vec<u32> = burndown.days().iter().map(|d| d.remaining()).collect();
+        let completed: Vec<u32> = burndown.days().iter().map(|d| d.completed()).collect();
+        let changes: Vec<i64> = burndown.days().iter().map(|d| d.scope_change()).collect();
+        let ideal: Vec<f64> = burndown.days().iter().map(|d| d.ideal()).collect();
+        assert_eq!(remaining, vec![5, 0, 3]);
+        assert_eq!(completed, vec![0, 5, 5]);
+        assert_eq!(changes, vec![0, 0, 3]);
+        assert_eq!(ideal, vec![5.0, 2.5, 0.0]);
+
+        // the series stops at today.
+        let burndown = Burndown::new(&backlog, &id, date(4)).unwrap();
+        assert_eq!(burndown.days().len(), 2);
+    }
+
+    #[test]
+    fn test_completed_within() {
+        let sprint = Sprint::new("sprint 1", Team::new("team"), date(3), date(5)).unwrap();
+        let mut backlog = Backlog::new();
+
+        // done on the 4th, and re-planned into the next sprint on the 6th.
+        let mut story = Story::new("", Some(StoryPoint::new(5).unwrap()), None);
+        story.record_at(at(1), Change::Added);
+        story.plan(sprint.id());
+        story.record_at(
+            at(1),
+            Change::SprintChanged {
+                from: None,
+                to: Some(sprint.id()),
+            },
+        );
+        story.change_status(Status::Done);
+        story.record_at(
+            at(4),
+            Change::StatusChanged {
+                from: Status::Todo,
+                to: Status::Done,
+            },
+        );
+        let next = Uuid::new_v4();
+        story.plan(next);
+        story.record_at(
+            at(6),
+            Change::SprintChanged {
+                from: Some(sprint.id()),
+                to: Some(next),
+            },
+        );
+        backlog.add_item(Box::new(story));
+
+        // done on the 6th, after the sprint ended.
+        let mut story = Story::new("", Some(StoryPoint::new(3).unwrap()), None);
+        story.record_at(at(1), Change::Added);
+        story.plan(sprint.id());
+        story.record_at(
+            at(1),
+            Change::SprintChanged {
+                from: None,
+                to: Some(sprint.id()),
+            },
+        );
+        story.change_status(Status::Done);
+        story.record_at(
+            at(6),
+            Change::StatusChanged {
+                from: Status::Todo,
+                to: Status::Done,
+            },
+        );
+        backlog.add_item(Box::new(story));
+
+        assert_eq!(completed_within(&backlog, &sprint), (5, 1));
+    }
+
+    #[test]
+    fn test_burndown_expect_fail() {
+        assert!(Burndown::new(&Backlog::new(), &Uuid::new_v4(), date(1)).is_err());
+    }
+}
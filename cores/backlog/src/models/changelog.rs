@@ -0,0 +1,199 @@
+use std::fmt::Display;
+
+use uuid::Uuid;
+
+use crate::{Backlog, BacklogItem, Change, Entity};
+
+/// describe each change since `before` in a line, e.g. `Estimate "Login page": 3 → 5`.
+pub(crate) fn changelog(backlog: &Backlog, before: &Backlog) -> Vec<String> {
+    let mut changes = Vec::new();
+    for item in backlog.items().values() {
+        let prev = before.items().get(&item.id());
+        let seen = prev.map(|prev| prev.history().len()).unwrap_or(0);
+        for event in item.history().iter().skip(seen) {
+            changes.push(describe(backlog, item.as_ref(), event.change()));
+        }
+        if matches!(prev, Some(prev) if prev.rank() != item.rank()) {
+            changes.push(format!("Rank {}", quoted(item.as_ref())));
+        }
+    }
+    for item in before.items().values() {
+        if !backlog.items().contains_key(&item.id()) {
+            changes.push(format!("Remove {}", quoted(item.as_ref())));
+        }
+    }
+
+    for sprint in backlog.sprints().values() {
+        if !before.sprints().contains_key(&sprint.id()) {
+            changes.push(format!("Add sprint {}", sprint.name()));
+        }
+    }
+    for release in backlog.releases().values() {
+        match before.releases().get(&release.id()) {
+            None => changes.push(format!("Add release {}", release.name())),
+            Some(prev) if !prev.is_released() && release.is_released() => {
+                changes.push(format!("Ship release {}", release.name()))
+            }
+            Some(_) => {}
+        }
+    }
+
+    let (directory, prev) = (backlog.directory(), before.directory());
+    for user in directory.users() {
+        let handle = user.handle().name();
+        match prev.user(handle) {
+            None => changes.push(format!("Add user {}", handle)),
+            Some(prev) if prev != user => changes.push(format!("Update user {}", handle)),
+            Some(_) => {}
+        }
+    }
+    for user in prev.users() {
+        if directory.user(user.handle().name()).is_none() {
+            changes.push(format!("Remove user {}", user.handle().name()));
+        }
+    }
+    for team in directory.teams() {
+        let handle = team.handle().name();
+        match prev.team(handle) {
+            None => changes.push(format!("Add team {}", handle)),
+            Some(prev) if prev != team => changes.push(format!("Update team {}", handle)),
+            Some(_) => {}
+        }
+    }
+
+    if backlog.board_config() != before.board_config() {
+        changes.push("Configure the board".to_string());
+    }
+    changes
+}
+
+fn describe(backlog: &Backlog, item: &dyn BacklogItem, change: &Change) -> String {
+    let title = quoted(item);
+    match change {
+        Change::Added => format!("Add {} {}", item.kind(), title),
+        Change::Estimated { from, to } => format!(
+            "Estimate {}: {} → {}",
+            title,
+            or_none(from.as_ref().map(|point| point.value())),
+            to.value()
+        ),
+        Change::Assigned { from, to } => format!(
+            "Assign {}: {} → {}",
+            title,
+            or_none(from.as_ref().map(|assignee| assignee.name())),
+            to.name()
+        ),
+        Change::SprintChanged { from, to } => format!(
+            "Plan {}: {} → {}",
+            title,
+            sprint_name(backlog, from),
+            sprint_name(backlog, to)
+        ),
+        Change::StatusChanged { from, to } => format!("Move {}: {:?} → {:?}", title, from, to),
+        Change::Prioritized { from, to } => {
+            format!("Prioritize {}: {:?} → {:?}", title, from, to)
+        }
+        Change::Split { into } => format!("Split {} into {} items", title, into.len()),
+        Change::FixVersionChanged { from, to } => format!(
+            "Target {}: {} → {}",
+            title,
+            release_name(backlog, from),
+            release_name(backlog, to)
+        ),
+        Change::Attached { filename, .. } => format!("Attach {} to {}", filename, title),
+        Change::Detached { filename, .. } => format!("Detach {} from {}", filename, title),
+        Change::Labelled { from, to } => format!(
+            "Label {}: {} → {}",
+            title,
+            or_none(Some(from.join(", ")).filter(|labels| !labels.is_empty())),
+            or_none(Some(to.join(", ")).filter(|labels| !labels.is_empty()))
+        ),
+        Change::EpicChanged { from, to } => format!(
+            "Group {}: {} → {}",
+            title,
+            item_title(backlog, from),
+            item_title(backlog, to)
+        ),
+        Change::Linked { from, to } => {
+            format!("Link {}: {} → {} items", title, from.len(), to.len())
+        }
+    }
+}
+
+fn quoted(item: &dyn BacklogItem) -> String {
+    format!("\"{}\"", item.title().value())
+}
+
+fn or_none(value: Option<impl Display>) -> String {
+    value
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| "none".to_string())
+}
+
+fn sprint_name(backlog: &Backlog, id: &Option<Uuid>) -> String {
+    or_none(id.map(|id| {
+        backlog
+            .sprints()
+            .get(&id)
+            .map(|sprint| sprint.name().to_string())
+            .unwrap_or_else(|| id.to_string())
+    }))
+}
+
+fn item_title(backlog: &Backlog, id: &Option<Uuid>) -> String {
+    or_none(id.map(|id| {
+        backlog
+            .items()
+            .get(&id)
+            .map(|item| quoted(item.as_ref()))
+            .unwrap_or_else(|| id.to_string())
+    }))
+}
+
+fn release_name(backlog: &Backlog, id: &Option<Uuid>) -> String {
+    or_none(id.map(|id| {
+        backlog
+            .releases()
+            .get(&id)
+            .map(|release| release.name().to_string())
+            .unwrap_or_else(|| id.to_string())
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AssignableFromCollection, Assignee, BacklogFixture, EstimatableFromCollection,
+        ProgressableFromCollection, Status, StoryPoint, User,
+    };
+
+    #[test]
+    fn test_changelog() {
+        let (id, mut backlog) = Backlog::specific_id();
+        backlog
+            .estimate_item(&id, StoryPoint::new(3).unwrap())
+            .unwrap();
+        let before = backlog.clone();
+        assert!(backlog.changelog(&before).is_empty());
+
+        backlog
+            .estimate_item(&id, StoryPoint::new(5).unwrap())
+            .unwrap();
+        backlog
+            .add_user(User::new("alice", "Alice Liddell").unwrap())
+            .unwrap();
+        backlog.assign_item(&id, Assignee::new("alice")).unwrap();
+        backlog.change_item_status(&id, Status::Done).unwrap();
+        let title = backlog.items()[&id].title().value().to_string();
+        assert_eq!(
+            backlog.changelog(&before),
+            [
+                format!("Estimate \"{}\": 3 → 5", title),
+                format!("Assign \"{}\": none → alice", title),
+                format!("Move \"{}\": Todo → Done", title),
+                "Add user alice".to_string(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,10 @@
+use crate::Title;
+
+/// Represents that the item can be described to users.
+pub trait Describable {
+    /// the name of the item type.
+    fn kind(&self) -> &'static str;
+
+    /// accessor
+    fn title(&self) -> &Title;
+}
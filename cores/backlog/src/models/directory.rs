@@ -0,0 +1,293 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::text::suggest;
+use crate::{Assignee, BacklogError, BacklogResult, Team};
+
+/// The users and teams who work on the backlog.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Directory {
+    #[serde(default)]
+    users: IndexMap<String, User>,
+    #[serde(default)]
+    teams: IndexMap<String, TeamProfile>,
+}
+
+/// It means the user who can be assigned to items.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct User {
+    handle: Assignee,
+    display_name: String,
+    active: bool,
+}
+
+/// It means the team who works on sprints.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamProfile {
+    handle: Team,
+    display_name: String,
+    active: bool,
+}
+
+impl User {
+    pub fn new(handle: &str, display_name: &str) -> BacklogResult<Self> {
+        validate_handle(handle)?;
+        Ok(Self {
+            handle: Assignee::new(handle),
+            display_name: display_name.to_string(),
+            active: true,
+        })
+    }
+
+    pub fn handle(&self) -> &Assignee {
+        &self.handle
+    }
+
+    pub fn display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+impl TeamProfile {
+    pub fn new(handle: &str, display_name: &str) -> BacklogResult<Self> {
+        validate_handle(handle)?;
+        Ok(Self {
+            handle: Team::new(handle),
+            display_name: display_name.to_string(),
+            active: true,
+        })
+    }
+
+    pub fn handle(&self) -> &Team {
+        &self.handle
+    }
+
+    pub fn display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+impl Directory {
+    pub fn users(&self) -> impl Iterator<Item = &User> {
+        self.users.values()
+    }
+
+    pub fn teams(&self) -> impl Iterator<Item = &TeamProfile> {
+        self.teams.values()
+    }
+
+    pub fn user(&self, handle: &str) -> Option<&User> {
+        self.users.get(handle)
+    }
+
+    pub fn team(&self, handle: &str) -> Option<&TeamProfile> {
+        self.teams.get(handle)
+    }
+
+    /// Add the user. The handle must be unique.
+    pub fn add_user(&mut self, user: User) -> BacklogResult<()> {
+        let handle = user.handle.name().to_string();
+        if self.users.contains_key(&handle) {
+            return Err(BacklogError::type_error(format!(
+                "User, {} already exists",
+                handle
+            )));
+        }
+        self.users.insert(handle, user);
+        Ok(())
+    }
+
+    /// Add the team. The handle must be unique.
+    pub fn add_team(&mut self, team: TeamProfile) -> BacklogResult<()> {
+        let handle = team.handle.name().to_string();
+        if self.teams.contains_key(&handle) {
+            return Err(BacklogError::type_error(format!(
+                "Team, {} already exists",
+                handle
+            )));
+        }
+        self.teams.insert(handle, team);
+        Ok(())
+    }
+
+    /// Update the display name and the active flag of the user.
+    pub fn update_user(
+        &mut self,
+        handle: &str,
+        display_name: Option<&str>,
+        active: Option<bool>,
+    ) -> BacklogResult<()> {
+        let user = self.find_user_mut(handle)?;
+        if let Some(display_name) = display_name {
+            user.display_name = display_name.to_string();
+        }
+        if let Some(active) = active {
+            user.active = active;
+        }
+        Ok(())
+    }
+
+    /// Update the display name and the active flag of the team.
+    pub fn update_team(
+        &mut self,
+        handle: &str,
+        display_name: Option<&str>,
+        active: Option<bool>,
+    ) -> BacklogResult<()> {
+        let team = self
+            .teams
+            .get_mut(handle)
+            .ok_or_else(|| BacklogError::not_found(format!("Team, {} does not found", handle)))?;
+        if let Some(display_name) = display_name {
+            team.display_name = display_name.to_string();
+        }
+        if let Some(active) = active {
+            team.active = active;
+        }
+        Ok(())
+    }
+
+    /// Change the handle of the user, keeping its position in the directory.
+    pub fn rename_user(&mut self, handle: &str, new_handle: &str) -> BacklogResult<()> {
+        validate_handle(new_handle)?;
+        if self.users.contains_key(new_handle) {
+            return Err(BacklogError::type_error(format!(
+                "User, {} already exists",
+                new_handle
+            )));
+        }
+        if !self.users.contains_key(handle) {
+            return Err(self.user_not_found(handle));
+        }
+        self.users = std::mem::take(&mut self.users)
+            .into_iter()
+            .map(|(key, mut user)| {
+                if key != handle {
+                    return (key, user);
+                }
+                user.handle = Assignee::new(new_handle);
+                (new_handle.to_string(), user)
+            })
+            .collect();
+        Ok(())
+    }
+
+    /// Check that the assignee is an active user of the directory.
+    pub fn validate_assignee(&self, assignee: &Assignee) -> BacklogResult<()> {
+        match self.users.get(assignee.name()) {
+            None => Err(self.user_not_found(assignee.name())),
+            Some(user) if !user.active => Err(BacklogError::type_error(format!(
+                "User, {} is inactive",
+                assignee.name()
+            ))),
+            Some(_) => Ok(()),
+        }
+    }
+
+    fn find_user_mut(&mut self, handle: &str) -> BacklogResult<&mut User> {
+        if !self.users.contains_key(handle) {
+            return Err(self.user_not_found(handle));
+        }
+        Ok(self.users.get_mut(handle).unwrap())
+    }
+
+    fn user_not_found(&self, handle: &str) -> BacklogError {
+        let suggestions = suggest(handle, self.users.keys().map(|key| key.as_str()));
+        if suggestions.is_empty() {
+            BacklogError::not_found(format!("User, {} does not found", handle))
+        } else {
+            BacklogError::not_found(format!(
+                "User, {} does not found. did you mean {}?",
+                handle,
+                suggestions.join(" or ")
+            ))
+        }
+    }
+}
+
+/// A handle must be a non-empty word without whitespaces.
+fn validate_handle(handle: &str) -> BacklogResult<()> {
+    if handle.is_empty() || handle.chars().any(char::is_whitespace) {
+        return Err(BacklogError::type_error(format!(
+            "the handle must be a non-empty word without whitespaces, {:?}",
+            handle
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directory() -> Directory {
+        let mut directory = Directory::default();
+        directory
+            .add_user(User::new("alice", "Alice").unwrap())
+            .unwrap();
+        directory
+            .add_user(User::new("bob", "Bob").unwrap())
+            .unwrap();
+        directory
+    }
+
+    #[test]
+    fn test_add_user() {
+        let mut directory = directory();
+        assert!(directory
+            .add_user(User::new("alice", "Alice").unwrap())
+            .is_err());
+        assert!(User::new("a lice", "Alice").is_err());
+        assert!(User::new("", "Alice").is_err());
+    }
+
+    #[test]
+    fn test_validate_assignee() {
+        let mut directory = directory();
+        assert!(directory.validate_assignee(&Assignee::new("alice")).is_ok());
+
+        let err = directory
+            .validate_assignee(&Assignee::new("alcie"))
+            .unwrap_err();
+        assert!(err.to_string().contains("did you mean alice?"));
+
+        directory.update_user("alice", None, Some(false)).unwrap();
+        assert!(directory
+            .validate_assignee(&Assignee::new("alice"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_rename_user() {
+        let mut directory = directory();
+        directory.rename_user("alice", "alicia").unwrap();
+
+        let handles: Vec<&str> = directory.users().map(|u| u.handle().name()).collect();
+        assert_eq!(handles, vec!["alicia", "bob"]);
+        assert!(directory.rename_user("alicia", "bob").is_err());
+        assert!(directory.rename_user("carol", "dave").is_err());
+    }
+
+    #[test]
+    fn test_add_team() {
+        let mut directory = directory();
+        directory
+            .add_team(TeamProfile::new("core", "Core").unwrap())
+            .unwrap();
+        directory
+            .update_team("core", Some("Core team"), Some(false))
+            .unwrap();
+
+        let team = directory.team("core").unwrap();
+        assert_eq!(team.display_name(), "Core team");
+        assert!(!team.is_active());
+    }
+}
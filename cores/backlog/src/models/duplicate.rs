@@ -0,0 +1,249 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{text::token_similarity, AddItem, Backlog, BacklogError, BacklogItem, BacklogResult};
+
+/// The item which looks like another.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Duplicate {
+    id: Uuid,
+    title: String,
+    /// from 0.0 to 1.0.
+    similarity: f64,
+}
+
+/// The items which look like each other.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DuplicateCluster {
+    /// the similarity of each item is the highest one to the other items.
+    items: Vec<Duplicate>,
+}
+
+/// The item added to the backlog, with the existing items which look like it.
+#[derive(Clone, Debug, Serialize)]
+pub struct AddedItem {
+    id: Uuid,
+    duplicates: Vec<Duplicate>,
+    backlog: Backlog,
+}
+
+impl Duplicate {
+    /// Titles at least this similar are reported as duplicates.
+    pub const THRESHOLD: f64 = 0.7;
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn similarity(&self) -> f64 {
+        self.similarity
+    }
+}
+
+impl std::fmt::Display for Duplicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {:?} ({:.0}% similar)",
+            self.id,
+            self.title,
+            self.similarity * 100.0
+        )
+    }
+}
+
+impl DuplicateCluster {
+    pub fn items(&self) -> &[Duplicate] {
+        &self.items
+    }
+}
+
+impl AddedItem {
+    pub fn new(id: Uuid, duplicates: Vec<Duplicate>, backlog: Backlog) -> Self {
+        Self {
+            id,
+            duplicates,
+            backlog,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn duplicates(&self) -> &[Duplicate] {
+        &self.duplicates
+    }
+
+    pub fn backlog(&self) -> &Backlog {
+        &self.backlog
+    }
+}
+
+fn validate_threshold(threshold: f64) -> BacklogResult<()> {
+    if threshold > 0.0 && threshold <= 1.0 {
+        return Ok(());
+    }
+    Err(BacklogError::type_error(format!(
+        "the threshold must be greater than 0 and at most 1, {}",
+        threshold
+    )))
+}
+
+/// return the items whose titles look like the title, the most similar first.
+pub(crate) fn similar_items(
+    backlog: &Backlog,
+    title: &str,
+    threshold: f64,
+) -> BacklogResult<Vec<Duplicate>> {
+    validate_threshold(threshold)?;
+    let mut duplicates: Vec<Duplicate> = backlog
+        .items()
+        .values()
+        .map(|item| Duplicate {
+            id: item.id(),
+            title: item.title().value().to_string(),
+            similarity: token_similarity(title, item.title().value()),
+        })
+        .filter(|duplicate| duplicate.similarity >= threshold)
+        .collect();
+    duplicates.sort_by(|a, b| {
+        b.similarity
+            .partial_cmp(&a.similarity)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    Ok(duplicates)
+}
+
+/// add the item, unless it looks like any existing item and it is not forced.
+pub(crate) fn add_item_checked(
+    backlog: &mut Backlog,
+    item: Box<dyn BacklogItem>,
+    force: bool,
+) -> BacklogResult<Vec<Duplicate>> {
+    let duplicates = similar_items(backlog, item.title().value(), Duplicate::THRESHOLD)?;
+    if !duplicates.is_empty() && !force {
+        let found: Vec<String> = duplicates.iter().map(|d| d.to_string()).collect();
+        return Err(BacklogError::duplicated(format!(
+            "the item looks like existing items, {}",
+            found.join(", ")
+        )));
+    }
+    backlog.add_item(item);
+    Ok(duplicates)
+}
+
+/// group the items whose titles look like each other, directly or through another item.
+pub(crate) fn duplicate_clusters(
+    backlog: &Backlog,
+    threshold: f64,
+) -> BacklogResult<Vec<DuplicateCluster>> {
+    validate_threshold(threshold)?;
+    let items: Vec<&dyn BacklogItem> = backlog.items().values().map(|item| item.as_ref()).collect();
+    let mut parents: Vec<usize> = (0..items.len()).collect();
+    let mut best = vec![0.0_f64; items.len()];
+
+    for i in 0..items.len() {
+        for j in (i + 1)..items.len() {
+            let similarity = token_similarity(items[i].title().value(), items[j].title().value());
+            if similarity < threshold {
+                continue;
+            }
+            best[i] = best[i].max(similarity);
+            best[j] = best[j].max(similarity);
+            let (a, b) = (root(&mut parents, i), root(&mut parents, j));
+            parents[b.max(a)] = b.min(a);
+        }
+    }
+
+    let mut clusters: Vec<(usize, Vec<Duplicate>)> = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        if best[i] == 0.0 {
+            continue;
+        }
+        let duplicate = Duplicate {
+            id: item.id(),
+            title: item.title().value().to_string(),
+            similarity: best[i],
+        };
+        let root = root(&mut parents, i);
+        match clusters.iter_mut().find(|(r, _)| *r == root) {
+            Some((_, cluster)) => cluster.push(duplicate),
+            None => clusters.push((root, vec![duplicate])),
+        }
+    }
+    Ok(clusters
+        .into_iter()
+        .map(|(_, items)| DuplicateCluster { items })
+        .collect())
+}
+
+fn root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BacklogFixture, Story};
+
+    fn backlog(titles: &[&str]) -> Backlog {
+        let mut backlog = Backlog::empty_items();
+        for title in titles {
+            backlog.add_item(Box::new(Story::new(title, None, None)));
+        }
+        backlog
+    }
+
+    #[test]
+    fn test_add_item_checked() {
+        let mut backlog = backlog(&["Fix the login bug", "Add payment page"]);
+
+        let item = Box::new(Story::new("fix login bug", None, None));
+        assert!(add_item_checked(&mut backlog, item.clone(), false).is_err());
+        assert_eq!(backlog.items().len(), 2);
+
+        let duplicates = add_item_checked(&mut backlog, item, true).unwrap();
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].title(), "Fix the login bug");
+        assert_eq!(backlog.items().len(), 3);
+
+        let item = Box::new(Story::new("Export the report", None, None));
+        assert!(add_item_checked(&mut backlog, item, false)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_clusters() {
+        let backlog = backlog(&[
+            "Fix the login bug",
+            "Add payment page",
+            "fix login bug",
+            "Add the payment page",
+            "Export the report",
+        ]);
+        let clusters = duplicate_clusters(&backlog, Duplicate::THRESHOLD).unwrap();
+
+        let titles: Vec<Vec<&str>> = clusters
+            .iter()
+            .map(|cluster| cluster.items().iter().map(|item| item.title()).collect())
+            .collect();
+        assert_eq!(
+            titles,
+            vec![
+                vec!["Fix the login bug", "fix login bug"],
+                vec!["Add payment page", "Add the payment page"],
+            ]
+        );
+        assert!(duplicate_clusters(&backlog, 0.0).is_err());
+    }
+}
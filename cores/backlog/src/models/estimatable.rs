@@ -1,9 +1,11 @@
 use uuid::Uuid;
 
-use crate::{BacklogError, BacklogItem, BacklogResult, FindFromCollection, StoryPoint};
+use crate::{BacklogError, BacklogItem, BacklogResult, Change, FindFromCollection, StoryPoint};
 
 /// It can be estimated by story point.
 pub trait Estimatable {
+    fn point(&self) -> Option<&StoryPoint>;
+
     fn mut_point(&mut self) -> &mut Option<StoryPoint>;
 
     /// estimate it.
@@ -24,7 +26,9 @@ pub trait EstimatableFromCollection:
                 id
             ))),
             Some(item) => {
-                item.estimate(point);
+                let from = item.point().cloned();
+                item.estimate(point.clone());
+                item.record(Change::Estimated { from, to: point });
                 Ok(())
             }
         }
@@ -41,6 +45,10 @@ mod test_estimatable {
     }
 
     impl Estimatable for TestEstimateable {
+        fn point(&self) -> Option<&StoryPoint> {
+            self.point.as_ref()
+        }
+
         fn mut_point(&mut self) -> &mut Option<StoryPoint> {
             &mut self.point
         }
@@ -0,0 +1,484 @@
+use chrono::{Duration, NaiveDate};
+use rand::{seq::SliceRandom, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{Backlog, BacklogError, BacklogResult, Entity, Sprint, Team};
+
+/// The confidence levels reported by forecasts.
+pub const CONFIDENCES: [u8; 3] = [50, 85, 95];
+
+/// What the forecast samples from completed sprints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ForecastBasis {
+    /// completed story points per sprint.
+    Velocity,
+    /// the number of completed items per sprint.
+    Throughput,
+}
+
+impl std::str::FromStr for ForecastBasis {
+    type Err = BacklogError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "velocity" => Ok(Self::Velocity),
+            "throughput" => Ok(Self::Throughput),
+            _ => Err(BacklogError::type_error(format!(
+                "the basis of forecast must be velocity or throughput, {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// The parameters of the simulation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Simulation {
+    seed: u64,
+    trials: usize,
+    team: Option<Team>,
+}
+
+/// When the remaining work will be completed.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Forecast {
+    basis: ForecastBasis,
+    simulation: Simulation,
+    start: NaiveDate,
+    sprint_length: i64,
+    samples: Vec<u32>,
+    remaining: u32,
+    unestimated: usize,
+    completions: Vec<Completion>,
+}
+
+/// The completion date at the confidence level.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Completion {
+    confidence: u8,
+    sprints: u32,
+    date: NaiveDate,
+}
+
+/// How many items will be done by the specific date.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ItemsForecast {
+    simulation: Simulation,
+    start: NaiveDate,
+    date: NaiveDate,
+    sprints: u32,
+    samples: Vec<u32>,
+    remaining: u32,
+    outcomes: Vec<ItemsOutcome>,
+}
+
+/// The number of items done at least, at the confidence level.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ItemsOutcome {
+    confidence: u8,
+    items: u32,
+}
+
+impl Simulation {
+    /// If `seed` is `None`, it is chosen randomly and kept to reproduce the result.
+    pub fn new(seed: Option<u64>, trials: usize, team: Option<Team>) -> BacklogResult<Self> {
+        if trials == 0 {
+            return Err(BacklogError::type_error(
+                "the number of trials must be greater than 0",
+            ));
+        }
+        Ok(Self {
+            seed: seed.unwrap_or_else(rand::random),
+            trials,
+            team,
+        })
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn trials(&self) -> usize {
+        self.trials
+    }
+
+    pub fn team(&self) -> Option<&Team> {
+        self.team.as_ref()
+    }
+
+    /// the sprints completed before `today` of the team.
+    fn sprints<'a>(
+        &self,
+        backlog: &'a Backlog,
+        today: NaiveDate,
+    ) -> BacklogResult<Vec<&'a Sprint>> {
+        let sprints: Vec<&Sprint> = backlog
+            .sprints()
+            .values()
+            .filter(|sprint| sprint.is_completed(today))
+            .filter(|sprint| match &self.team {
+                Some(team) => sprint.team() == team,
+                None => true,
+            })
+            .collect();
+        if sprints.is_empty() {
+            return Err(BacklogError::not_found(
+                "there are no completed sprints to sample from",
+            ));
+        }
+        Ok(sprints)
+    }
+}
+
+impl Forecast {
+    /// simulate when the remaining items of the backlog will be completed.
+    pub fn new(
+        backlog: &Backlog,
+        basis: ForecastBasis,
+        simulation: Simulation,
+        today: NaiveDate,
+    ) -> BacklogResult<Self> {
+        let sprints = simulation.sprints(backlog, today)?;
+        let sprint_length = sprint_length(&sprints);
+        let samples = samples(backlog, &sprints, basis);
+        if samples.iter().all(|sample| *sample == 0) {
+            return Err(BacklogError::type_error(
+                "the completed sprints have done nothing, it can not be forecasted",
+            ));
+        }
+
+        let remaining_items = backlog.items().values().filter(|item| !item.is_done());
+        let (remaining, unestimated) = match basis {
+            ForecastBasis::Velocity => {
+                remaining_items.fold((0, 0), |(points, none), item| match item.point() {
+                    Some(point) => (points + point.value() as u32, none),
+                    None => (points, none + 1),
+                })
+            }
+            ForecastBasis::Throughput => (remaining_items.count() as u32, 0),
+        };
+
+        let mut rng = ChaCha8Rng::seed_from_u64(simulation.seed);
+        let mut trials: Vec<u32> = (0..simulation.trials)
+            .map(|_| {
+                let mut done = 0;
+                let mut count = 0;
+                while done < remaining {
+                    done += samples.choose(&mut rng).copied().unwrap_or(0);
+                    count += 1;
+                }
+                count
+            })
+            .collect();
+        trials.sort_unstable();
+
+        let completions = CONFIDENCES
+            .iter()
+            .map(|confidence| {
+                let sprints = percentile(&trials, *confidence);
+                Completion {
+                    confidence: *confidence,
+                    sprints,
+                    date: today + Duration::days(sprints as i64 * sprint_length),
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            basis,
+            simulation,
+            start: today,
+            sprint_length,
+            samples,
+            remaining,
+            unestimated,
+            completions,
+        })
+    }
+
+    pub fn basis(&self) -> ForecastBasis {
+        self.basis
+    }
+
+    pub fn simulation(&self) -> &Simulation {
+        &self.simulation
+    }
+
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    pub fn sprint_length(&self) -> i64 {
+        self.sprint_length
+    }
+
+    pub fn samples(&self) -> &[u32] {
+        &self.samples
+    }
+
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    pub fn unestimated(&self) -> usize {
+        self.unestimated
+    }
+
+    pub fn completions(&self) -> &[Completion] {
+        &self.completions
+    }
+}
+
+impl Completion {
+    pub fn confidence(&self) -> u8 {
+        self.confidence
+    }
+
+    pub fn sprints(&self) -> u32 {
+        self.sprints
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+}
+
+impl ItemsForecast {
+    /// simulate how many items will be done by `date` from the throughput.
+    pub fn new(
+        backlog: &Backlog,
+        date: NaiveDate,
+        simulation: Simulation,
+        today: NaiveDate,
+    ) -> BacklogResult<Self> {
+        if date < today {
+            return Err(BacklogError::type_error(
+                "the date of the forecast must not be in the past",
+            ));
+        }
+        let sprints = simulation.sprints(backlog, today)?;
+        let sprint_length = sprint_length(&sprints);
+        let samples = samples(backlog, &sprints, ForecastBasis::Throughput);
+        let remaining = backlog
+            .items()
+            .values()
+            .filter(|item| !item.is_done())
+            .count() as u32;
+
+        let count = ((date - today).num_days() / sprint_length) as u32;
+        let mut rng = ChaCha8Rng::seed_from_u64(simulation.seed);
+        let mut trials: Vec<u32> = (0..simulation.trials)
+            .map(|_| {
+                let done: u32 = (0..count)
+                    .map(|_| samples.choose(&mut rng).copied().unwrap_or(0))
+                    .sum();
+                done.min(remaining)
+            })
+            .collect();
+        trials.sort_unstable();
+
+        // "at least N items with 85% confidence" is the 15th percentile.
+        let outcomes = CONFIDENCES
+            .iter()
+            .map(|confidence| ItemsOutcome {
+                confidence: *confidence,
+                items: percentile(&trials, 100 - *confidence),
+            })
+            .collect();
+
+        Ok(Self {
+            simulation,
+            start: today,
+            date,
+            sprints: count,
+            samples,
+            remaining,
+            outcomes,
+        })
+    }
+
+    pub fn simulation(&self) -> &Simulation {
+        &self.simulation
+    }
+
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    pub fn sprints(&self) -> u32 {
+        self.sprints
+    }
+
+    pub fn samples(&self) -> &[u32] {
+        &self.samples
+    }
+
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    pub fn outcomes(&self) -> &[ItemsOutcome] {
+        &self.outcomes
+    }
+}
+
+impl ItemsOutcome {
+    pub fn confidence(&self) -> u8 {
+        self.confidence
+    }
+
+    pub fn items(&self) -> u32 {
+        self.items
+    }
+}
+
+/// the average length of the sprints in days.
+fn sprint_length(sprints: &[&Sprint]) -> i64 {
+    let total: i64 = sprints
+        .iter()
+        .map(|sprint| (sprint.end() - sprint.start()).num_days() + 1)
+        .sum();
+    std::cmp::max(1, total / sprints.len() as i64)
+}
+
+fn samples(backlog: &Backlog, sprints: &[&Sprint], basis: ForecastBasis) -> Vec<u32> {
+    sprints
+        .iter()
+        .map(|sprint| match basis {
+            ForecastBasis::Velocity => backlog.completed_points(&sprint.id()),
+            ForecastBasis::Throughput => backlog.completed_items(&sprint.id()),
+        })
+        .collect()
+}
+
+/// the smallest value that `percent` % of the sorted values are less than or equal to.
+fn percentile(sorted: &[u32], percent: u8) -> u32 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (sorted.len() * percent as usize + 99) / 100;
+    sorted[rank.max(1) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::{AddItem, Change, Historical, Plannable, Progressable, Status, Story, StoryPoint};
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2022, month, day).unwrap()
+    }
+
+    /// the story done on the first day of the sprint if any, and not done otherwise.
+    fn story(point: u8, sprint: Option<&Sprint>) -> Box<Story> {
+        let mut story = Story::new("", Some(StoryPoint::new(point).unwrap()), None);
+        if let Some(sprint) = sprint {
+            let at = Utc.from_utc_datetime(&sprint.start().and_hms_opt(12, 0, 0).unwrap());
+            story.record_at(at, Change::Added);
+            story.plan(sprint.id());
+            story.change_status(Status::Done);
+            story.record_at(
+                at,
+                Change::StatusChanged {
+                    from: Status::Todo,
+                    to: Status::Done,
+                },
+            );
+        }
+        Box::new(story)
+    }
+
+    /// two completed sprints of 14 days, with 8 and 13 points.
+    fn backlog() -> Backlog {
+        let team = Team::new("team");
+        let sprint1 = Sprint::new("sprint 1", team.clone(), date(1, 1), date(1, 14)).unwrap();
+        let sprint2 = Sprint::new("sprint 2", team, date(1, 15), date(1, 28)).unwrap();
+
+        let mut backlog = Backlog::new();
+        backlog.add_item(story(3, Some(&sprint1)));
+        backlog.add_item(story(5, Some(&sprint1)));
+        backlog.add_item(story(13, Some(&sprint2)));
+        for _ in 0..6 {
+            backlog.add_item(story(5, None));
+        }
+        backlog.add_sprint(sprint1).unwrap();
+        backlog.add_sprint(sprint2).unwrap();
+        backlog
+    }
+
+    #[test]
+    fn test_forecast() {
+        let simulation = Simulation::new(Some(42), 1000, None).unwrap();
+        let forecast =
+            Forecast::new(&backlog(), ForecastBasis::Velocity, simulation, date(2, 1)).unwrap();
+
+        assert_eq!(forecast.remaining(), 30);
+        assert_eq!(forecast.sprint_length(), 14);
+        let sprints: Vec<u32> = forecast.completions().iter().map(|c| c.sprints()).collect();
+        // 30 points need 3 sprints of 13 points at best, 4 sprints of 8 points at worst.
+        assert!(sprints.iter().all(|s| (3..=4).contains(s)));
+        assert!(sprints.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(
+            forecast.completions()[0].date(),
+            date(2, 1) + Duration::days(sprints[0] as i64 * 14)
+        );
+    }
+
+    #[test]
+    fn test_forecast_is_deterministic() {
+        let run = |seed| {
+            let simulation = Simulation::new(Some(seed), 200, None).unwrap();
+            Forecast::new(
+                &backlog(),
+                ForecastBasis::Throughput,
+                simulation,
+                date(2, 1),
+            )
+            .unwrap()
+            .completions()
+            .to_vec()
+        };
+        assert_eq!(run(7), run(7));
+    }
+
+    #[test]
+    fn test_forecast_expect_fail() {
+        let simulation = Simulation::new(Some(1), 10, None).unwrap();
+        let result = Forecast::new(
+            &Backlog::new(),
+            ForecastBasis::Velocity,
+            simulation,
+            date(2, 1),
+        );
+        assert!(result.is_err());
+        assert!(Simulation::new(None, 0, None).is_err());
+    }
+
+    #[test]
+    fn test_items_forecast() {
+        let simulation = Simulation::new(Some(42), 1000, None).unwrap();
+        let forecast = ItemsForecast::new(&backlog(), date(3, 1), simulation, date(2, 1)).unwrap();
+
+        // 28 days are 2 sprints, and each sprint completes 1 or 2 items.
+        assert_eq!(forecast.sprints(), 2);
+        let items: Vec<u32> = forecast.outcomes().iter().map(|o| o.items()).collect();
+        assert!(items.iter().all(|i| (2..=4).contains(i)));
+        assert!(items.windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    fn test_percentile() {
+        let sorted: Vec<u32> = (1..=100).collect();
+        assert_eq!(percentile(&sorted, 50), 50);
+        assert_eq!(percentile(&sorted, 85), 85);
+        assert_eq!(percentile(&sorted, 5), 5);
+        assert_eq!(percentile(&[], 50), 0);
+    }
+}
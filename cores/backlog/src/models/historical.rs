@@ -0,0 +1,143 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{Assignee, Priority, Status, StoryPoint};
+
+/// A change of the item that happened at the specific time.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemEvent {
+    at: DateTime<Utc>,
+    change: Change,
+}
+
+/// What has been changed.
+///
+/// Each change keeps the value before the change,
+/// so the past state of the item can be rebuilt from the current state.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Change {
+    Added,
+    Estimated {
+        from: Option<StoryPoint>,
+        to: StoryPoint,
+    },
+    Assigned {
+        from: Option<Assignee>,
+        to: Assignee,
+    },
+    SprintChanged {
+        from: Option<Uuid>,
+        to: Option<Uuid>,
+    },
+    StatusChanged {
+        from: Status,
+        to: Status,
+    },
+    Prioritized {
+        from: Priority,
+        to: Priority,
+    },
+    Split {
+        into: Vec<Uuid>,
+    },
+    FixVersionChanged {
+        from: Option<Uuid>,
+        to: Option<Uuid>,
+    },
+    Attached {
+        attachment: Uuid,
+        filename: String,
+    },
+    Detached {
+        attachment: Uuid,
+        filename: String,
+    },
+    Labelled {
+        from: Vec<String>,
+        to: Vec<String>,
+    },
+    EpicChanged {
+        from: Option<Uuid>,
+        to: Option<Uuid>,
+    },
+    Linked {
+        from: Vec<Uuid>,
+        to: Vec<Uuid>,
+    },
+}
+
+impl ItemEvent {
+    pub fn new(at: DateTime<Utc>, change: Change) -> Self {
+        Self { at, change }
+    }
+
+    pub fn at(&self) -> DateTime<Utc> {
+        self.at
+    }
+
+    pub fn change(&self) -> &Change {
+        &self.change
+    }
+}
+
+/// It keeps the history of changes.
+pub trait Historical {
+    /// accessor
+    fn history(&self) -> &[ItemEvent];
+
+    /// mutable accessor
+    fn mut_history(&mut self) -> &mut Vec<ItemEvent>;
+
+    /// record the change as happened now.
+    fn record(&mut self, change: Change) {
+        self.record_at(Utc::now(), change);
+    }
+
+    /// record the change as happened at the specific time.
+    fn record_at(&mut self, at: DateTime<Utc>, change: Change) {
+        self.mut_history().push(ItemEvent::new(at, change));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestHistorical {
+        history: Vec<ItemEvent>,
+    }
+
+    impl Historical for TestHistorical {
+        fn history(&self) -> &[ItemEvent] {
+            &self.history
+        }
+
+        fn mut_history(&mut self) -> &mut Vec<ItemEvent> {
+            &mut self.history
+        }
+    }
+
+    #[test]
+    fn test_record() {
+        let mut historical = TestHistorical { history: vec![] };
+        historical.record(Change::Added);
+        historical.record(Change::StatusChanged {
+            from: Status::Todo,
+            to: Status::Done,
+        });
+
+        let changes: Vec<&Change> = historical.history().iter().map(|e| e.change()).collect();
+        assert_eq!(
+            changes,
+            vec![
+                &Change::Added,
+                &Change::StatusChanged {
+                    from: Status::Todo,
+                    to: Status::Done
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use serde::Serialize;
+
+use crate::text::suggest;
+use crate::{Assignee, BacklogError, BacklogItem, BacklogResult, Story, StoryPoint, Task};
+
+/// The constructor of a specific kind of item.
+pub type ItemConstructor = fn(&str, Option<StoryPoint>, Option<Assignee>) -> Box<dyn BacklogItem>;
+
+/// It describes a kind of backlog item that users can create.
+#[derive(Clone, Copy, Serialize)]
+pub struct ItemKind {
+    name: &'static str,
+    description: &'static str,
+    fields: &'static [&'static str],
+    #[serde(skip)]
+    constructor: ItemConstructor,
+}
+
+/// All kinds of items.
+///
+/// When you add a new item type, register it here.
+static ITEM_KINDS: &[ItemKind] = &[
+    ItemKind {
+        name: "Story",
+        description: "A user story that delivers value to users.",
+        fields: &["title", "point", "assignee"],
+        constructor: |title, point, assignee| Box::new(Story::new(title, point, assignee)),
+    },
+    ItemKind {
+        name: "Task",
+        description: "A piece of work that does not deliver value by itself.",
+        fields: &["title", "point", "assignee"],
+        constructor: |title, point, assignee| Box::new(Task::new(title, point, assignee)),
+    },
+];
+
+impl ItemKind {
+    /// return all registered kinds.
+    pub fn all() -> &'static [ItemKind] {
+        ITEM_KINDS
+    }
+
+    /// find the kind by name.
+    ///
+    /// If the kind does not find, return the error with suggestions.
+    pub fn find(name: &str) -> BacklogResult<&'static ItemKind> {
+        match Self::all().iter().find(|kind| kind.name == name) {
+            Some(kind) => Ok(kind),
+            None => {
+                let suggestions = Self::suggest(name);
+                let msg = if suggestions.is_empty() {
+                    format!(
+                        "unknown item kind, {}. available kinds are {}",
+                        name,
+                        Self::names().join(", ")
+                    )
+                } else {
+                    format!(
+                        "unknown item kind, {}. did you mean {}?",
+                        name,
+                        suggestions.join(" or ")
+                    )
+                };
+                Err(BacklogError::type_error(msg))
+            }
+        }
+    }
+
+    /// create the item of the specific kind.
+    pub fn create(
+        name: &str,
+        title: &str,
+        point: Option<StoryPoint>,
+        assignee: Option<Assignee>,
+    ) -> BacklogResult<Box<dyn BacklogItem>> {
+        Self::find(name).map(|kind| kind.new_item(title, point, assignee))
+    }
+
+    /// return the names of all registered kinds.
+    pub fn names() -> Vec<&'static str> {
+        Self::all().iter().map(|kind| kind.name).collect()
+    }
+
+    /// return the names of kinds which look like the specific name.
+    pub fn suggest(name: &str) -> Vec<&'static str> {
+        suggest(name, Self::all().iter().map(|kind| kind.name))
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    pub fn fields(&self) -> &'static [&'static str] {
+        self.fields
+    }
+
+    /// create the item of this kind.
+    pub fn new_item(
+        &self,
+        title: &str,
+        point: Option<StoryPoint>,
+        assignee: Option<Assignee>,
+    ) -> Box<dyn BacklogItem> {
+        (self.constructor)(title, point, assignee)
+    }
+}
+
+impl std::fmt::Debug for ItemKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ItemKind")
+            .field("name", &self.name)
+            .field("description", &self.description)
+            .field("fields", &self.fields)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_create() {
+        let item = ItemKind::create("Story", "title", None, None).unwrap();
+        let value = serde_json::to_value(&item).unwrap();
+        assert_eq!(value["type"], "Story");
+        assert_eq!(value["title"], "title");
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(ItemKind::names(), vec!["Story", "Task"]);
+    }
+
+    #[rstest(
+        input,
+        expected,
+        case("story", vec!["Story"]),
+        case("Stroy", vec!["Story"]),
+        case("tsak", vec!["Task"]),
+        case("Epic", vec![])
+    )]
+    fn test_suggest(input: &str, expected: Vec<&str>) {
+        assert_eq!(ItemKind::suggest(input), expected)
+    }
+
+    #[test]
+    fn test_find_unknown_kind() {
+        let err = ItemKind::find("Stroy").unwrap_err();
+        assert!(err.to_string().contains("did you mean Story?"));
+    }
+}
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{Assignee, Backlog, ItemEvent};
+
+/// The message to the watchers that the item has been changed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Notification {
+    item: Uuid,
+    title: String,
+    event: ItemEvent,
+    recipients: Vec<Assignee>,
+}
+
+impl Notification {
+    pub fn item(&self) -> Uuid {
+        self.item
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn event(&self) -> &ItemEvent {
+        &self.event
+    }
+
+    pub fn recipients(&self) -> &[Assignee] {
+        &self.recipients
+    }
+}
+
+/// make a notification for each change recorded on watched items since `before`.
+pub(crate) fn notifications(backlog: &Backlog, before: &Backlog) -> Vec<Notification> {
+    backlog
+        .items()
+        .values()
+        .filter(|item| !item.watchers().is_empty())
+        .flat_map(|item| {
+            let seen = before
+                .items()
+                .get(&item.id())
+                .map(|prev| prev.history().len())
+                .unwrap_or(0);
+            item.history()
+                .iter()
+                .skip(seen)
+                .map(move |event| Notification {
+                    item: item.id(),
+                    title: item.title().value().to_string(),
+                    event: event.clone(),
+                    recipients: item.watchers().to_vec(),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BacklogFixture, Change, PrioritizableFromCollection, Priority, ProgressableFromCollection,
+        Status, WatchableFromCollection,
+    };
+
+    #[test]
+    fn test_notifications() {
+        let (id, mut backlog) = Backlog::specific_id();
+        backlog.change_item_status(&id, Status::InProgress).unwrap();
+        let before = backlog.clone();
+        // nobody watches the item yet.
+        backlog.prioritize_item(&id, Priority::High).unwrap();
+        assert!(backlog.notifications(&before).is_empty());
+
+        let before = backlog.clone();
+        backlog.watch_item(&id, Assignee::new("dummy")).unwrap();
+        backlog.change_item_status(&id, Status::Done).unwrap();
+
+        let notifications = backlog.notifications(&before);
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].recipients(), &[Assignee::new("dummy")]);
+        assert_eq!(
+            notifications[0].event().change(),
+            &Change::StatusChanged {
+                from: Status::InProgress,
+                to: Status::Done
+            }
+        );
+        assert!(backlog.watch_item(&id, Assignee::new("unknown")).is_err());
+    }
+}
@@ -0,0 +1,131 @@
+use uuid::Uuid;
+
+use crate::{BacklogError, BacklogItem, BacklogResult, Change, FindFromCollection};
+
+/// Represents that the item will be planned into a sprint.
+pub trait Plannable {
+    /// accessor
+    fn sprint(&self) -> Option<Uuid>;
+
+    /// mutable accessor
+    fn mut_sprint(&mut self) -> &mut Option<Uuid>;
+
+    /// plan the item into the specific sprint.
+    fn plan(&mut self, sprint: Uuid) {
+        *self.mut_sprint() = Some(sprint);
+    }
+
+    /// move the item out of the sprint.
+    fn unplan(&mut self) {
+        *self.mut_sprint() = None;
+    }
+}
+
+/// The collection can search a specific item and plan it into a sprint.
+pub trait PlannableFromCollection:
+    FindFromCollection<Key = Uuid, Ret = Box<dyn BacklogItem>>
+{
+    /// move the specific item into the sprint, or out of any sprint with `None`.
+    fn plan_item(&mut self, id: &Uuid, sprint: Option<Uuid>) -> BacklogResult<()> {
+        match self.find_by_id_mut(id) {
+            None => Err(BacklogError::not_found(format!(
+                "BacklogItem, id: {} does not found",
+                id
+            ))),
+            Some(item) => {
+                let from = item.sprint();
+                if from == sprint {
+                    return Ok(());
+                }
+                match sprint {
+                    Some(sprint) => item.plan(sprint),
+                    None => item.unplan(),
+                }
+                item.record(Change::SprintChanged { from, to: sprint });
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestPlannable {
+        sprint: Option<Uuid>,
+    }
+
+    impl Plannable for TestPlannable {
+        fn sprint(&self) -> Option<Uuid> {
+            self.sprint
+        }
+
+        fn mut_sprint(&mut self) -> &mut Option<Uuid> {
+            &mut self.sprint
+        }
+    }
+
+    #[test]
+    fn test_plannable() {
+        let sprint = Uuid::new_v4();
+        let mut plannable = TestPlannable { sprint: None };
+        plannable.plan(sprint);
+        assert_eq!(plannable.sprint(), Some(sprint));
+
+        plannable.unplan();
+        assert_eq!(plannable.sprint(), None);
+    }
+}
+
+#[cfg(test)]
+mod test_plannable_from_collection {
+    use indexmap::IndexMap;
+
+    use super::*;
+    use crate::{Entity, Story};
+
+    struct TestCollection(IndexMap<Uuid, Box<dyn BacklogItem>>);
+
+    impl FindFromCollection for TestCollection {
+        type Key = Uuid;
+        type Ret = Box<dyn BacklogItem>;
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+
+        fn find_by_id_mut(&mut self, key: &Self::Key) -> Option<&mut Self::Ret> {
+            self.0.get_mut(key)
+        }
+    }
+
+    impl PlannableFromCollection for TestCollection {}
+
+    #[test]
+    fn test_plan_item() {
+        let story: Box<dyn BacklogItem> = Box::new(Story::new("", None, None));
+        let id = story.id();
+        let mut collection = TestCollection(IndexMap::new());
+        collection.0.insert(id, story);
+
+        let sprint = Uuid::new_v4();
+        collection.plan_item(&id, Some(sprint)).unwrap();
+        // planning into the same sprint does not record the change.
+        collection.plan_item(&id, Some(sprint)).unwrap();
+
+        let item = &collection.0[&id];
+        assert_eq!(item.sprint(), Some(sprint));
+        assert_eq!(item.history().len(), 1);
+    }
+
+    #[test]
+    fn test_plan_item_expect_fail() {
+        let mut collection = TestCollection(IndexMap::new());
+        assert!(collection.plan_item(&Uuid::new_v4(), None).is_err());
+    }
+}
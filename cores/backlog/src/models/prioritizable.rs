@@ -0,0 +1,104 @@
+use uuid::Uuid;
+
+use crate::{BacklogError, BacklogItem, BacklogResult, Change, FindFromCollection, Priority, Rank};
+
+/// Represents that the item has the priority and the position in the ranking.
+pub trait Prioritizable {
+    /// accessor
+    fn priority(&self) -> Priority;
+
+    /// mutable accessor
+    fn mut_priority(&mut self) -> &mut Priority;
+
+    /// accessor
+    fn rank(&self) -> &Rank;
+
+    /// mutable accessor
+    fn mut_rank(&mut self) -> &mut Rank;
+
+    /// change the priority of the item.
+    fn prioritize(&mut self, priority: Priority) {
+        *self.mut_priority() = priority;
+    }
+}
+
+/// The collection can search a specific item and change its priority.
+pub trait PrioritizableFromCollection:
+    FindFromCollection<Key = Uuid, Ret = Box<dyn BacklogItem>>
+{
+    /// change the priority of the specific item.
+    fn prioritize_item(&mut self, id: &Uuid, priority: Priority) -> BacklogResult<()> {
+        match self.find_by_id_mut(id) {
+            None => Err(BacklogError::not_found(format!(
+                "BacklogItem, id: {} does not found",
+                id
+            ))),
+            Some(item) => {
+                let from = item.priority();
+                if from == priority {
+                    return Ok(());
+                }
+                item.prioritize(priority);
+                item.record(Change::Prioritized { from, to: priority });
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_prioritizable_from_collection {
+    use indexmap::IndexMap;
+
+    use super::*;
+    use crate::{Entity, Story};
+
+    struct TestCollection(IndexMap<Uuid, Box<dyn BacklogItem>>);
+
+    impl FindFromCollection for TestCollection {
+        type Key = Uuid;
+        type Ret = Box<dyn BacklogItem>;
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+
+        fn find_by_id_mut(&mut self, key: &Self::Key) -> Option<&mut Self::Ret> {
+            self.0.get_mut(key)
+        }
+    }
+
+    impl PrioritizableFromCollection for TestCollection {}
+
+    #[test]
+    fn test_prioritize_item() {
+        let story: Box<dyn BacklogItem> = Box::new(Story::new("", None, None));
+        let id = story.id();
+        let mut collection = TestCollection(IndexMap::new());
+        collection.0.insert(id, story);
+
+        collection.prioritize_item(&id, Priority::Highest).unwrap();
+
+        let item = &collection.0[&id];
+        assert_eq!(item.priority(), Priority::Highest);
+        assert_eq!(
+            item.history()[0].change(),
+            &Change::Prioritized {
+                from: Priority::Medium,
+                to: Priority::Highest
+            }
+        );
+    }
+
+    #[test]
+    fn test_prioritize_item_expect_fail() {
+        let mut collection = TestCollection(IndexMap::new());
+        assert!(collection
+            .prioritize_item(&Uuid::new_v4(), Priority::Low)
+            .is_err());
+    }
+}
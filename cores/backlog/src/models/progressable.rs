@@ -0,0 +1,133 @@
+use uuid::Uuid;
+
+use crate::{BacklogError, BacklogItem, BacklogResult, Change, FindFromCollection, Status};
+
+/// Represents that the item has the progress.
+pub trait Progressable {
+    /// accessor
+    fn status(&self) -> Status;
+
+    /// mutable accessor
+    fn mut_status(&mut self) -> &mut Status;
+
+    /// change the status of the item.
+    fn change_status(&mut self, status: Status) {
+        *self.mut_status() = status;
+    }
+
+    /// whether the item has been done.
+    fn is_done(&self) -> bool {
+        self.status() == Status::Done
+    }
+}
+
+/// The collection can search a specific item and change its status.
+pub trait ProgressableFromCollection:
+    FindFromCollection<Key = Uuid, Ret = Box<dyn BacklogItem>>
+{
+    /// change the status of the specific item.
+    fn change_item_status(&mut self, id: &Uuid, status: Status) -> BacklogResult<()> {
+        match self.find_by_id_mut(id) {
+            None => Err(BacklogError::not_found(format!(
+                "BacklogItem, id: {} does not found",
+                id
+            ))),
+            Some(item) => {
+                let from = item.status();
+                if from == status {
+                    return Ok(());
+                }
+                item.change_status(status);
+                item.record(Change::StatusChanged { from, to: status });
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestProgressable {
+        status: Status,
+    }
+
+    impl Progressable for TestProgressable {
+        fn status(&self) -> Status {
+            self.status
+        }
+
+        fn mut_status(&mut self) -> &mut Status {
+            &mut self.status
+        }
+    }
+
+    #[test]
+    fn test_progressable() {
+        let mut progressable = TestProgressable {
+            status: Status::default(),
+        };
+        assert!(!progressable.is_done());
+
+        progressable.change_status(Status::Done);
+        assert!(progressable.is_done());
+    }
+}
+
+#[cfg(test)]
+mod test_progressable_from_collection {
+    use indexmap::IndexMap;
+
+    use super::*;
+    use crate::{Entity, Story};
+
+    struct TestCollection(IndexMap<Uuid, Box<dyn BacklogItem>>);
+
+    impl FindFromCollection for TestCollection {
+        type Key = Uuid;
+        type Ret = Box<dyn BacklogItem>;
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+
+        fn find_by_id_mut(&mut self, key: &Self::Key) -> Option<&mut Self::Ret> {
+            self.0.get_mut(key)
+        }
+    }
+
+    impl ProgressableFromCollection for TestCollection {}
+
+    #[test]
+    fn test_change_item_status() {
+        let story: Box<dyn BacklogItem> = Box::new(Story::new("", None, None));
+        let id = story.id();
+        let mut collection = TestCollection(IndexMap::new());
+        collection.0.insert(id, story);
+
+        collection.change_item_status(&id, Status::Done).unwrap();
+
+        let item = &collection.0[&id];
+        assert!(item.is_done());
+        assert_eq!(
+            item.history()[0].change(),
+            &Change::StatusChanged {
+                from: Status::Todo,
+                to: Status::Done
+            }
+        );
+    }
+
+    #[test]
+    fn test_change_item_status_expect_fail() {
+        let mut collection = TestCollection(IndexMap::new());
+        assert!(collection
+            .change_item_status(&Uuid::new_v4(), Status::Done)
+            .is_err());
+    }
+}
@@ -0,0 +1,256 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{BacklogError, BacklogItem, BacklogResult};
+
+const DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+const BASE: u32 = 36;
+
+/// The lexicographically ordered key of the item.
+///
+/// A key can always be made between two others, so an item is moved without renumbering the rest.
+/// Keys never end with `0`, otherwise no key could be made between `a` and `a0`.
+/// The empty key means that the item has not been ranked yet, and it is ordered last.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Rank(String);
+
+/// Where the item is moved in the ranking.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Placement {
+    Top,
+    Bottom,
+    After(Uuid),
+    Before(Uuid),
+}
+
+/// How the items are sorted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ItemOrder {
+    /// by rank.
+    Rank,
+    /// by priority, and then by rank.
+    Priority,
+}
+
+impl Rank {
+    /// When a key gets longer than this, all keys are rebalanced.
+    pub const MAX_LENGTH: usize = 12;
+
+    pub fn new(rank: &str) -> BacklogResult<Self> {
+        if rank.is_empty() || rank.ends_with('0') || !rank.bytes().all(|b| DIGITS.contains(&b)) {
+            return Err(BacklogError::type_error(format!(
+                "the rank must consist of 0-9 and a-z and must not end with 0, {}",
+                rank
+            )));
+        }
+        Ok(Self(rank.to_string()))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// make the key between `prev` and `next`. `None` means the edge of the ranking.
+    pub fn between(prev: Option<&Rank>, next: Option<&Rank>) -> BacklogResult<Self> {
+        let prev = prev.map(|r| r.0.as_bytes()).unwrap_or_default();
+        let mut next = next.map(|r| r.0.as_bytes());
+        if let Some(next) = next {
+            if prev >= next {
+                return Err(BacklogError::type_error(
+                    "the previous rank must be less than the next rank",
+                ));
+            }
+        }
+
+        let mut rank = Vec::new();
+        for i in 0.. {
+            let p = prev.get(i).map(|&b| digit(b)).unwrap_or(0);
+            let n = next
+                .and_then(|next| next.get(i))
+                .map(|&b| digit(b))
+                .unwrap_or(BASE);
+            if p == n {
+                rank.push(DIGITS[p as usize]);
+                continue;
+            }
+            if n - p > 1 {
+                rank.push(DIGITS[((p + n) / 2) as usize]);
+                break;
+            }
+            // there is no room at this digit, so anything after `prev` is less than `next`.
+            rank.push(DIGITS[p as usize]);
+            next = None;
+        }
+        Ok(Self(String::from_utf8(rank).unwrap()))
+    }
+
+    /// make the key after `prev`, keeping it as short as possible.
+    pub fn after(prev: Option<&Rank>) -> Self {
+        match prev.and_then(|prev| prev.0.as_bytes().last().map(|&b| (prev, digit(b)))) {
+            Some((prev, last)) if last + 1 < BASE => {
+                let mut rank = prev.0.clone();
+                rank.pop();
+                rank.push(DIGITS[(last + 1) as usize] as char);
+                Self(rank)
+            }
+            _ => Self::between(prev, None).unwrap(),
+        }
+    }
+
+    /// make `count` keys evenly spread over the whole range.
+    pub fn spread(count: usize) -> Vec<Self> {
+        // two extra digits leave room for moves and appends between the keys.
+        let mut width = 2;
+        let mut capacity = BASE as u128 * BASE as u128;
+        while capacity < count as u128 + 1 {
+            width += 1;
+            capacity *= BASE as u128;
+        }
+        width += 1;
+        capacity *= BASE as u128;
+
+        let step = capacity / (count as u128 + 1);
+        (1..=count as u128)
+            .map(|i| {
+                let mut value = i * step;
+                let mut rank = vec![b'0'; width];
+                for digit in rank.iter_mut().rev() {
+                    *digit = DIGITS[(value % BASE as u128) as usize];
+                    value /= BASE as u128;
+                }
+                while rank.last() == Some(&b'0') {
+                    rank.pop();
+                }
+                Self(String::from_utf8(rank).unwrap())
+            })
+            .collect()
+    }
+}
+
+impl PartialOrd for Rank {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rank {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // the unranked item comes last.
+        (self.is_empty(), &self.0).cmp(&(other.is_empty(), &other.0))
+    }
+}
+
+impl std::str::FromStr for ItemOrder {
+    type Err = BacklogError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rank" => Ok(Self::Rank),
+            "priority" => Ok(Self::Priority),
+            _ => Err(BacklogError::type_error(format!(
+                "the order must be rank or priority, {}",
+                s
+            ))),
+        }
+    }
+}
+
+fn digit(b: u8) -> u32 {
+    DIGITS.iter().position(|&d| d == b).unwrap_or(0) as u32
+}
+
+/// return the ids of items in the ranking order.
+pub(crate) fn ranked_ids(items: &IndexMap<Uuid, Box<dyn BacklogItem>>) -> Vec<Uuid> {
+    let mut ids: Vec<(&Rank, Uuid)> = items
+        .values()
+        .map(|item| (item.rank(), item.id()))
+        .collect();
+    // the sort is stable, so unranked items keep the order in which they were added.
+    ids.sort_by(|a, b| a.0.cmp(b.0));
+    ids.into_iter().map(|(_, id)| id).collect()
+}
+
+/// give evenly spread keys to all items, keeping their order.
+pub(crate) fn rebalance(items: &mut IndexMap<Uuid, Box<dyn BacklogItem>>) {
+    let ids = ranked_ids(items);
+    for (id, rank) in ids.iter().zip(Rank::spread(ids.len())) {
+        if let Some(item) = items.get_mut(id) {
+            *item.mut_rank() = rank;
+        }
+    }
+}
+
+/// make the key after every item.
+pub(crate) fn last_rank(items: &mut IndexMap<Uuid, Box<dyn BacklogItem>>) -> Rank {
+    if items.values().any(|item| item.rank().is_empty()) {
+        rebalance(items);
+    }
+    let rank = Rank::after(items.values().map(|item| item.rank()).max());
+    if rank.0.len() <= Rank::MAX_LENGTH {
+        return rank;
+    }
+    rebalance(items);
+    Rank::after(items.values().map(|item| item.rank()).max())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn rank(s: &str) -> Rank {
+        Rank::new(s).unwrap()
+    }
+
+    #[rstest(
+        prev,
+        next,
+        expected,
+        case(None, None, "i"),
+        case(Some("a"), Some("c"), "b"),
+        case(Some("a"), Some("b"), "ai"),
+        case(Some("az"), Some("b"), "azi"),
+        case(Some("a"), Some("a1"), "a0i"),
+        case(None, Some("1"), "0i"),
+        case(Some("y"), None, "z")
+    )]
+    fn test_between(prev: Option<&str>, next: Option<&str>, expected: &str) {
+        let prev = prev.map(rank);
+        let next = next.map(rank);
+        let between = Rank::between(prev.as_ref(), next.as_ref()).unwrap();
+        assert_eq!(between.value(), expected);
+        assert!(prev.map_or(true, |prev| prev < between));
+        assert!(next.map_or(true, |next| between < next));
+    }
+
+    #[test]
+    fn test_between_expect_fail() {
+        assert!(Rank::between(Some(&rank("b")), Some(&rank("a"))).is_err());
+    }
+
+    #[test]
+    fn test_after() {
+        assert_eq!(Rank::after(None).value(), "i");
+        assert_eq!(Rank::after(Some(&rank("a5"))).value(), "a6");
+        assert_eq!(Rank::after(Some(&rank("z"))).value(), "zi");
+    }
+
+    #[test]
+    fn test_spread() {
+        let ranks = Rank::spread(5000);
+        assert!(ranks.windows(2).all(|w| w[0] < w[1]));
+        assert!(ranks.iter().all(|r| !r.value().ends_with('0')));
+        assert!(ranks.iter().all(|r| r.value().len() <= 4));
+    }
+
+    #[test]
+    fn test_unranked_comes_last() {
+        assert!(rank("z") < Rank::default());
+    }
+}
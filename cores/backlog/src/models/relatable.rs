@@ -0,0 +1,209 @@
+use uuid::Uuid;
+
+use crate::{BacklogError, BacklogItem, BacklogResult, Change, FindFromCollection};
+
+/// Represents that the item is labelled, grouped under an epic, and linked to the related items.
+///
+/// By default, the item has none of them, and can not have them.
+pub trait Relatable {
+    /// accessor
+    fn labels(&self) -> &[String] {
+        &[]
+    }
+
+    /// the item which this item is grouped under.
+    fn epic(&self) -> Option<Uuid> {
+        None
+    }
+
+    /// the related items.
+    fn links(&self) -> &[Uuid] {
+        &[]
+    }
+
+    /// mutable accessor, `None` if the item can not be labelled.
+    fn mut_labels(&mut self) -> Option<&mut Vec<String>> {
+        None
+    }
+
+    /// mutable accessor, `None` if the item can not be grouped.
+    fn mut_epic(&mut self) -> Option<&mut Option<Uuid>> {
+        None
+    }
+
+    /// mutable accessor, `None` if the item can not be linked.
+    fn mut_links(&mut self) -> Option<&mut Vec<Uuid>> {
+        None
+    }
+}
+
+fn not_found(id: &Uuid) -> BacklogError {
+    BacklogError::not_found(format!("BacklogItem, id: {} does not found", id))
+}
+
+/// The collection can search a specific item and change its labels, epic and links.
+pub trait RelatableFromCollection:
+    FindFromCollection<Key = Uuid, Ret = Box<dyn BacklogItem>>
+{
+    /// replace the labels of the specific item.
+    ///
+    /// The labels are trimmed, and the blank and the repeated ones are left out.
+    fn label_item(&mut self, id: &Uuid, labels: Vec<String>) -> BacklogResult<()> {
+        let mut to: Vec<String> = Vec::new();
+        for label in labels {
+            let label = label.trim().to_string();
+            if !label.is_empty() && !to.contains(&label) {
+                to.push(label);
+            }
+        }
+        let item = self.find_by_id_mut(id).ok_or_else(|| not_found(id))?;
+        let from = item.labels().to_vec();
+        if from == to {
+            return Ok(());
+        }
+        let kind = item.kind();
+        let labels = item
+            .mut_labels()
+            .ok_or_else(|| BacklogError::type_error(format!("the {} can not be labelled", kind)))?;
+        *labels = to.clone();
+        item.record(Change::Labelled { from, to });
+        Ok(())
+    }
+
+    /// group the specific item under the epic, or under no epic with `None`.
+    fn set_item_epic(&mut self, id: &Uuid, epic: Option<Uuid>) -> BacklogResult<()> {
+        if let Some(epic) = epic {
+            if &epic == id {
+                return Err(BacklogError::type_error(
+                    "the item can not be grouped under itself",
+                ));
+            }
+            self.find_by_id_mut(&epic).ok_or_else(|| not_found(&epic))?;
+        }
+        let item = self.find_by_id_mut(id).ok_or_else(|| not_found(id))?;
+        let from = item.epic();
+        if from == epic {
+            return Ok(());
+        }
+        let kind = item.kind();
+        let mut_epic = item
+            .mut_epic()
+            .ok_or_else(|| BacklogError::type_error(format!("the {} can not be grouped", kind)))?;
+        *mut_epic = epic;
+        item.record(Change::EpicChanged { from, to: epic });
+        Ok(())
+    }
+
+    /// replace the items which the specific item is linked to.
+    ///
+    /// The repeated ones are left out.
+    fn link_item(&mut self, id: &Uuid, links: Vec<Uuid>) -> BacklogResult<()> {
+        let mut to: Vec<Uuid> = Vec::new();
+        for link in links {
+            if &link == id {
+                return Err(BacklogError::type_error(
+                    "the item can not be linked to itself",
+                ));
+            }
+            self.find_by_id_mut(&link).ok_or_else(|| not_found(&link))?;
+            if !to.contains(&link) {
+                to.push(link);
+            }
+        }
+        let item = self.find_by_id_mut(id).ok_or_else(|| not_found(id))?;
+        let from = item.links().to_vec();
+        if from == to {
+            return Ok(());
+        }
+        let kind = item.kind();
+        let links = item
+            .mut_links()
+            .ok_or_else(|| BacklogError::type_error(format!("the {} can not be linked", kind)))?;
+        *links = to.clone();
+        item.record(Change::Linked { from, to });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_relatable_from_collection {
+    use indexmap::IndexMap;
+
+    use super::*;
+    use crate::{Entity, Story, Task};
+
+    struct TestCollection(IndexMap<Uuid, Box<dyn BacklogItem>>);
+
+    impl FindFromCollection for TestCollection {
+        type Key = Uuid;
+        type Ret = Box<dyn BacklogItem>;
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+
+        fn find_by_id_mut(&mut self, key: &Self::Key) -> Option<&mut Self::Ret> {
+            self.0.get_mut(key)
+        }
+    }
+
+    impl RelatableFromCollection for TestCollection {}
+
+    fn collection() -> (Uuid, Uuid, Uuid, TestCollection) {
+        let story: Box<dyn BacklogItem> = Box::new(Story::new("Login page", None, None));
+        let epic: Box<dyn BacklogItem> = Box::new(Story::new("Accounts", None, None));
+        let task: Box<dyn BacklogItem> = Box::new(Task::new("Upgrade", None, None));
+        let (id, epic_id, task_id) = (story.id(), epic.id(), task.id());
+        let mut collection = TestCollection(IndexMap::new());
+        collection.0.insert(id, story);
+        collection.0.insert(epic_id, epic);
+        collection.0.insert(task_id, task);
+        (id, epic_id, task_id, collection)
+    }
+
+    #[test]
+    fn test_label_item() {
+        let (id, _, task_id, mut collection) = collection();
+        let labels = vec![" ui ".to_string(), "".to_string(), "ui".to_string()];
+        collection.label_item(&id, labels).unwrap();
+        collection.label_item(&id, vec!["ui".to_string()]).unwrap();
+
+        let item = &collection.0[&id];
+        assert_eq!(item.labels(), ["ui"]);
+        assert_eq!(item.history().len(), 1);
+        assert!(collection
+            .label_item(&task_id, vec!["ui".to_string()])
+            .is_err());
+        assert!(collection.label_item(&Uuid::new_v4(), Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_set_item_epic() {
+        let (id, epic, _, mut collection) = collection();
+        collection.set_item_epic(&id, Some(epic)).unwrap();
+        assert_eq!(collection.0[&id].epic(), Some(epic));
+
+        assert!(collection.set_item_epic(&id, Some(id)).is_err());
+        assert!(collection.set_item_epic(&id, Some(Uuid::new_v4())).is_err());
+        collection.set_item_epic(&id, None).unwrap();
+        let item = &collection.0[&id];
+        assert_eq!(item.epic(), None);
+        assert_eq!(item.history().len(), 2);
+    }
+
+    #[test]
+    fn test_link_item() {
+        let (id, epic, task, mut collection) = collection();
+        collection.link_item(&id, vec![task, epic, task]).unwrap();
+        assert_eq!(collection.0[&id].links(), [task, epic]);
+
+        assert!(collection.link_item(&id, vec![id]).is_err());
+        assert!(collection.link_item(&id, vec![Uuid::new_v4()]).is_err());
+        assert!(collection.link_item(&task, vec![id]).is_err());
+        assert_eq!(collection.0[&id].links(), [task, epic]);
+    }
+}
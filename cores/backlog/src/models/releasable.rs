@@ -0,0 +1,82 @@
+use uuid::Uuid;
+
+use crate::{BacklogError, BacklogItem, BacklogResult, Change, FindFromCollection};
+
+/// Represents that the item will be shipped in a release.
+pub trait Releasable {
+    /// accessor
+    fn fix_version(&self) -> Option<Uuid>;
+
+    /// mutable accessor
+    fn mut_fix_version(&mut self) -> &mut Option<Uuid>;
+}
+
+/// The collection can search a specific item and change its fix version.
+pub trait ReleasableFromCollection:
+    FindFromCollection<Key = Uuid, Ret = Box<dyn BacklogItem>>
+{
+    /// target the specific item at the release, or at no release with `None`.
+    fn fix_item_version(&mut self, id: &Uuid, release: Option<Uuid>) -> BacklogResult<()> {
+        match self.find_by_id_mut(id) {
+            None => Err(BacklogError::not_found(format!(
+                "BacklogItem, id: {} does not found",
+                id
+            ))),
+            Some(item) => {
+                let from = item.fix_version();
+                if from == release {
+                    return Ok(());
+                }
+                *item.mut_fix_version() = release;
+                item.record(Change::FixVersionChanged { from, to: release });
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_releasable_from_collection {
+    use indexmap::IndexMap;
+
+    use super::*;
+    use crate::{Entity, Story};
+
+    struct TestCollection(IndexMap<Uuid, Box<dyn BacklogItem>>);
+
+    impl FindFromCollection for TestCollection {
+        type Key = Uuid;
+        type Ret = Box<dyn BacklogItem>;
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+
+        fn find_by_id_mut(&mut self, key: &Self::Key) -> Option<&mut Self::Ret> {
+            self.0.get_mut(key)
+        }
+    }
+
+    impl ReleasableFromCollection for TestCollection {}
+
+    #[test]
+    fn test_fix_item_version() {
+        let story: Box<dyn BacklogItem> = Box::new(Story::new("", None, None));
+        let id = story.id();
+        let mut collection = TestCollection(IndexMap::new());
+        collection.0.insert(id, story);
+
+        let release = Uuid::new_v4();
+        collection.fix_item_version(&id, Some(release)).unwrap();
+        collection.fix_item_version(&id, Some(release)).unwrap();
+
+        let item = &collection.0[&id];
+        assert_eq!(item.fix_version(), Some(release));
+        assert_eq!(item.history().len(), 1);
+        assert!(collection.fix_item_version(&Uuid::new_v4(), None).is_err());
+    }
+}
@@ -0,0 +1,259 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{Backlog, BacklogError, BacklogItem, BacklogResult, Entity, Status};
+
+/// It means the version in which items are shipped.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Release {
+    id: Uuid,
+    name: String,
+    target: NaiveDate,
+    #[serde(default)]
+    released: bool,
+}
+
+/// The item shipped in a release.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ReleaseItem {
+    id: Uuid,
+    title: String,
+    status: Status,
+}
+
+/// The done items of the release, grouped by type.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ReleaseNotes {
+    release: Release,
+    sections: Vec<ReleaseSection>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ReleaseSection {
+    kind: String,
+    items: Vec<ReleaseItem>,
+}
+
+/// The items of the release which are not done yet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UnresolvedItems {
+    release: Release,
+    items: Vec<ReleaseItem>,
+}
+
+impl Release {
+    pub fn new(name: &str, target: NaiveDate) -> BacklogResult<Self> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(BacklogError::type_error(
+                "the release name must not be empty",
+            ));
+        }
+        Ok(Self {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            target,
+            released: false,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn target(&self) -> NaiveDate {
+        self.target
+    }
+
+    pub fn is_released(&self) -> bool {
+        self.released
+    }
+}
+
+impl Entity for Release {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+impl ReleaseItem {
+    fn new(item: &dyn BacklogItem) -> Self {
+        Self {
+            id: item.id(),
+            title: item.title().value().to_string(),
+            status: item.status(),
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+}
+
+impl ReleaseNotes {
+    pub fn release(&self) -> &Release {
+        &self.release
+    }
+
+    pub fn sections(&self) -> &[ReleaseSection] {
+        &self.sections
+    }
+}
+
+impl ReleaseSection {
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub fn items(&self) -> &[ReleaseItem] {
+        &self.items
+    }
+}
+
+impl UnresolvedItems {
+    pub fn release(&self) -> &Release {
+        &self.release
+    }
+
+    pub fn items(&self) -> &[ReleaseItem] {
+        &self.items
+    }
+}
+
+/// render the notes as markdown.
+impl std::fmt::Display for ReleaseNotes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "# {} ({})", self.release.name, self.release.target)?;
+        for section in &self.sections {
+            write!(f, "\n\n## {}\n", section.kind)?;
+            for item in &section.items {
+                write!(f, "\n- {}", item.title)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn items_of<'a>(backlog: &'a Backlog, release: &Release) -> Vec<&'a dyn BacklogItem> {
+    backlog
+        .items()
+        .values()
+        .map(|item| item.as_ref())
+        .filter(|item| item.fix_version() == Some(release.id))
+        .collect()
+}
+
+pub(crate) fn release_notes(backlog: &Backlog, release: &Release) -> ReleaseNotes {
+    let mut sections: Vec<ReleaseSection> = Vec::new();
+    for item in items_of(backlog, release) {
+        if item.status() != Status::Done {
+            continue;
+        }
+        match sections.iter_mut().find(|s| s.kind == item.kind()) {
+            Some(section) => section.items.push(ReleaseItem::new(item)),
+            None => sections.push(ReleaseSection {
+                kind: item.kind().to_string(),
+                items: vec![ReleaseItem::new(item)],
+            }),
+        }
+    }
+    ReleaseNotes {
+        release: release.clone(),
+        sections,
+    }
+}
+
+pub(crate) fn unresolved_items(backlog: &Backlog, release: &Release) -> UnresolvedItems {
+    let items = items_of(backlog, release)
+        .into_iter()
+        .filter(|item| item.status() != Status::Done)
+        .map(ReleaseItem::new)
+        .collect();
+    UnresolvedItems {
+        release: release.clone(),
+        items,
+    }
+}
+
+/// mark the release as shipped, which is refused while any item is unresolved.
+pub(crate) fn ship(backlog: &Backlog, release: &mut Release) -> BacklogResult<()> {
+    if release.released {
+        return Err(BacklogError::type_error(format!(
+            "the release has already been shipped, {}",
+            release.name
+        )));
+    }
+    let unresolved = unresolved_items(backlog, release).items.len();
+    if unresolved > 0 {
+        return Err(BacklogError::type_error(format!(
+            "the release has {} unresolved items, {}",
+            unresolved, release.name
+        )));
+    }
+    release.released = true;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AddItem, BacklogFixture, ProgressableFromCollection, Story, Task};
+
+    fn fixture() -> (Backlog, Uuid, Uuid) {
+        let mut backlog = Backlog::empty_items();
+        let target = NaiveDate::from_ymd_opt(2022, 4, 1).unwrap();
+        backlog
+            .add_release(Release::new("1.0", target).unwrap())
+            .unwrap();
+
+        let story = Story::new("Login page", None, None);
+        let task = Task::new("Upgrade the database", None, None);
+        let (story_id, task_id) = (story.id(), task.id());
+        backlog.add_item(Box::new(story));
+        backlog.add_item(Box::new(task));
+        backlog.add_item(Box::new(Story::new("Not in the release", None, None)));
+        backlog.set_fix_version(&story_id, Some("1.0")).unwrap();
+        backlog.set_fix_version(&task_id, Some("1.0")).unwrap();
+        (backlog, story_id, task_id)
+    }
+
+    #[test]
+    fn test_release_notes() {
+        let (mut backlog, story_id, task_id) = fixture();
+        backlog.change_item_status(&story_id, Status::Done).unwrap();
+        backlog.change_item_status(&task_id, Status::Done).unwrap();
+
+        let notes = backlog.release_notes("1.0").unwrap();
+        assert_eq!(
+            notes.to_string(),
+            "# 1.0 (2022-04-01)\n\n## Story\n\n- Login page\n\n## Task\n\n- Upgrade the database"
+        );
+    }
+
+    #[test]
+    fn test_ship_release() {
+        let (mut backlog, story_id, task_id) = fixture();
+        backlog.change_item_status(&story_id, Status::Done).unwrap();
+
+        let unresolved = backlog.unresolved_items("1.0").unwrap();
+        assert_eq!(unresolved.items().len(), 1);
+        assert_eq!(unresolved.items()[0].id(), task_id);
+        assert!(backlog.ship_release("1.0").is_err());
+
+        backlog.set_fix_version(&task_id, None).unwrap();
+        backlog.ship_release("1.0").unwrap();
+        assert!(backlog.release("1.0").unwrap().is_released());
+        // no item can be added to the shipped release.
+        assert!(backlog.set_fix_version(&task_id, Some("1.0")).is_err());
+        assert!(backlog.ship_release("1.0").is_err());
+    }
+}
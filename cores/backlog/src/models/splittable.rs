@@ -0,0 +1,52 @@
+use uuid::Uuid;
+
+use crate::{BacklogError, BacklogItem, BacklogResult};
+
+/// How many items the item is split into.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SplitInto {
+    /// the children are titled after the original, like "title (1/3)".
+    Count(usize),
+    Titles(Vec<String>),
+}
+
+impl SplitInto {
+    /// return the titles of the children.
+    pub fn titles(&self, original: &str) -> BacklogResult<Vec<String>> {
+        let titles = match self {
+            Self::Count(count) => (1..=*count)
+                .map(|i| format!("{} ({}/{})", original, i, count))
+                .collect(),
+            Self::Titles(titles) => titles.clone(),
+        };
+        if titles.len() < 2 {
+            return Err(BacklogError::type_error(
+                "the item must be split into at least 2 items",
+            ));
+        }
+        Ok(titles)
+    }
+}
+
+/// Represents that the item can be split into smaller items.
+///
+/// By default, the item can not be split.
+pub trait Splittable {
+    /// make the children that inherit the item.
+    fn split(&self, _into: &SplitInto) -> BacklogResult<Vec<Box<dyn BacklogItem>>> {
+        Err(BacklogError::type_error("the item can not be split"))
+    }
+
+    /// the items which this item has been split into.
+    fn split_into(&self) -> &[Uuid] {
+        &[]
+    }
+
+    /// mark the item as split into the children.
+    fn mark_split(&mut self, _children: Vec<Uuid>) {}
+
+    /// whether the item has been split.
+    fn is_split(&self) -> bool {
+        !self.split_into().is_empty()
+    }
+}
@@ -0,0 +1,81 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{BacklogError, BacklogResult, Entity, Team};
+
+/// It means the time-box that the team works on.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sprint {
+    id: Uuid,
+    name: String,
+    team: Team,
+    start: NaiveDate,
+    end: NaiveDate,
+}
+
+impl Sprint {
+    pub fn new(name: &str, team: Team, start: NaiveDate, end: NaiveDate) -> BacklogResult<Self> {
+        if end < start {
+            return Err(BacklogError::type_error(
+                "Sprint must end on or after the start date",
+            ));
+        }
+        Ok(Self {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            team,
+            start,
+            end,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn team(&self) -> &Team {
+        &self.team
+    }
+
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    /// whether the sprint has been completed on the specific day.
+    pub fn is_completed(&self, today: NaiveDate) -> bool {
+        self.end < today
+    }
+}
+
+impl Entity for Sprint {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let start = NaiveDate::from_ymd_opt(2022, 1, 3).unwrap();
+        let end = NaiveDate::from_ymd_opt(2022, 1, 14).unwrap();
+        assert!(Sprint::new("sprint 1", Team::new("team"), start, end).is_ok());
+        assert!(Sprint::new("sprint 1", Team::new("team"), end, start).is_err());
+    }
+
+    #[test]
+    fn test_is_completed() {
+        let start = NaiveDate::from_ymd_opt(2022, 1, 3).unwrap();
+        let end = NaiveDate::from_ymd_opt(2022, 1, 14).unwrap();
+        let sprint = Sprint::new("sprint 1", Team::new("team"), start, end).unwrap();
+        assert!(!sprint.is_completed(end));
+        assert!(sprint.is_completed(NaiveDate::from_ymd_opt(2022, 1, 15).unwrap()));
+    }
+}
@@ -1,7 +1,12 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::{Assignable, Assignee, BacklogItem, Entity, Estimatable, StoryPoint, Title};
+use crate::{
+    Assignable, Assignee, Attachable, Attachment, BacklogError, BacklogItem, BacklogResult,
+    Describable, Entity, Estimatable, Historical, ItemEvent, Plannable, Prioritizable, Priority,
+    Progressable, Rank, Relatable, Releasable, SplitInto, Splittable, Status, StoryPoint, Title,
+    Watchable,
+};
 
 /// It means the user story.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -11,6 +16,33 @@ pub struct Story {
     title: Title,
     point: Option<StoryPoint>,
     assignee: Option<Assignee>,
+    #[serde(default)]
+    status: Status,
+    #[serde(default)]
+    sprint: Option<Uuid>,
+    #[serde(default)]
+    history: Vec<ItemEvent>,
+    #[serde(default)]
+    priority: Priority,
+    #[serde(default)]
+    rank: Rank,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    epic: Option<Uuid>,
+    /// the related items.
+    #[serde(default)]
+    links: Vec<Uuid>,
+    #[serde(default)]
+    split_from: Option<Uuid>,
+    #[serde(default)]
+    split_into: Vec<Uuid>,
+    #[serde(default)]
+    fix_version: Option<Uuid>,
+    #[serde(default)]
+    watchers: Vec<Assignee>,
+    #[serde(default)]
+    attachments: Vec<Attachment>,
 }
 
 impl Story {
@@ -20,8 +52,26 @@ impl Story {
             title: Title::new(title),
             point,
             assignee,
+            status: Status::default(),
+            sprint: None,
+            history: Vec::new(),
+            priority: Priority::default(),
+            rank: Rank::default(),
+            labels: Vec::new(),
+            epic: None,
+            links: Vec::new(),
+            split_from: None,
+            split_into: Vec::new(),
+            fix_version: None,
+            watchers: Vec::new(),
+            attachments: Vec::new(),
         }
     }
+
+    /// the story which this story has been split from.
+    pub fn split_from(&self) -> Option<Uuid> {
+        self.split_from
+    }
 }
 
 #[typetag::serde]
@@ -33,14 +83,171 @@ impl Entity for Story {
     }
 }
 
+impl Describable for Story {
+    fn kind(&self) -> &'static str {
+        "Story"
+    }
+
+    fn title(&self) -> &Title {
+        &self.title
+    }
+}
+
 impl Assignable for Story {
+    fn assignee(&self) -> Option<&Assignee> {
+        self.assignee.as_ref()
+    }
+
     fn mut_assignee(&mut self) -> &mut Option<Assignee> {
         &mut self.assignee
     }
 }
 
 impl Estimatable for Story {
+    fn point(&self) -> Option<&StoryPoint> {
+        self.point.as_ref()
+    }
+
     fn mut_point(&mut self) -> &mut Option<StoryPoint> {
         &mut self.point
     }
 }
+
+impl Progressable for Story {
+    fn status(&self) -> Status {
+        self.status
+    }
+
+    fn mut_status(&mut self) -> &mut Status {
+        &mut self.status
+    }
+}
+
+impl Plannable for Story {
+    fn sprint(&self) -> Option<Uuid> {
+        self.sprint
+    }
+
+    fn mut_sprint(&mut self) -> &mut Option<Uuid> {
+        &mut self.sprint
+    }
+}
+
+impl Prioritizable for Story {
+    fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    fn mut_priority(&mut self) -> &mut Priority {
+        &mut self.priority
+    }
+
+    fn rank(&self) -> &Rank {
+        &self.rank
+    }
+
+    fn mut_rank(&mut self) -> &mut Rank {
+        &mut self.rank
+    }
+}
+
+impl Releasable for Story {
+    fn fix_version(&self) -> Option<Uuid> {
+        self.fix_version
+    }
+
+    fn mut_fix_version(&mut self) -> &mut Option<Uuid> {
+        &mut self.fix_version
+    }
+}
+
+impl Relatable for Story {
+    fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    fn epic(&self) -> Option<Uuid> {
+        self.epic
+    }
+
+    fn links(&self) -> &[Uuid] {
+        &self.links
+    }
+
+    fn mut_labels(&mut self) -> Option<&mut Vec<String>> {
+        Some(&mut self.labels)
+    }
+
+    fn mut_epic(&mut self) -> Option<&mut Option<Uuid>> {
+        Some(&mut self.epic)
+    }
+
+    fn mut_links(&mut self) -> Option<&mut Vec<Uuid>> {
+        Some(&mut self.links)
+    }
+}
+
+impl Splittable for Story {
+    fn split(&self, into: &SplitInto) -> BacklogResult<Vec<Box<dyn BacklogItem>>> {
+        if self.is_split() {
+            return Err(BacklogError::type_error(format!(
+                "the story has already been split, id: {}",
+                self.id
+            )));
+        }
+        let children = into
+            .titles(self.title.value())?
+            .iter()
+            .map(|title| {
+                let mut child = Story::new(title, None, self.assignee.clone());
+                child.priority = self.priority;
+                child.labels = self.labels.clone();
+                child.epic = self.epic;
+                child.links = self.links.clone();
+                child.split_from = Some(self.id);
+                child.fix_version = self.fix_version;
+                child.watchers = self.watchers.clone();
+                Box::new(child) as Box<dyn BacklogItem>
+            })
+            .collect();
+        Ok(children)
+    }
+
+    fn split_into(&self) -> &[Uuid] {
+        &self.split_into
+    }
+
+    fn mark_split(&mut self, children: Vec<Uuid>) {
+        self.split_into = children;
+    }
+}
+
+impl Watchable for Story {
+    fn watchers(&self) -> &[Assignee] {
+        &self.watchers
+    }
+
+    fn mut_watchers(&mut self) -> &mut Vec<Assignee> {
+        &mut self.watchers
+    }
+}
+
+impl Attachable for Story {
+    fn attachments(&self) -> &[Attachment] {
+        &self.attachments
+    }
+
+    fn mut_attachments(&mut self) -> &mut Vec<Attachment> {
+        &mut self.attachments
+    }
+}
+
+impl Historical for Story {
+    fn history(&self) -> &[ItemEvent] {
+        &self.history
+    }
+
+    fn mut_history(&mut self) -> &mut Vec<ItemEvent> {
+        &mut self.history
+    }
+}
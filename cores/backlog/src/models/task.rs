@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::{Assignable, Assignee, BacklogItem, Entity, Estimatable, StoryPoint, Title};
+use crate::{
+    Assignable, Assignee, Attachable, Attachment, BacklogItem, Describable, Entity, Estimatable,
+    Historical, ItemEvent, Plannable, Prioritizable, Priority, Progressable, Rank, Relatable,
+    Releasable, Splittable, Status, StoryPoint, Title, Watchable,
+};
 
 /// It means the task.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -10,6 +14,22 @@ pub struct Task {
     title: Title,
     point: Option<StoryPoint>,
     assignee: Option<Assignee>,
+    #[serde(default)]
+    status: Status,
+    #[serde(default)]
+    sprint: Option<Uuid>,
+    #[serde(default)]
+    history: Vec<ItemEvent>,
+    #[serde(default)]
+    priority: Priority,
+    #[serde(default)]
+    rank: Rank,
+    #[serde(default)]
+    fix_version: Option<Uuid>,
+    #[serde(default)]
+    watchers: Vec<Assignee>,
+    #[serde(default)]
+    attachments: Vec<Attachment>,
 }
 
 impl Task {
@@ -19,6 +39,14 @@ impl Task {
             title: Title::new(title),
             point,
             assignee,
+            status: Status::default(),
+            sprint: None,
+            history: Vec::new(),
+            priority: Priority::default(),
+            rank: Rank::default(),
+            fix_version: None,
+            watchers: Vec::new(),
+            attachments: Vec::new(),
         }
     }
 }
@@ -32,14 +60,114 @@ impl Entity for Task {
     }
 }
 
+impl Describable for Task {
+    fn kind(&self) -> &'static str {
+        "Task"
+    }
+
+    fn title(&self) -> &Title {
+        &self.title
+    }
+}
+
 impl Assignable for Task {
+    fn assignee(&self) -> Option<&Assignee> {
+        self.assignee.as_ref()
+    }
+
     fn mut_assignee(&mut self) -> &mut Option<Assignee> {
         &mut self.assignee
     }
 }
 
 impl Estimatable for Task {
+    fn point(&self) -> Option<&StoryPoint> {
+        self.point.as_ref()
+    }
+
     fn mut_point(&mut self) -> &mut Option<StoryPoint> {
         &mut self.point
     }
 }
+
+impl Progressable for Task {
+    fn status(&self) -> Status {
+        self.status
+    }
+
+    fn mut_status(&mut self) -> &mut Status {
+        &mut self.status
+    }
+}
+
+impl Plannable for Task {
+    fn sprint(&self) -> Option<Uuid> {
+        self.sprint
+    }
+
+    fn mut_sprint(&mut self) -> &mut Option<Uuid> {
+        &mut self.sprint
+    }
+}
+
+impl Prioritizable for Task {
+    fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    fn mut_priority(&mut self) -> &mut Priority {
+        &mut self.priority
+    }
+
+    fn rank(&self) -> &Rank {
+        &self.rank
+    }
+
+    fn mut_rank(&mut self) -> &mut Rank {
+        &mut self.rank
+    }
+}
+
+impl Releasable for Task {
+    fn fix_version(&self) -> Option<Uuid> {
+        self.fix_version
+    }
+
+    fn mut_fix_version(&mut self) -> &mut Option<Uuid> {
+        &mut self.fix_version
+    }
+}
+
+impl Relatable for Task {}
+
+impl Splittable for Task {}
+
+impl Watchable for Task {
+    fn watchers(&self) -> &[Assignee] {
+        &self.watchers
+    }
+
+    fn mut_watchers(&mut self) -> &mut Vec<Assignee> {
+        &mut self.watchers
+    }
+}
+
+impl Attachable for Task {
+    fn attachments(&self) -> &[Attachment] {
+        &self.attachments
+    }
+
+    fn mut_attachments(&mut self) -> &mut Vec<Attachment> {
+        &mut self.attachments
+    }
+}
+
+impl Historical for Task {
+    fn history(&self) -> &[ItemEvent] {
+        &self.history
+    }
+
+    fn mut_history(&mut self) -> &mut Vec<ItemEvent> {
+        &mut self.history
+    }
+}
@@ -0,0 +1,240 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{Backlog, BacklogError, BacklogResult, Entity, Sprint, Team};
+
+/// The velocity of all teams.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct VelocityReport {
+    window: usize,
+    teams: Vec<TeamVelocity>,
+}
+
+/// The velocity of the team.
+///
+/// `average` and `std_dev` are calculated over the last `window` completed sprints.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TeamVelocity {
+    team: Team,
+    sprints: Vec<SprintVelocity>,
+    average: f64,
+    std_dev: f64,
+}
+
+/// The completed story points of the sprint.
+///
+/// `rolling_average` and `rolling_std_dev` are calculated over
+/// the last `window` sprints that end with this sprint.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SprintVelocity {
+    sprint: Uuid,
+    name: String,
+    end: NaiveDate,
+    points: u32,
+    rolling_average: f64,
+    rolling_std_dev: f64,
+}
+
+impl VelocityReport {
+    /// calculate the velocity from the sprints completed before `today`.
+    pub fn new(backlog: &Backlog, window: usize, today: NaiveDate) -> BacklogResult<Self> {
+        if window == 0 {
+            return Err(BacklogError::type_error(
+                "the window of velocity must be greater than 0",
+            ));
+        }
+
+        let mut sprints: Vec<&Sprint> = backlog
+            .sprints()
+            .values()
+            .filter(|sprint| sprint.is_completed(today))
+            .collect();
+        sprints.sort_by_key(|sprint| (sprint.team().clone(), sprint.end()));
+
+        let mut teams: Vec<TeamVelocity> = Vec::new();
+        for sprint in sprints {
+            let points = backlog.completed_points(&sprint.id());
+            match teams.last_mut() {
+                Some(team) if &team.team == sprint.team() => team.push(sprint, points, window),
+                _ => {
+                    let mut team = TeamVelocity::new(sprint.team().clone());
+                    team.push(sprint, points, window);
+                    teams.push(team);
+                }
+            }
+        }
+        Ok(Self { window, teams })
+    }
+
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    pub fn teams(&self) -> &[TeamVelocity] {
+        &self.teams
+    }
+}
+
+impl TeamVelocity {
+    fn new(team: Team) -> Self {
+        Self {
+            team,
+            sprints: Vec::new(),
+            average: 0.0,
+            std_dev: 0.0,
+        }
+    }
+
+    fn push(&mut self, sprint: &Sprint, points: u32, window: usize) {
+        let mut samples: Vec<u32> = self
+            .sprints
+            .iter()
+            .rev()
+            .take(window - 1)
+            .map(|sprint| sprint.points)
+            .collect();
+        samples.push(points);
+        let (average, std_dev) = statistics(&samples);
+
+        self.average = average;
+        self.std_dev = std_dev;
+        self.sprints.push(SprintVelocity {
+            sprint: sprint.id(),
+            name: sprint.name().to_string(),
+            end: sprint.end(),
+            points,
+            rolling_average: average,
+            rolling_std_dev: std_dev,
+        });
+    }
+
+    pub fn team(&self) -> &Team {
+        &self.team
+    }
+
+    pub fn sprints(&self) -> &[SprintVelocity] {
+        &self.sprints
+    }
+
+    pub fn average(&self) -> f64 {
+        self.average
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        self.std_dev
+    }
+}
+
+impl SprintVelocity {
+    pub fn sprint(&self) -> Uuid {
+        self.sprint
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    pub fn points(&self) -> u32 {
+        self.points
+    }
+
+    pub fn rolling_average(&self) -> f64 {
+        self.rolling_average
+    }
+
+    pub fn rolling_std_dev(&self) -> f64 {
+        self.rolling_std_dev
+    }
+}
+
+/// return the mean and the population standard deviation.
+fn statistics(samples: &[u32]) -> (f64, f64) {
+    if samples.is_empty() {
+        return (0.0, 0.0);
+    }
+    let n = samples.len() as f64;
+    let mean = samples.iter().map(|v| *v as f64).sum::<f64>() / n;
+    let variance = samples
+        .iter()
+        .map(|v| (*v as f64 - mean).powi(2))
+        .sum::<f64>()
+        / n;
+    (mean, variance.sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::{AddItem, Change, Historical, Plannable, Progressable, Status, Story, StoryPoint};
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2022, 1, day).unwrap()
+    }
+
+    /// the story added and done on the first day of the sprint.
+    fn done_story(sprint: &Sprint, point: u8) -> Box<Story> {
+        let at = Utc.from_utc_datetime(&sprint.start().and_hms_opt(12, 0, 0).unwrap());
+        let mut story = Story::new("", Some(StoryPoint::new(point).unwrap()), None);
+        story.record_at(at, Change::Added);
+        story.plan(sprint.id());
+        story.change_status(Status::Done);
+        story.record_at(
+            at,
+            Change::StatusChanged {
+                from: Status::Todo,
+                to: Status::Done,
+            },
+        );
+        Box::new(story)
+    }
+
+    #[test]
+    fn test_velocity() {
+        let team = Team::new("team");
+        let sprint1 = Sprint::new("sprint 1", team.clone(), date(1), date(7)).unwrap();
+        let sprint2 = Sprint::new("sprint 2", team.clone(), date(8), date(14)).unwrap();
+        let sprint3 = Sprint::new("sprint 3", team, date(15), date(21)).unwrap();
+
+        let mut backlog = Backlog::new();
+        backlog.add_item(done_story(&sprint1, 3));
+        backlog.add_item(done_story(&sprint1, 5));
+        backlog.add_item(done_story(&sprint2, 2));
+        // not done yet
+        let mut story = Story::new("", Some(StoryPoint::new(13).unwrap()), None);
+        story.plan(sprint2.id());
+        backlog.add_item(Box::new(story));
+        // not completed yet
+        backlog.add_item(done_story(&sprint3, 8));
+        backlog.add_sprint(sprint1).unwrap();
+        backlog.add_sprint(sprint2).unwrap();
+        backlog.add_sprint(sprint3).unwrap();
+
+        let report = VelocityReport::new(&backlog, 2, date(20)).unwrap();
+        assert_eq!(report.teams().len(), 1);
+
+        let team = &report.teams()[0];
+        let points: Vec<u32> = team.sprints().iter().map(|s| s.points()).collect();
+        assert_eq!(points, vec![8, 2]);
+        assert_eq!(team.sprints()[0].rolling_average(), 8.0);
+        assert_eq!(team.average(), 5.0);
+        assert_eq!(team.std_dev(), 3.0);
+    }
+
+    #[test]
+    fn test_velocity_expect_fail() {
+        assert!(VelocityReport::new(&Backlog::new(), 0, date(1)).is_err());
+    }
+
+    #[test]
+    fn test_statistics() {
+        assert_eq!(statistics(&[]), (0.0, 0.0));
+        assert_eq!(statistics(&[2, 4, 4, 4, 5, 5, 7, 9]), (5.0, 2.0));
+    }
+}
@@ -0,0 +1,89 @@
+use uuid::Uuid;
+
+use crate::{AssignableFromCollection, Assignee, BacklogError, BacklogResult};
+
+/// Represents that users can watch the changes of the item.
+pub trait Watchable {
+    /// accessor
+    fn watchers(&self) -> &[Assignee];
+
+    /// mutable accessor
+    fn mut_watchers(&mut self) -> &mut Vec<Assignee>;
+
+    /// add the watcher unless the user already watches the item.
+    fn watch(&mut self, watcher: Assignee) {
+        if !self.watchers().contains(&watcher) {
+            self.mut_watchers().push(watcher);
+        }
+    }
+
+    /// remove the watcher.
+    fn unwatch(&mut self, watcher: &Assignee) {
+        self.mut_watchers().retain(|w| w != watcher);
+    }
+}
+
+/// The collection can search a specific item and change its watchers.
+///
+/// Only the users who can be assigned can watch items.
+pub trait WatchableFromCollection: AssignableFromCollection {
+    /// let the user watch the specific item.
+    fn watch_item(&mut self, id: &Uuid, watcher: Assignee) -> BacklogResult<()> {
+        self.validate_assignee(&watcher)?;
+        match self.find_by_id_mut(id) {
+            None => Err(BacklogError::not_found(format!(
+                "BacklogItem, id: {} does not found",
+                id
+            ))),
+            Some(item) => {
+                item.watch(watcher);
+                Ok(())
+            }
+        }
+    }
+
+    /// stop the user watching the specific item.
+    fn unwatch_item(&mut self, id: &Uuid, watcher: &Assignee) -> BacklogResult<()> {
+        match self.find_by_id_mut(id) {
+            None => Err(BacklogError::not_found(format!(
+                "BacklogItem, id: {} does not found",
+                id
+            ))),
+            Some(item) => {
+                item.unwatch(watcher);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_watchable {
+    use super::*;
+
+    struct TestWatchable {
+        watchers: Vec<Assignee>,
+    }
+
+    impl Watchable for TestWatchable {
+        fn watchers(&self) -> &[Assignee] {
+            &self.watchers
+        }
+
+        fn mut_watchers(&mut self) -> &mut Vec<Assignee> {
+            &mut self.watchers
+        }
+    }
+
+    #[test]
+    fn test_watch() {
+        let mut watchable = TestWatchable { watchers: vec![] };
+        watchable.watch(Assignee::new("alice"));
+        watchable.watch(Assignee::new("alice"));
+        watchable.watch(Assignee::new("bob"));
+        assert_eq!(watchable.watchers().len(), 2);
+
+        watchable.unwatch(&Assignee::new("alice"));
+        assert_eq!(watchable.watchers(), &[Assignee::new("bob")]);
+    }
+}
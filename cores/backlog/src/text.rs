@@ -0,0 +1,90 @@
+use std::collections::BTreeSet;
+
+/// Levenshtein distance between two strings.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+/// return the candidates which look like the specific name, ignoring case.
+pub(crate) fn suggest<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let name = name.to_lowercase();
+    let threshold = std::cmp::max(2, name.chars().count() / 3);
+    candidates
+        .filter(|candidate| edit_distance(&name, &candidate.to_lowercase()) <= threshold)
+        .collect()
+}
+
+/// words which carry no meaning to compare titles.
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "by", "for", "from", "in", "into", "of", "on", "or", "the", "to",
+    "with",
+];
+
+/// split the text into lowercase words, dropping punctuation and stop words.
+pub(crate) fn tokens(text: &str) -> BTreeSet<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty() && !STOP_WORDS.contains(token))
+        .map(|token| token.to_string())
+        .collect()
+}
+
+/// Jaccard similarity of the words of two texts, from 0.0 to 1.0.
+pub(crate) fn token_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (tokens(a), tokens(b));
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest(
+        a,
+        b,
+        expected,
+        case("", "", 0),
+        case("task", "task", 0),
+        case("story", "stroy", 2),
+        case("", "task", 4)
+    )]
+    fn test_edit_distance(a: &str, b: &str, expected: usize) {
+        assert_eq!(edit_distance(a, b), expected)
+    }
+
+    #[test]
+    fn test_suggest() {
+        let candidates = vec!["alice", "bob"];
+        assert_eq!(suggest("alcie", candidates.iter().copied()), vec!["alice"]);
+        assert!(suggest("carol", candidates.iter().copied()).is_empty());
+    }
+
+    #[rstest(
+        a,
+        b,
+        expected,
+        case("Fix the login bug", "fix login-bug", 1.0),
+        case("Fix login bug", "Fix signup bug", 0.5),
+        case("", "", 0.0),
+        case("Login", "Payment", 0.0)
+    )]
+    fn test_token_similarity(a: &str, b: &str, expected: f64) {
+        assert!((token_similarity(a, b) - expected).abs() < f64::EPSILON)
+    }
+}
@@ -9,15 +9,101 @@ impl Title {
     pub fn new(title: &str) -> Self {
         Self(title.to_string())
     }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Assignee(String);
 
 impl Assignee {
     pub fn new(name: &str) -> Self {
         Self(name.to_string())
     }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+/// It means the team that works on sprints.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Team(String);
+
+impl Team {
+    pub fn new(name: &str) -> Self {
+        Self(name.to_string())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+/// The progress of the item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Status {
+    Todo,
+    InProgress,
+    Done,
+}
+
+impl Default for Status {
+    fn default() -> Self {
+        Self::Todo
+    }
+}
+
+impl std::str::FromStr for Status {
+    type Err = BacklogError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Todo" => Ok(Self::Todo),
+            "InProgress" => Ok(Self::InProgress),
+            "Done" => Ok(Self::Done),
+            _ => Err(BacklogError::type_error(format!(
+                "the status must be Todo, InProgress or Done, {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// The priority of the item. `Highest` is ordered first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Priority {
+    Highest,
+    High,
+    Medium,
+    Low,
+    Lowest,
+}
+
+impl Default for Priority {
+    fn default() -> Self {
+        Self::Medium
+    }
+}
+
+impl std::str::FromStr for Priority {
+    type Err = BacklogError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Highest" => Ok(Self::Highest),
+            "High" => Ok(Self::High),
+            "Medium" => Ok(Self::Medium),
+            "Low" => Ok(Self::Low),
+            "Lowest" => Ok(Self::Lowest),
+            _ => Err(BacklogError::type_error(format!(
+                "the priority must be Highest, High, Medium, Low or Lowest, {}",
+                s
+            ))),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -33,6 +119,10 @@ impl StoryPoint {
             )),
         }
     }
+
+    pub fn value(&self) -> u8 {
+        self.0
+    }
 }
 
 #[cfg(test)]
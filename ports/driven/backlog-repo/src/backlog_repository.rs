@@ -1,6 +1,12 @@
-use backlog::Backlog;
+use std::sync::Arc;
 
-use crate::BacklogRepositoryResult;
+use backlog::{Backlog, BacklogItem, MigrationReport, Uuid};
+
+use crate::unit_of_work::changed;
+use crate::{
+    BacklogLock, BacklogRepositoryError, BacklogRepositoryResult, Backup, Committed, HistoryEntry,
+    LockMode, TransactionError, UnitOfWork, ATTEMPTS,
+};
 
 pub trait ProvideBacklogRepository {
     type Repository: BacklogRepository + Send + Sync;
@@ -10,11 +16,200 @@ pub trait ProvideBacklogRepository {
 
 #[async_trait::async_trait]
 pub trait BacklogRepository {
+    /// Lock the backlog against the other processes until the lock is dropped.
+    ///
+    /// Hold it through the whole read-modify-write cycle of a use case,
+    /// and never lock again while holding it.
+    /// The repository which is never shared needs no lock.
+    async fn lock(&self, _mode: LockMode) -> BacklogRepositoryResult<BacklogLock> {
+        Ok(BacklogLock::unlocked())
+    }
+
     /// Get the specific backlog.
     ///
     /// If backlog does not find, return the error.
     async fn get(&self) -> BacklogRepositoryResult<Backlog>;
 
-    /// Save the specific backlog.
+    /// Get the specific item of the backlog, or `None` if it is not found.
+    ///
+    /// The repository which can load an item alone overrides it.
+    async fn get_item(&self, id: &Uuid) -> BacklogRepositoryResult<Option<Box<dyn BacklogItem>>> {
+        Ok(self.get().await?.items().get(id).cloned())
+    }
+
+    /// Save the specific backlog, and advance its revision.
+    ///
+    /// If the stored revision differs from the loaded one, the backlog has been saved
+    /// by another in the meantime, and it fails with `Conflict` without saving.
     async fn save(&self, backlog: Backlog) -> BacklogRepositoryResult<()>;
+
+    /// Save the specific backlog as `save` does, as the result of `operation`, e.g. "Split the item".
+    ///
+    /// The repository which records each save, e.g. as a commit, overrides it to name the save.
+    async fn save_as(&self, backlog: Backlog, _operation: &str) -> BacklogRepositoryResult<()> {
+        self.save(backlog).await
+    }
+
+    /// Run `work` on the stored backlog as a unit of work named `operation`, under the exclusive lock.
+    ///
+    /// The backlog is saved as the result of `operation` only if `work` succeeds and changes it,
+    /// and it is rolled back, dropped unsaved, if `work` fails.
+    /// On the conflicting save, `work` is run again on the backlog got anew, up to `ATTEMPTS` times.
+    ///
+    /// It is run by `commit`, which is the one to override.
+    async fn transaction<T, E, F>(
+        &self,
+        operation: &str,
+        mut work: F,
+    ) -> Result<Committed<T>, TransactionError<E>>
+    where
+        Self: Sized,
+        F: FnMut(&mut Backlog) -> Result<T, E> + Send,
+        T: Send,
+        E: Send,
+    {
+        let mut outcome = None;
+        let committed = self
+            .commit(operation, &mut |backlog| {
+                let result = work(backlog);
+                let done = result.is_ok();
+                outcome = Some(result);
+                done
+            })
+            .await?;
+        match (committed, outcome) {
+            (Some(committed), Some(Ok(value))) => Ok(committed.with_value(value)),
+            (_, Some(Err(err))) => Err(TransactionError::Aborted(err)),
+            _ => unreachable!("the unit of work is committed without being run"),
+        }
+    }
+
+    /// Run `work` as `transaction` does, and return `None` if `work` rolls back.
+    ///
+    /// The repository which can do it better, e.g. in a transaction of its database, overrides it.
+    async fn commit(
+        &self,
+        operation: &str,
+        work: &mut UnitOfWork<'_>,
+    ) -> BacklogRepositoryResult<Option<Committed<()>>> {
+        let _lock = self.lock(LockMode::Exclusive).await?;
+        let mut attempt = 1;
+        loop {
+            let before = self.get().await?;
+            let mut backlog = before.clone();
+            if !work(&mut backlog) {
+                return Ok(None);
+            }
+            if !changed(&before, &backlog)? {
+                return Ok(Some(Committed::new((), before, backlog, false)));
+            }
+            match self.save_as(backlog.clone(), operation).await {
+                Err(BacklogRepositoryError::Conflict { .. }) if attempt < ATTEMPTS => attempt += 1,
+                Err(err) => return Err(err),
+                Ok(()) => {
+                    let revision = backlog.revision() + 1;
+                    let backlog = backlog.with_revision(revision);
+                    return Ok(Some(Committed::new((), before, backlog, true)));
+                }
+            }
+        }
+    }
+
+    /// Upgrade the stored backlog to the current format.
+    ///
+    /// With `dry_run`, only report what would change.
+    /// The repository which has never stored older formats is always up to date.
+    async fn migrate(&self, _dry_run: bool) -> BacklogRepositoryResult<MigrationReport> {
+        Ok(MigrationReport::up_to_date())
+    }
+
+    /// List the backups of the previous saves, the newest first.
+    ///
+    /// The repository which keeps no backup has none.
+    async fn backups(&self) -> BacklogRepositoryResult<Vec<Backup>> {
+        Ok(Vec::new())
+    }
+
+    /// Replace the backlog with the specific backup, and return it.
+    ///
+    /// The replaced backlog is kept as the newest backup, so the restore can be undone.
+    async fn restore(&self, generation: usize) -> BacklogRepositoryResult<Backlog> {
+        Err(BacklogRepositoryError::not_found(format!(
+            "the backup, generation: {}",
+            generation
+        )))
+    }
+
+    /// List the saves recorded in the history, the newest first, up to `limit`.
+    ///
+    /// The repository which keeps no history has none.
+    async fn history(&self, _limit: usize) -> BacklogRepositoryResult<Vec<HistoryEntry>> {
+        Ok(Vec::new())
+    }
+
+    /// Show how the stored backlog has changed from the revision `from` to `to`, as a patch.
+    ///
+    /// The revisions are the ids of the history.
+    async fn diff(&self, from: &str, _to: &str) -> BacklogRepositoryResult<String> {
+        Err(BacklogRepositoryError::not_found(format!(
+            "the revision, {}",
+            from
+        )))
+    }
+}
+
+/// The repository chosen at runtime, e.g. `Arc<dyn BacklogRepository + Send + Sync>`.
+#[async_trait::async_trait]
+impl<R> BacklogRepository for Arc<R>
+where
+    R: BacklogRepository + Send + Sync + ?Sized,
+{
+    async fn lock(&self, mode: LockMode) -> BacklogRepositoryResult<BacklogLock> {
+        (**self).lock(mode).await
+    }
+
+    async fn get(&self) -> BacklogRepositoryResult<Backlog> {
+        (**self).get().await
+    }
+
+    async fn get_item(&self, id: &Uuid) -> BacklogRepositoryResult<Option<Box<dyn BacklogItem>>> {
+        (**self).get_item(id).await
+    }
+
+    async fn save(&self, backlog: Backlog) -> BacklogRepositoryResult<()> {
+        (**self).save(backlog).await
+    }
+
+    async fn save_as(&self, backlog: Backlog, operation: &str) -> BacklogRepositoryResult<()> {
+        (**self).save_as(backlog, operation).await
+    }
+
+    /// `transaction` runs the unit of work in the way of the repository inside through it.
+    async fn commit(
+        &self,
+        operation: &str,
+        work: &mut UnitOfWork<'_>,
+    ) -> BacklogRepositoryResult<Option<Committed<()>>> {
+        (**self).commit(operation, work).await
+    }
+
+    async fn migrate(&self, dry_run: bool) -> BacklogRepositoryResult<MigrationReport> {
+        (**self).migrate(dry_run).await
+    }
+
+    async fn backups(&self) -> BacklogRepositoryResult<Vec<Backup>> {
+        (**self).backups().await
+    }
+
+    async fn restore(&self, generation: usize) -> BacklogRepositoryResult<Backlog> {
+        (**self).restore(generation).await
+    }
+
+    async fn history(&self, limit: usize) -> BacklogRepositoryResult<Vec<HistoryEntry>> {
+        (**self).history(limit).await
+    }
+
+    async fn diff(&self, from: &str, to: &str) -> BacklogRepositoryResult<String> {
+        (**self).diff(from, to).await
+    }
 }
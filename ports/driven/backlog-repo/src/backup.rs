@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+
+/// The previous generation of the saved backlog.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Backup {
+    generation: usize,
+    saved_at: DateTime<Utc>,
+    items: Option<usize>,
+}
+
+impl Backup {
+    pub fn new(generation: usize, saved_at: DateTime<Utc>, items: Option<usize>) -> Self {
+        Self {
+            generation,
+            saved_at,
+            items,
+        }
+    }
+
+    /// 1 is the newest.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn saved_at(&self) -> DateTime<Utc> {
+        self.saved_at
+    }
+
+    /// the number of the items, or `None` if the backup can not be read.
+    pub fn items(&self) -> Option<usize> {
+        self.items
+    }
+}
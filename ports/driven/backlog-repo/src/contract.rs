@@ -0,0 +1,260 @@
+//! The behaviour which every `BacklogRepository` shares.
+//!
+//! Each adaptor runs it from its own tests, e.g. `contract::run(|| repository()).await`.
+//! Run it on the multi-thread runtime, so that the concurrent access really runs in parallel.
+use std::sync::Arc;
+
+use backlog::{
+    AddItem, Assignee, Backlog, Entity, ItemKind, NaiveDate, ProgressableFromCollection, Release,
+    Sprint, Status, Story, StoryPoint, Task, Team, TeamProfile, User,
+};
+
+use crate::{BacklogRepository, BacklogRepositoryError, LockMode, TransactionError};
+
+/// The number of the tasks which save the backlog at the same time.
+const WRITERS: usize = 8;
+
+/// Run every check against a new repository made by `repository`.
+pub async fn run<R, F>(repository: F)
+where
+    R: BacklogRepository + Send + Sync + 'static,
+    F: Fn() -> R,
+{
+    get_new_backlog(&repository()).await;
+    save_and_get(&repository()).await;
+    round_trip_item_kinds(&repository()).await;
+    save_empty_backlog(&repository()).await;
+    save_expect_conflict(&repository()).await;
+    transaction(&repository()).await;
+    concurrent_access(Arc::new(repository())).await;
+    concurrent_transactions(Arc::new(repository())).await;
+}
+
+/// The repository which has never been saved has a new empty backlog.
+pub async fn get_new_backlog(repo: &impl BacklogRepository) {
+    let backlog = repo.get().await.unwrap();
+    assert!(backlog.items().is_empty());
+    assert_eq!(backlog.revision(), 0);
+}
+
+/// The saved backlog is got as it is, except its revision advances.
+pub async fn save_and_get(repo: &impl BacklogRepository) {
+    let mut backlog = repo.get().await.unwrap();
+    backlog.add_item(Box::new(Story::new("Login page", None, None)));
+    backlog.add_item(Box::new(Task::new("Upgrade the database", None, None)));
+    repo.save(backlog.clone()).await.unwrap();
+
+    let saved = repo.get().await.unwrap();
+    assert_eq!(saved.revision(), backlog.revision() + 1);
+    assert_eq!(saved.id(), backlog.id());
+    let titles: Vec<String> = saved
+        .items()
+        .values()
+        .map(|item| item.title().value().to_string())
+        .collect();
+    assert_eq!(titles, ["Login page", "Upgrade the database"]);
+}
+
+/// Every kind of item is got with all its fields, as are the sprints, releases and directory.
+pub async fn round_trip_item_kinds(repo: &impl BacklogRepository) {
+    let mut backlog = repo.get().await.unwrap();
+    let target = NaiveDate::from_ymd_opt(2022, 4, 1).unwrap();
+    backlog
+        .add_release(Release::new("1.0", target).unwrap())
+        .unwrap();
+    backlog
+        .add_user(User::new("alice", "Alice Liddell").unwrap())
+        .unwrap();
+    backlog
+        .add_team(TeamProfile::new("platform", "Platform").unwrap())
+        .unwrap();
+    backlog
+        .add_sprint(
+            Sprint::new(
+                "Sprint 1",
+                Team::new("platform"),
+                NaiveDate::from_ymd_opt(2022, 3, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2022, 3, 14).unwrap(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    for kind in ItemKind::all() {
+        let item = kind.new_item(
+            &format!("The {}", kind.name()),
+            Some(StoryPoint::new(3).unwrap()),
+            Some(Assignee::new("alice")),
+        );
+        let id = item.id();
+        backlog.add_item(item);
+        backlog.change_item_status(&id, Status::InProgress).unwrap();
+        backlog.set_fix_version(&id, Some("1.0")).unwrap();
+    }
+    repo.save(backlog.clone()).await.unwrap();
+
+    let saved = repo.get().await.unwrap();
+    let revision = backlog.revision() + 1;
+    assert_eq!(
+        serde_json::to_value(&saved).unwrap(),
+        serde_json::to_value(backlog.with_revision(revision)).unwrap()
+    );
+    let kinds: Vec<&str> = saved.items().values().map(|item| item.kind()).collect();
+    assert_eq!(kinds, ItemKind::names());
+}
+
+/// The backlog without any item is saved, and replaces the stored items.
+pub async fn save_empty_backlog(repo: &impl BacklogRepository) {
+    let backlog = repo.get().await.unwrap();
+    repo.save(backlog.clone()).await.unwrap();
+    let saved = repo.get().await.unwrap();
+    assert_eq!(saved.revision(), 1);
+    assert_eq!(saved.id(), backlog.id());
+    assert!(saved.items().is_empty());
+
+    let mut filled = saved.clone();
+    filled.add_item(Box::new(Story::new("Login page", None, None)));
+    repo.save(filled).await.unwrap();
+    let emptied = Backlog::new().with_revision(2);
+    repo.save(emptied.clone()).await.unwrap();
+    let saved = repo.get().await.unwrap();
+    assert_eq!(saved.revision(), 3);
+    assert_eq!(saved.id(), emptied.id());
+    assert!(saved.items().is_empty());
+}
+
+/// The backlog loaded before another save is refused, and the stored one is kept.
+pub async fn save_expect_conflict(repo: &impl BacklogRepository) {
+    let loaded = repo.get().await.unwrap();
+    let stale = loaded.clone();
+    repo.save(loaded).await.unwrap();
+
+    let mut stale = stale;
+    stale.add_item(Box::new(Story::new("Lost update", None, None)));
+    match repo.save(stale).await {
+        Err(BacklogRepositoryError::Conflict { loaded, stored }) => {
+            assert_eq!((loaded, stored), (0, 1));
+        }
+        other => panic!("expect a conflict, {:?}", other),
+    }
+    let stored: Backlog = repo.get().await.unwrap();
+    assert_eq!(stored.revision(), 1);
+    assert!(stored.items().is_empty());
+}
+
+/// The unit of work is saved only when it succeeds and changes the backlog.
+pub async fn transaction<R>(repo: &R)
+where
+    R: BacklogRepository + Send + Sync,
+{
+    let committed = repo
+        .transaction("Add the item", |backlog| {
+            backlog.add_item(Box::new(Story::new("Login page", None, None)));
+            Ok::<_, ()>(backlog.items().len())
+        })
+        .await
+        .unwrap();
+    assert!(committed.is_saved());
+    assert_eq!(*committed.value(), 1);
+    assert_eq!(committed.backlog().revision(), 1);
+    assert_eq!(committed.before().revision(), 0);
+
+    let rolled_back = repo
+        .transaction("Add the item", |backlog| {
+            backlog.add_item(Box::new(Story::new("Rolled back", None, None)));
+            Err::<(), _>("abort")
+        })
+        .await;
+    assert!(matches!(
+        rolled_back,
+        Err(TransactionError::Aborted("abort"))
+    ));
+
+    let unchanged = repo
+        .transaction("Read the revision", |backlog| {
+            Ok::<_, ()>(backlog.revision())
+        })
+        .await
+        .unwrap();
+    assert!(!unchanged.is_saved());
+    assert_eq!(unchanged.into_value(), 1);
+
+    let stored = repo.get().await.unwrap();
+    assert_eq!(stored.revision(), 1);
+    let titles: Vec<&str> = stored
+        .items()
+        .values()
+        .map(|item| item.title().value())
+        .collect();
+    assert_eq!(titles, ["Login page"]);
+}
+
+/// The writers at the same time lose no update, and the readers never see a torn backlog.
+///
+/// Each writer adds an item like a use case does, under the exclusive lock and
+/// retrying on the conflict, so the revision always equals the number of the items.
+pub async fn concurrent_access<R>(repo: Arc<R>)
+where
+    R: BacklogRepository + Send + Sync + 'static,
+{
+    let mut tasks = Vec::new();
+    for writer in 0..WRITERS {
+        let (writing, reading) = (repo.clone(), repo.clone());
+        tasks.push(tokio::spawn(async move {
+            loop {
+                let _lock = writing.lock(LockMode::Exclusive).await.unwrap();
+                let mut backlog = writing.get().await.unwrap();
+                let title = format!("Written by {}", writer);
+                backlog.add_item(Box::new(Story::new(&title, None, None)));
+                match writing.save(backlog).await {
+                    Err(BacklogRepositoryError::Conflict { .. }) => continue,
+                    saved => break saved.unwrap(),
+                }
+            }
+        }));
+        tasks.push(tokio::spawn(async move {
+            let _lock = reading.lock(LockMode::Shared).await.unwrap();
+            let backlog = reading.get().await.unwrap();
+            assert_eq!(backlog.items().len() as u64, backlog.revision());
+        }));
+    }
+    for task in tasks {
+        task.await.unwrap();
+    }
+
+    let backlog = repo.get().await.unwrap();
+    assert_eq!(backlog.revision(), WRITERS as u64);
+    let mut titles: Vec<String> = backlog
+        .items()
+        .values()
+        .map(|item| item.title().value().to_string())
+        .collect();
+    titles.sort();
+    let expected: Vec<String> = (0..WRITERS)
+        .map(|writer| format!("Written by {}", writer))
+        .collect();
+    assert_eq!(titles, expected);
+}
+
+/// The units of work at the same time are all committed, through the repository chosen at runtime.
+pub async fn concurrent_transactions(repo: Arc<dyn BacklogRepository + Send + Sync>) {
+    let mut tasks = Vec::new();
+    for writer in 0..WRITERS {
+        let repo = repo.clone();
+        tasks.push(tokio::spawn(async move {
+            repo.transaction("Add the item", |backlog| {
+                let title = format!("Committed by {}", writer);
+                backlog.add_item(Box::new(Story::new(&title, None, None)));
+                Ok::<_, ()>(())
+            })
+            .await
+            .unwrap()
+        }));
+    }
+    for task in tasks {
+        assert!(task.await.unwrap().is_saved());
+    }
+
+    let backlog = repo.get().await.unwrap();
+    assert_eq!(backlog.revision(), WRITERS as u64);
+    assert_eq!(backlog.items().len(), WRITERS);
+}
@@ -1,5 +1,6 @@
 use std::fmt::Debug;
 
+use backlog::BacklogError;
 use thiserror::Error;
 
 pub type BacklogRepositoryResult<T> = Result<T, BacklogRepositoryError>;
@@ -12,10 +13,68 @@ pub enum BacklogRepositoryError {
     Io(#[from] std::io::Error),
     #[error("BacklogRepositoryError: serialize/deserialize yaml occurred something, {0}")]
     Yaml(#[from] serde_yaml::Error),
+    #[error("BacklogRepositoryError: serialize/deserialize json occurred something, {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("BacklogRepositoryError: deserialize toml occurred something, {0}")]
+    Toml(#[from] toml::de::Error),
+    #[error("BacklogRepositoryError: serialize toml occurred something, {0}")]
+    TomlSerialize(#[from] toml::ser::Error),
+    #[error("BacklogRepositoryError: the database occurred something, {0}")]
+    Database(Box<dyn std::error::Error + Send + Sync>),
+    #[error("BacklogRepositoryError: the version control occurred something, {0}")]
+    VersionControl(Box<dyn std::error::Error + Send + Sync>),
+    #[error("BacklogRepositoryError: unknown format of the data file, {0}")]
+    UnknownFormat(String),
+    #[error("BacklogRepositoryError: migrate the document occurred something, {0}")]
+    Migration(#[from] BacklogError),
+    #[error(
+        "BacklogRepositoryError: the backlog has been saved by another, loaded revision: {loaded}, stored revision: {stored}"
+    )]
+    Conflict { loaded: u64, stored: u64 },
+    #[error("BacklogRepositoryError: backlog is locked by {}", holder(.pid))]
+    Locked { pid: Option<u32> },
+    #[error("BacklogRepositoryError: fail to parse the backlog{}, {msg}", at(.line, .column))]
+    Parse {
+        line: Option<usize>,
+        column: Option<usize>,
+        msg: String,
+    },
 }
 
 impl BacklogRepositoryError {
     pub fn not_found(msg: impl Into<String>) -> Self {
         Self::NotFound(msg.into())
     }
+
+    pub fn database(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Database(Box::new(err))
+    }
+
+    pub fn version_control(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::VersionControl(Box::new(err))
+    }
+
+    /// The line and the column start from 1.
+    pub fn parse(line: Option<usize>, column: Option<usize>, msg: impl Into<String>) -> Self {
+        Self::Parse {
+            line,
+            column,
+            msg: msg.into(),
+        }
+    }
+}
+
+fn holder(pid: &Option<u32>) -> String {
+    match pid {
+        Some(pid) => format!("pid {}", pid),
+        None => "another process".to_string(),
+    }
+}
+
+fn at(line: &Option<usize>, column: &Option<usize>) -> String {
+    match (line, column) {
+        (Some(line), Some(column)) => format!(" at line {} column {}", line, column),
+        (Some(line), None) => format!(" at line {}", line),
+        _ => String::new(),
+    }
 }
@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+
+/// A past save of the backlog, recorded by the repository which keeps the history.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistoryEntry {
+    id: String,
+    message: String,
+    author: String,
+    saved_at: DateTime<Utc>,
+}
+
+impl HistoryEntry {
+    pub fn new(id: &str, message: &str, author: &str, saved_at: DateTime<Utc>) -> Self {
+        Self {
+            id: id.to_string(),
+            message: message.to_string(),
+            author: author.to_string(),
+            saved_at,
+        }
+    }
+
+    /// the revision to refer it by, e.g. to diff.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// what has been changed, a line per change after the summary.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// the first line of the message.
+    pub fn summary(&self) -> &str {
+        self.message.lines().next().unwrap_or_default()
+    }
+
+    pub fn author(&self) -> &str {
+        &self.author
+    }
+
+    pub fn saved_at(&self) -> DateTime<Utc> {
+        self.saved_at
+    }
+}
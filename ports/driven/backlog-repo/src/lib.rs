@@ -1,5 +1,15 @@
 mod backlog_repository;
+mod backup;
+#[cfg(feature = "contract")]
+pub mod contract;
 mod error;
+mod history;
+mod lock;
+mod unit_of_work;
 
 pub use backlog_repository::{BacklogRepository, ProvideBacklogRepository};
+pub use backup::Backup;
 pub use error::{BacklogRepositoryError, BacklogRepositoryResult};
+pub use history::HistoryEntry;
+pub use lock::{BacklogLock, LockMode};
+pub use unit_of_work::{changed, Committed, TransactionError, UnitOfWork, ATTEMPTS};
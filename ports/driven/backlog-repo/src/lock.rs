@@ -0,0 +1,33 @@
+/// How the backlog is locked through a use case.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LockMode {
+    /// for reads, which can run together.
+    Shared,
+    /// for the read-modify-write cycle of mutations.
+    Exclusive,
+}
+
+/// The lock of the backlog, which is released when it is dropped.
+pub struct BacklogLock(Option<Box<dyn Send + Sync>>);
+
+impl BacklogLock {
+    /// hold the guard of the repository until the lock is dropped.
+    pub fn new(guard: impl Send + Sync + 'static) -> Self {
+        Self(Some(Box::new(guard)))
+    }
+
+    /// The lock of the repository which does not need any lock.
+    pub fn unlocked() -> Self {
+        Self(None)
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+impl std::fmt::Debug for BacklogLock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BacklogLock(locked: {})", self.is_locked())
+    }
+}
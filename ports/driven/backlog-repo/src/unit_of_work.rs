@@ -0,0 +1,116 @@
+use std::fmt::Display;
+
+use backlog::Backlog;
+
+use crate::{BacklogRepositoryError, BacklogRepositoryResult};
+
+/// How many times a unit of work is run against the conflicting saves.
+pub const ATTEMPTS: usize = 3;
+
+/// Whether the unit of work has changed the backlog.
+///
+/// Compare the documents, since the items can not be compared as they are.
+pub fn changed(before: &Backlog, after: &Backlog) -> BacklogRepositoryResult<bool> {
+    Ok(serde_json::to_value(before)? != serde_json::to_value(after)?)
+}
+
+/// The unit of work as `BacklogRepository::commit` runs it, which returns false to roll back.
+///
+/// It is erased from its value and error, so that `Arc<dyn BacklogRepository>` can run it
+/// in the way of the repository inside.
+pub type UnitOfWork<'a> = dyn FnMut(&mut Backlog) -> bool + Send + 'a;
+
+/// The unit of work which has been done, saved unless it changed nothing.
+#[derive(Debug)]
+pub struct Committed<T> {
+    value: T,
+    before: Backlog,
+    backlog: Backlog,
+    saved: bool,
+}
+
+impl<T> Committed<T> {
+    pub fn new(value: T, before: Backlog, backlog: Backlog, saved: bool) -> Self {
+        Self {
+            value,
+            before,
+            backlog,
+            saved,
+        }
+    }
+
+    /// what the work returned.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// the backlog as the work found it.
+    pub fn before(&self) -> &Backlog {
+        &self.before
+    }
+
+    /// the backlog as it is stored now, with its revision advanced if saved.
+    pub fn backlog(&self) -> &Backlog {
+        &self.backlog
+    }
+
+    /// false if the work changed nothing, and the save was skipped.
+    pub fn is_saved(&self) -> bool {
+        self.saved
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    pub fn into_backlog(self) -> Backlog {
+        self.backlog
+    }
+
+    pub fn into_parts(self) -> (T, Backlog) {
+        (self.value, self.backlog)
+    }
+
+    /// Replace what the work returned.
+    pub fn with_value<U>(self, value: U) -> Committed<U> {
+        Committed {
+            value,
+            before: self.before,
+            backlog: self.backlog,
+            saved: self.saved,
+        }
+    }
+}
+
+/// Why the unit of work was rolled back.
+#[derive(Debug)]
+pub enum TransactionError<E> {
+    /// the repository failed, e.g. the save conflicted `ATTEMPTS` times.
+    Repository(BacklogRepositoryError),
+    /// the work itself failed.
+    Aborted(E),
+}
+
+impl<E> From<BacklogRepositoryError> for TransactionError<E> {
+    fn from(err: BacklogRepositoryError) -> Self {
+        Self::Repository(err)
+    }
+}
+
+impl<E: Display> Display for TransactionError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Repository(err) => write!(f, "{}", err),
+            Self::Aborted(err) => write!(f, "TransactionError: the work is aborted, {}", err),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for TransactionError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Repository(err) => Some(err),
+            Self::Aborted(err) => Some(err),
+        }
+    }
+}
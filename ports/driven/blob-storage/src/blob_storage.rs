@@ -0,0 +1,24 @@
+use crate::BlobStorageResult;
+
+pub trait ProvideBlobStorage {
+    type BlobStorage: BlobStorage + Send + Sync;
+
+    fn blob_storage(&self) -> &Self::BlobStorage;
+}
+
+/// Store the contents addressed by their hash.
+#[async_trait::async_trait]
+pub trait BlobStorage {
+    /// Store the content under the hash.
+    ///
+    /// Storing the same hash again must keep the content.
+    async fn put(&self, hash: &str, content: Vec<u8>) -> BlobStorageResult<()>;
+
+    /// Get the content of the hash.
+    ///
+    /// If the content does not find, return the error.
+    async fn get(&self, hash: &str) -> BlobStorageResult<Vec<u8>>;
+
+    /// Delete the content of the hash, which is not an error if it does not exist.
+    async fn delete(&self, hash: &str) -> BlobStorageResult<()>;
+}
@@ -0,0 +1,19 @@
+use std::fmt::Debug;
+
+use thiserror::Error;
+
+pub type BlobStorageResult<T> = Result<T, BlobStorageError>;
+
+#[derive(Debug, Error)]
+pub enum BlobStorageError {
+    #[error("BlobStorageError: not found the content, {0}")]
+    NotFound(String),
+    #[error("BlobStorageError: IO occurred something, {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl BlobStorageError {
+    pub fn not_found(msg: impl Into<String>) -> Self {
+        Self::NotFound(msg.into())
+    }
+}
@@ -0,0 +1,5 @@
+mod blob_storage;
+mod error;
+
+pub use blob_storage::{BlobStorage, ProvideBlobStorage};
+pub use error::{BlobStorageError, BlobStorageResult};
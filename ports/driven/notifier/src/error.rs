@@ -0,0 +1,31 @@
+use std::fmt::Debug;
+
+use thiserror::Error;
+
+pub type NotifierResult<T> = Result<T, NotifierError>;
+
+#[derive(Debug, Error)]
+pub enum NotifierError {
+    #[error("NotifierError: IO occurred something, {0}")]
+    Io(#[from] std::io::Error),
+    #[error("NotifierError: serialize the notification occurred something, {0}")]
+    Serialize(String),
+    #[error("NotifierError: fail to deliver the notification, {0}")]
+    Delivery(String),
+    #[error("NotifierError: invalid configuration, {0}")]
+    Config(String),
+}
+
+impl NotifierError {
+    pub fn serialize(msg: impl Into<String>) -> Self {
+        Self::Serialize(msg.into())
+    }
+
+    pub fn delivery(msg: impl Into<String>) -> Self {
+        Self::Delivery(msg.into())
+    }
+
+    pub fn config(msg: impl Into<String>) -> Self {
+        Self::Config(msg.into())
+    }
+}
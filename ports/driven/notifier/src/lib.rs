@@ -0,0 +1,5 @@
+mod error;
+mod notifier;
+
+pub use error::{NotifierError, NotifierResult};
+pub use notifier::{Notifier, Notifiers, ProvideNotifier};
@@ -0,0 +1,61 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use backlog::Notification;
+
+use crate::NotifierResult;
+
+pub trait ProvideNotifier {
+    type Notifier: Notifier + Send + Sync;
+
+    fn notifier(&self) -> &Self::Notifier;
+}
+
+#[async_trait::async_trait]
+pub trait Notifier {
+    /// Deliver the notification to its recipients.
+    async fn notify(&self, notification: &Notification) -> NotifierResult<()>;
+}
+
+/// Deliver each notification through all notifiers.
+///
+/// Without any notifier, notifications are discarded.
+#[derive(Clone, Default)]
+pub struct Notifiers(Vec<Arc<dyn Notifier + Send + Sync>>);
+
+impl Notifiers {
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn with(mut self, notifier: impl Notifier + Send + Sync + 'static) -> Self {
+        self.0.push(Arc::new(notifier));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Debug for Notifiers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Notifiers({})", self.0.len())
+    }
+}
+
+#[async_trait::async_trait]
+impl Notifier for Notifiers {
+    /// Every notifier is tried even if another fails, and the first error is returned.
+    async fn notify(&self, notification: &Notification) -> NotifierResult<()> {
+        let mut result = Ok(());
+        for notifier in &self.0 {
+            if let Err(err) = notifier.notify(notification).await {
+                if result.is_ok() {
+                    result = Err(err);
+                }
+            }
+        }
+        result
+    }
+}
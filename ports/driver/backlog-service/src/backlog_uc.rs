@@ -1,41 +1,222 @@
 use backlog::{
-    AddItem, AssignableFromCollection, Assignee, Backlog, BacklogItem, EstimatableFromCollection,
-    StoryPoint, Uuid,
+    AddedItem, AssignableFromCollection, Assignee, AttachableFromCollection, Attachment, Backlog,
+    BacklogError, BacklogItem, Board, BoardConfig, BulkChange, BulkReport, Burndown, Directory,
+    Duplicate, DuplicateCluster, EstimatableFromCollection, Forecast, ForecastBasis, ItemOrder,
+    ItemsForecast, MigrationReport, NaiveDate, Placement, PrioritizableFromCollection, Priority,
+    RelatableFromCollection, Release, ReleaseNotes, Simulation, SplitInto, Sprint, StoryPoint,
+    Swimlane, TeamProfile, UnresolvedItems, User, Uuid, VelocityReport, WatchableFromCollection,
 };
-use backlog_repo::{BacklogRepository, ProvideBacklogRepository};
+use backlog_repo::{
+    BacklogRepository, Backup, Committed, HistoryEntry, LockMode, ProvideBacklogRepository,
+    TransactionError,
+};
+use blob_storage::{BlobStorage, ProvideBlobStorage};
 use eyre::WrapErr;
 use eyre_ext::WrapErrExt;
+use notifier::{Notifier, ProvideNotifier};
 
 use crate::{BusinessLogicError, IncommingResult, OutcommingError};
 
+fn item_not_found(id: &Uuid) -> BacklogError {
+    BacklogError::not_found(format!("BacklogItem, id: {} does not found", id))
+}
+
 #[async_trait::async_trait]
-pub trait BacklogUseCase: ProvideBacklogRepository {
+pub trait BacklogUseCase: ProvideBacklogRepository + ProvideNotifier + ProvideBlobStorage {
+    /// Change the backlog as a unit of work named `operation`, e.g. "Split the item",
+    /// and notify the watchers of the items changed by it.
+    ///
+    /// Nothing is saved, nor notified, if `work` fails or changes nothing.
+    /// Once saved, the command succeeds even if the watchers can not be notified,
+    /// and the failure is only logged, so that the client does not retry the saved command.
+    async fn update_and_notify<T, F>(&self, operation: &str, work: F) -> eyre::Result<Committed<T>>
+    where
+        F: FnMut(&mut Backlog) -> eyre::Result<T> + Send + 'async_trait,
+        T: Send + 'async_trait,
+    {
+        let committed = match self.provide().transaction(operation, work).await {
+            Ok(committed) => committed,
+            Err(TransactionError::Aborted(err)) => return Err(err),
+            Err(TransactionError::Repository(err)) => return Err(err).wrap::<OutcommingError>(),
+        };
+        for notification in committed.backlog().notifications(committed.before()) {
+            if let Err(err) = self.notifier().notify(&notification).await {
+                tracing::warn!(
+                    "the backlog has been saved, but fail to notify the watchers, {}",
+                    err
+                );
+            }
+        }
+        Ok(committed)
+    }
+
     async fn get_backlog(&self) -> eyre::Result<Backlog> {
         let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
         let backlog = repo.get().await.wrap::<OutcommingError>()?;
         Ok(backlog)
     }
 
-    /// Add item to backlog
-    async fn add_item(&self, cmd: impl AddItemCmd + 'async_trait) -> eyre::Result<Backlog> {
+    /// Upgrade the stored backlog to the current format, or only report it with dry run.
+    async fn migrate(&self, cmd: impl MigrateCmd + 'async_trait) -> eyre::Result<MigrationReport> {
+        let dry_run = cmd.dry_run()?;
+
+        let repo = self.provide();
+        let mode = if dry_run {
+            LockMode::Shared
+        } else {
+            LockMode::Exclusive
+        };
+        let _lock = repo.lock(mode).await.wrap::<OutcommingError>()?;
+        let report = repo.migrate(dry_run).await.wrap::<OutcommingError>()?;
+        Ok(report)
+    }
+
+    /// Copy the backlog into another repository, e.g. the data file in another format.
+    async fn convert<R>(&self, target: &R) -> eyre::Result<Backlog>
+    where
+        R: BacklogRepository + Send + Sync,
+    {
         let repo = self.provide();
-        let mut backlog = repo.get().await.wrap::<OutcommingError>()?;
-        backlog.add_item(cmd.item()?);
-        repo.save(backlog.clone()).await.wrap::<OutcommingError>()?;
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let backlog = repo.get().await.wrap::<OutcommingError>()?;
+
+        let _target_lock = target
+            .lock(LockMode::Exclusive)
+            .await
+            .wrap::<OutcommingError>()?;
+        let stored = target.get().await.wrap::<OutcommingError>()?;
+        target
+            .save_as(
+                backlog.clone().with_revision(stored.revision()),
+                "Convert the backlog",
+            )
+            .await
+            .wrap::<OutcommingError>()?;
         Ok(backlog)
     }
 
-    /// Assign the specific item to someone.
-    async fn assign_item(&self, cmd: impl AssignItemCmd + 'async_trait) -> eyre::Result<Backlog> {
+    /// List the backups of the previous saves, the newest first.
+    async fn get_backups(&self) -> eyre::Result<Vec<Backup>> {
         let repo = self.provide();
-        let mut backlog = repo.get().await.wrap::<OutcommingError>()?;
-        backlog
-            .assign_item(&cmd.id()?, cmd.assignee()?)
-            .wrap::<BusinessLogicError>()?;
-        repo.save(backlog.clone()).await.wrap::<OutcommingError>()?;
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let backups = repo.backups().await.wrap::<OutcommingError>()?;
+        Ok(backups)
+    }
+
+    /// List the saves recorded in the history of the repository, the newest first.
+    async fn get_history(
+        &self,
+        cmd: impl HistoryCmd + 'async_trait,
+    ) -> eyre::Result<Vec<HistoryEntry>> {
+        let limit = cmd.limit()?;
+
+        let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let history = repo.history(limit).await.wrap::<OutcommingError>()?;
+        Ok(history)
+    }
+
+    /// Show how the backlog has changed between the revisions of the history, as a patch.
+    async fn diff(&self, cmd: impl DiffCmd + 'async_trait) -> eyre::Result<String> {
+        let from = cmd.from()?;
+        let to = cmd.to()?;
+
+        let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let patch = repo.diff(&from, &to).await.wrap::<OutcommingError>()?;
+        Ok(patch)
+    }
+
+    /// Replace the backlog with the specific backup.
+    async fn restore_backup(&self, cmd: impl RestoreCmd + 'async_trait) -> eyre::Result<Backlog> {
+        let generation = cmd.generation()?;
+
+        let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Exclusive)
+            .await
+            .wrap::<OutcommingError>()?;
+        let backlog = repo.restore(generation).await.wrap::<OutcommingError>()?;
         Ok(backlog)
     }
 
+    /// Add item to backlog
+    ///
+    /// The item is refused when it looks like any existing item, unless it is forced.
+    async fn add_item(&self, cmd: impl AddItemCmd + 'async_trait) -> eyre::Result<AddedItem> {
+        let item = cmd.item()?;
+        let force = cmd.force()?;
+
+        let id = item.id();
+        let item = &item;
+        let committed = self
+            .update_and_notify("Add the item", move |backlog| {
+                if let Some(assignee) = item.assignee() {
+                    backlog
+                        .validate_assignee(assignee)
+                        .wrap::<BusinessLogicError>()?;
+                }
+                let duplicates = backlog
+                    .add_item_checked(item.clone(), force)
+                    .wrap::<BusinessLogicError>()?;
+                Ok(duplicates)
+            })
+            .await?;
+        let (duplicates, backlog) = committed.into_parts();
+        Ok(AddedItem::new(id, duplicates, backlog))
+    }
+
+    /// Group the items which look like each other.
+    async fn find_duplicates(
+        &self,
+        cmd: impl FindDuplicatesCmd + 'async_trait,
+    ) -> eyre::Result<Vec<DuplicateCluster>> {
+        let threshold = cmd.threshold()?.unwrap_or(Duplicate::THRESHOLD);
+
+        let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let backlog = repo.get().await.wrap::<OutcommingError>()?;
+        backlog
+            .duplicate_clusters(threshold)
+            .wrap::<BusinessLogicError>()
+    }
+
+    /// Assign the specific item to someone.
+    async fn assign_item(&self, cmd: impl AssignItemCmd + 'async_trait) -> eyre::Result<Backlog> {
+        let id = cmd.id()?;
+        let assignee = cmd.assignee()?;
+
+        let assignee = &assignee;
+        let committed = self
+            .update_and_notify("Assign the item", move |backlog| {
+                backlog
+                    .assign_item(&id, assignee.clone())
+                    .wrap::<BusinessLogicError>()?;
+                Ok(())
+            })
+            .await?;
+        Ok(committed.into_backlog())
+    }
+
     /// Estimate the specific item.
     async fn estimate_item(
         &self,
@@ -44,23 +225,671 @@ pub trait BacklogUseCase: ProvideBacklogRepository {
         let id = cmd.id().wrap_err("fail to get item id")?;
         let point = cmd.point().wrap_err("fail to get story point")?;
 
+        let point = &point;
+        let committed = self
+            .update_and_notify("Estimate the item", move |backlog| {
+                backlog
+                    .estimate_item(&id, point.clone())
+                    .wrap::<BusinessLogicError>()?;
+                Ok(())
+            })
+            .await?;
+        Ok(committed.into_backlog())
+    }
+
+    /// Change the priority of the specific item.
+    async fn prioritize_item(
+        &self,
+        cmd: impl PrioritizeItemCmd + 'async_trait,
+    ) -> eyre::Result<Backlog> {
+        let id = cmd.id()?;
+        let priority = cmd.priority()?;
+
+        let committed = self
+            .update_and_notify("Prioritize the item", move |backlog| {
+                backlog
+                    .prioritize_item(&id, priority)
+                    .wrap::<BusinessLogicError>()?;
+                Ok(())
+            })
+            .await?;
+        Ok(committed.into_backlog())
+    }
+
+    /// Move the specific item in the ranking.
+    async fn rank_item(&self, cmd: impl RankItemCmd + 'async_trait) -> eyre::Result<Backlog> {
+        let id = cmd.id()?;
+        let placement = cmd.placement()?;
+
+        let committed = self
+            .update_and_notify("Rank the item", move |backlog| {
+                backlog
+                    .rank_item(&id, placement)
+                    .wrap::<BusinessLogicError>()?;
+                Ok(())
+            })
+            .await?;
+        Ok(committed.into_backlog())
+    }
+
+    /// List the items in the specific order.
+    async fn list_items(
+        &self,
+        cmd: impl ListItemsCmd + 'async_trait,
+    ) -> eyre::Result<Vec<Box<dyn BacklogItem>>> {
+        let order = cmd.order()?;
+
         let repo = self.provide();
-        let mut backlog = repo
-            .get()
+        let _lock = repo
+            .lock(LockMode::Shared)
             .await
-            .wrap_msg::<OutcommingError>("fail to get backlog")?;
-        backlog
-            .estimate_item(&id, point)
+            .wrap::<OutcommingError>()?;
+        let backlog = repo.get().await.wrap::<OutcommingError>()?;
+        Ok(backlog.sorted_items(order))
+    }
+
+    /// Let the user watch the specific item.
+    async fn watch_item(&self, cmd: impl WatchItemCmd + 'async_trait) -> eyre::Result<Backlog> {
+        let id = cmd.id()?;
+        let watcher = cmd.watcher()?;
+
+        let watcher = &watcher;
+        let committed = self
+            .update_and_notify("Watch the item", move |backlog| {
+                backlog
+                    .watch_item(&id, watcher.clone())
+                    .wrap::<BusinessLogicError>()?;
+                Ok(())
+            })
+            .await?;
+        Ok(committed.into_backlog())
+    }
+
+    /// Stop the user watching the specific item.
+    async fn unwatch_item(&self, cmd: impl WatchItemCmd + 'async_trait) -> eyre::Result<Backlog> {
+        let id = cmd.id()?;
+        let watcher = cmd.watcher()?;
+
+        let watcher = &watcher;
+        let committed = self
+            .update_and_notify("Unwatch the item", move |backlog| {
+                backlog
+                    .unwatch_item(&id, watcher)
+                    .wrap::<BusinessLogicError>()?;
+                Ok(())
+            })
+            .await?;
+        Ok(committed.into_backlog())
+    }
+
+    /// Replace the labels of the specific item.
+    async fn label_item(&self, cmd: impl LabelItemCmd + 'async_trait) -> eyre::Result<Backlog> {
+        let id = cmd.id()?;
+        let labels = cmd.labels()?;
+
+        let labels = &labels;
+        let committed = self
+            .update_and_notify("Label the item", move |backlog| {
+                backlog
+                    .label_item(&id, labels.clone())
+                    .wrap::<BusinessLogicError>()?;
+                Ok(())
+            })
+            .await?;
+        Ok(committed.into_backlog())
+    }
+
+    /// Group the specific item under the epic.
+    async fn set_epic(&self, cmd: impl SetEpicCmd + 'async_trait) -> eyre::Result<Backlog> {
+        let id = cmd.id()?;
+        let epic = cmd.epic()?;
+
+        let committed = self
+            .update_and_notify("Group the item under the epic", move |backlog| {
+                backlog
+                    .set_item_epic(&id, epic)
+                    .wrap::<BusinessLogicError>()?;
+                Ok(())
+            })
+            .await?;
+        Ok(committed.into_backlog())
+    }
+
+    /// Replace the items which the specific item is linked to.
+    async fn link_item(&self, cmd: impl LinkItemCmd + 'async_trait) -> eyre::Result<Backlog> {
+        let id = cmd.id()?;
+        let links = cmd.links()?;
+
+        let links = &links;
+        let committed = self
+            .update_and_notify("Link the item", move |backlog| {
+                backlog
+                    .link_item(&id, links.clone())
+                    .wrap::<BusinessLogicError>()?;
+                Ok(())
+            })
+            .await?;
+        Ok(committed.into_backlog())
+    }
+
+    /// Attach the file to the specific item.
+    ///
+    /// The content is stored before the backlog refers to it.
+    async fn attach_item(
+        &self,
+        cmd: impl AttachItemCmd + 'async_trait,
+    ) -> eyre::Result<Attachment> {
+        let id = cmd.id()?;
+        let content = cmd.content()?;
+        let attachment = Attachment::new(&cmd.filename()?, &content, cmd.uploader()?)
+            .wrap::<BusinessLogicError>()?;
+
+        // the content is stored only for the attachment which the backlog accepts.
+        self.get_backlog()
+            .await?
+            .attach_item(&id, attachment.clone())
+            .wrap::<BusinessLogicError>()?;
+        self.blob_storage()
+            .put(attachment.hash(), content)
+            .await
+            .wrap::<OutcommingError>()?;
+
+        let attachment = &attachment;
+        self.update_and_notify("Attach the file to the item", move |backlog| {
+            backlog
+                .attach_item(&id, attachment.clone())
+                .wrap::<BusinessLogicError>()?;
+            Ok(())
+        })
+        .await?;
+        Ok(attachment.clone())
+    }
+
+    async fn get_attachments(
+        &self,
+        cmd: impl AttachmentsCmd + 'async_trait,
+    ) -> eyre::Result<Vec<Attachment>> {
+        let id = cmd.id()?;
+
+        let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let item = repo.get_item(&id).await.wrap::<OutcommingError>()?;
+        let item = item
+            .ok_or_else(|| item_not_found(&id))
+            .wrap::<BusinessLogicError>()?;
+        Ok(item.attachments().to_vec())
+    }
+
+    /// Get the metadata and the content of the specific attachment.
+    async fn download_attachment(
+        &self,
+        cmd: impl AttachmentCmd + 'async_trait,
+    ) -> eyre::Result<(Attachment, Vec<u8>)> {
+        let id = cmd.id()?;
+        let attachment = cmd.attachment()?;
+
+        let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let item = repo.get_item(&id).await.wrap::<OutcommingError>()?;
+        let item = item
+            .ok_or_else(|| item_not_found(&id))
             .wrap::<BusinessLogicError>()?;
-        repo.save(backlog.clone()).await.wrap::<OutcommingError>()?;
+        let attachment = item
+            .attachment(&attachment)
+            .cloned()
+            .ok_or_else(|| {
+                BacklogError::not_found(format!("Attachment, id: {} does not found", attachment))
+            })
+            .wrap::<BusinessLogicError>()?;
+        let content = self
+            .blob_storage()
+            .get(attachment.hash())
+            .await
+            .wrap::<OutcommingError>()?;
+        Ok((attachment, content))
+    }
+
+    /// Remove the specific attachment from the item.
+    ///
+    /// The content is deleted after the backlog is saved, unless another attachment refers to it.
+    async fn delete_attachment(
+        &self,
+        cmd: impl AttachmentCmd + 'async_trait,
+    ) -> eyre::Result<Backlog> {
+        let id = cmd.id()?;
+        let attachment = cmd.attachment()?;
+
+        let committed = self
+            .update_and_notify("Delete the attachment", move |backlog| {
+                backlog
+                    .detach_item(&id, &attachment)
+                    .wrap::<BusinessLogicError>()
+            })
+            .await?;
+        let (detached, backlog) = committed.into_parts();
+        if !backlog.refers_content(detached.hash()) {
+            self.blob_storage()
+                .delete(detached.hash())
+                .await
+                .wrap::<OutcommingError>()?;
+        }
         Ok(backlog)
     }
+
+    /// Split the specific item into new items.
+    async fn split_item(&self, cmd: impl SplitItemCmd + 'async_trait) -> eyre::Result<Backlog> {
+        let id = cmd.id()?;
+        let into = cmd.split_into()?;
+
+        let into = &into;
+        let committed = self
+            .update_and_notify("Split the item", move |backlog| {
+                backlog.split_item(&id, into).wrap::<BusinessLogicError>()?;
+                Ok(())
+            })
+            .await?;
+        Ok(committed.into_backlog())
+    }
+
+    /// Apply one change to many items in a single get/save cycle.
+    ///
+    /// If any id is unknown, nothing is changed.
+    async fn bulk_update(&self, cmd: impl BulkCmd + 'async_trait) -> eyre::Result<BulkReport> {
+        let ids = cmd.ids()?;
+        let change = cmd.change()?;
+
+        let (ids, change) = (&ids, &change);
+        let committed = self
+            .update_and_notify("Update the items in bulk", move |backlog| {
+                let report = backlog
+                    .apply_bulk(ids, change)
+                    .wrap::<BusinessLogicError>()?;
+                Ok(report)
+            })
+            .await?;
+        Ok(committed.into_value())
+    }
+
+    /// Report the velocity of each team.
+    async fn velocity(&self, cmd: impl VelocityCmd + 'async_trait) -> eyre::Result<VelocityReport> {
+        let window = cmd.window()?;
+
+        let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let backlog = repo.get().await.wrap::<OutcommingError>()?;
+        let report = backlog
+            .velocity(window, today())
+            .wrap::<BusinessLogicError>()?;
+        Ok(report)
+    }
+
+    /// Report the daily burndown of the specific sprint.
+    async fn burndown(&self, cmd: impl BurndownCmd + 'async_trait) -> eyre::Result<Burndown> {
+        let sprint = cmd.sprint()?;
+
+        let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let backlog = repo.get().await.wrap::<OutcommingError>()?;
+        let burndown = backlog
+            .burndown(&sprint, today())
+            .wrap::<BusinessLogicError>()?;
+        Ok(burndown)
+    }
+
+    /// Forecast when the remaining items will be completed.
+    async fn forecast(&self, cmd: impl ForecastCmd + 'async_trait) -> eyre::Result<Forecast> {
+        let basis = cmd.basis()?;
+        let simulation = cmd.simulation()?;
+
+        let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let backlog = repo.get().await.wrap::<OutcommingError>()?;
+        let forecast = backlog
+            .forecast(basis, simulation, today())
+            .wrap::<BusinessLogicError>()?;
+        Ok(forecast)
+    }
+
+    /// Forecast how many items will be done by the specific date.
+    async fn forecast_items(
+        &self,
+        cmd: impl ForecastItemsCmd + 'async_trait,
+    ) -> eyre::Result<ItemsForecast> {
+        let date = cmd.date()?;
+        let simulation = cmd.simulation()?;
+
+        let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let backlog = repo.get().await.wrap::<OutcommingError>()?;
+        let forecast = backlog
+            .forecast_items(date, simulation, today())
+            .wrap::<BusinessLogicError>()?;
+        Ok(forecast)
+    }
+
+    /// Get the users and teams.
+    async fn get_directory(&self) -> eyre::Result<Directory> {
+        let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let backlog = repo.get().await.wrap::<OutcommingError>()?;
+        Ok(backlog.directory().clone())
+    }
+
+    /// Add the user to the directory.
+    async fn add_user(&self, cmd: impl AddUserCmd + 'async_trait) -> eyre::Result<Directory> {
+        let user = cmd.user()?;
+
+        let user = &user;
+        let committed = self
+            .update_and_notify("Add the user", move |backlog| {
+                backlog
+                    .add_user(user.clone())
+                    .wrap::<BusinessLogicError>()?;
+                Ok(())
+            })
+            .await?;
+        Ok(committed.backlog().directory().clone())
+    }
+
+    /// Update the user. Renaming the user reassigns every item assigned to the user.
+    async fn update_user(&self, cmd: impl UpdateUserCmd + 'async_trait) -> eyre::Result<Directory> {
+        let handle = cmd.handle()?;
+        let display_name = cmd.display_name()?;
+        let active = cmd.active()?;
+        let new_handle = cmd.new_handle()?;
+
+        let (handle, display_name, new_handle) = (&handle, &display_name, &new_handle);
+        let committed = self
+            .update_and_notify("Update the user", move |backlog| {
+                backlog
+                    .update_user(handle, display_name.as_deref(), active)
+                    .wrap::<BusinessLogicError>()?;
+                if let Some(new_handle) = new_handle {
+                    backlog
+                        .rename_user(handle, new_handle)
+                        .wrap::<BusinessLogicError>()?;
+                }
+                Ok(())
+            })
+            .await?;
+        Ok(committed.backlog().directory().clone())
+    }
+
+    /// Add the team to the directory.
+    async fn add_team(&self, cmd: impl AddTeamCmd + 'async_trait) -> eyre::Result<Directory> {
+        let team = cmd.team()?;
+
+        let team = &team;
+        let committed = self
+            .update_and_notify("Add the team", move |backlog| {
+                backlog
+                    .add_team(team.clone())
+                    .wrap::<BusinessLogicError>()?;
+                Ok(())
+            })
+            .await?;
+        Ok(committed.backlog().directory().clone())
+    }
+
+    /// Update the team.
+    async fn update_team(&self, cmd: impl UpdateTeamCmd + 'async_trait) -> eyre::Result<Directory> {
+        let handle = cmd.handle()?;
+        let display_name = cmd.display_name()?;
+        let active = cmd.active()?;
+
+        let (handle, display_name) = (&handle, &display_name);
+        let committed = self
+            .update_and_notify("Update the team", move |backlog| {
+                backlog
+                    .update_team(handle, display_name.as_deref(), active)
+                    .wrap::<BusinessLogicError>()?;
+                Ok(())
+            })
+            .await?;
+        Ok(committed.backlog().directory().clone())
+    }
+
+    /// Show the board.
+    async fn board(&self, cmd: impl BoardCmd + 'async_trait) -> eyre::Result<Board> {
+        let swimlane = cmd.swimlane()?;
+
+        let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let backlog = repo.get().await.wrap::<OutcommingError>()?;
+        Ok(backlog.board(swimlane))
+    }
+
+    /// Move the specific item into the column of the board.
+    async fn move_item(&self, cmd: impl MoveItemCmd + 'async_trait) -> eyre::Result<Board> {
+        let id = cmd.id()?;
+        let column = cmd.column()?;
+
+        let column = &column;
+        let committed = self
+            .update_and_notify("Move the item on the board", move |backlog| {
+                backlog
+                    .move_item(&id, column)
+                    .wrap::<BusinessLogicError>()?;
+                Ok(())
+            })
+            .await?;
+        Ok(committed.backlog().board(None))
+    }
+
+    /// Replace the configuration of the board.
+    async fn configure_board(
+        &self,
+        cmd: impl ConfigureBoardCmd + 'async_trait,
+    ) -> eyre::Result<Board> {
+        let config = cmd.config()?;
+
+        let config = &config;
+        let committed = self
+            .update_and_notify("Configure the board", move |backlog| {
+                backlog.configure_board(config.clone());
+                Ok(())
+            })
+            .await?;
+        Ok(committed.backlog().board(None))
+    }
+
+    /// Get the sprints.
+    async fn get_sprints(&self) -> eyre::Result<Vec<Sprint>> {
+        let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let backlog = repo.get().await.wrap::<OutcommingError>()?;
+        Ok(backlog.sprints().values().cloned().collect())
+    }
+
+    /// Add the sprint.
+    async fn add_sprint(&self, cmd: impl AddSprintCmd + 'async_trait) -> eyre::Result<Sprint> {
+        let sprint = cmd.sprint()?;
+
+        let sprint = &sprint;
+        self.update_and_notify("Add the sprint", move |backlog| {
+            backlog
+                .add_sprint(sprint.clone())
+                .wrap::<BusinessLogicError>()?;
+            Ok(())
+        })
+        .await?;
+        Ok(sprint.clone())
+    }
+
+    /// Plan the specific item into the sprint.
+    async fn plan_item(&self, cmd: impl PlanItemCmd + 'async_trait) -> eyre::Result<Backlog> {
+        let id = cmd.id()?;
+        let sprint = cmd.sprint()?;
+
+        let committed = self
+            .update_and_notify("Plan the item", move |backlog| {
+                backlog
+                    .plan_item_into_sprint(&id, sprint)
+                    .wrap::<BusinessLogicError>()?;
+                Ok(())
+            })
+            .await?;
+        Ok(committed.into_backlog())
+    }
+
+    /// Get the releases.
+    async fn get_releases(&self) -> eyre::Result<Vec<Release>> {
+        let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let backlog = repo.get().await.wrap::<OutcommingError>()?;
+        Ok(backlog.releases().values().cloned().collect())
+    }
+
+    /// Add the release.
+    async fn add_release(&self, cmd: impl AddReleaseCmd + 'async_trait) -> eyre::Result<Release> {
+        let release = cmd.release()?;
+
+        let release = &release;
+        self.update_and_notify("Add the release", move |backlog| {
+            backlog
+                .add_release(release.clone())
+                .wrap::<BusinessLogicError>()?;
+            Ok(())
+        })
+        .await?;
+        Ok(release.clone())
+    }
+
+    /// Target the specific item at the release.
+    async fn set_fix_version(
+        &self,
+        cmd: impl SetFixVersionCmd + 'async_trait,
+    ) -> eyre::Result<Backlog> {
+        let id = cmd.id()?;
+        let release = cmd.release()?;
+
+        let release = &release;
+        let committed = self
+            .update_and_notify("Set the fix version of the item", move |backlog| {
+                backlog
+                    .set_fix_version(&id, release.as_deref())
+                    .wrap::<BusinessLogicError>()?;
+                Ok(())
+            })
+            .await?;
+        Ok(committed.into_backlog())
+    }
+
+    /// Make the release notes.
+    async fn release_notes(
+        &self,
+        cmd: impl ReleaseCmd + 'async_trait,
+    ) -> eyre::Result<ReleaseNotes> {
+        let name = cmd.name()?;
+
+        let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let backlog = repo.get().await.wrap::<OutcommingError>()?;
+        backlog.release_notes(&name).wrap::<BusinessLogicError>()
+    }
+
+    /// Report the items in the release which are not done yet.
+    async fn unresolved_items(
+        &self,
+        cmd: impl ReleaseCmd + 'async_trait,
+    ) -> eyre::Result<UnresolvedItems> {
+        let name = cmd.name()?;
+
+        let repo = self.provide();
+        let _lock = repo
+            .lock(LockMode::Shared)
+            .await
+            .wrap::<OutcommingError>()?;
+        let backlog = repo.get().await.wrap::<OutcommingError>()?;
+        backlog.unresolved_items(&name).wrap::<BusinessLogicError>()
+    }
+
+    /// Mark the release as shipped.
+    async fn ship_release(&self, cmd: impl ReleaseCmd + 'async_trait) -> eyre::Result<Release> {
+        let name = cmd.name()?;
+
+        let name = &name;
+        let committed = self
+            .update_and_notify("Ship the release", move |backlog| {
+                backlog.ship_release(name).wrap::<BusinessLogicError>()?;
+                let release = backlog.release(name).wrap::<BusinessLogicError>()?.clone();
+                Ok(release)
+            })
+            .await?;
+        Ok(committed.into_value())
+    }
+}
+
+fn today() -> backlog::NaiveDate {
+    chrono::Utc::now().naive_utc().date()
 }
 
 pub trait Command: Send {}
 
+pub trait MigrateCmd: Command {
+    /// only report what would change.
+    fn dry_run(&self) -> IncommingResult<bool>;
+}
+
+pub trait RestoreCmd: Command {
+    /// 1 is the newest backup.
+    fn generation(&self) -> IncommingResult<usize>;
+}
+
+pub trait HistoryCmd: Command {
+    /// the number of the saves to list at most.
+    fn limit(&self) -> IncommingResult<usize>;
+}
+
+pub trait DiffCmd: Command {
+    /// the older revision.
+    fn from(&self) -> IncommingResult<String>;
+    /// the newer revision.
+    fn to(&self) -> IncommingResult<String>;
+}
+
 pub trait AddItemCmd: Command {
     fn item(&self) -> IncommingResult<Box<dyn BacklogItem>>;
+    /// add the item even if it looks like any existing item.
+    fn force(&self) -> IncommingResult<bool>;
+}
+
+pub trait FindDuplicatesCmd: Command {
+    /// `None` means the default threshold.
+    fn threshold(&self) -> IncommingResult<Option<f64>>;
 }
 
 pub trait AssignItemCmd: Command {
@@ -73,106 +902,916 @@ pub trait EstimateItemCmd: Command {
     fn point(&self) -> IncommingResult<StoryPoint>;
 }
 
+pub trait PrioritizeItemCmd: Command {
+    fn id(&self) -> IncommingResult<Uuid>;
+    fn priority(&self) -> IncommingResult<Priority>;
+}
+
+pub trait RankItemCmd: Command {
+    fn id(&self) -> IncommingResult<Uuid>;
+    fn placement(&self) -> IncommingResult<Placement>;
+}
+
+pub trait ListItemsCmd: Command {
+    fn order(&self) -> IncommingResult<ItemOrder>;
+}
+
+pub trait WatchItemCmd: Command {
+    fn id(&self) -> IncommingResult<Uuid>;
+    fn watcher(&self) -> IncommingResult<Assignee>;
+}
+
+pub trait LabelItemCmd: Command {
+    fn id(&self) -> IncommingResult<Uuid>;
+    fn labels(&self) -> IncommingResult<Vec<String>>;
+}
+
+pub trait SetEpicCmd: Command {
+    fn id(&self) -> IncommingResult<Uuid>;
+    /// `None` means no epic.
+    fn epic(&self) -> IncommingResult<Option<Uuid>>;
+}
+
+pub trait LinkItemCmd: Command {
+    fn id(&self) -> IncommingResult<Uuid>;
+    fn links(&self) -> IncommingResult<Vec<Uuid>>;
+}
+
+pub trait AttachItemCmd: Command {
+    fn id(&self) -> IncommingResult<Uuid>;
+    fn filename(&self) -> IncommingResult<String>;
+    fn content(&self) -> IncommingResult<Vec<u8>>;
+    fn uploader(&self) -> IncommingResult<Assignee>;
+}
+
+pub trait AttachmentsCmd: Command {
+    fn id(&self) -> IncommingResult<Uuid>;
+}
+
+pub trait AttachmentCmd: Command {
+    fn id(&self) -> IncommingResult<Uuid>;
+    fn attachment(&self) -> IncommingResult<Uuid>;
+}
+
+pub trait SplitItemCmd: Command {
+    fn id(&self) -> IncommingResult<Uuid>;
+    fn split_into(&self) -> IncommingResult<SplitInto>;
+}
+
+pub trait BulkCmd: Command {
+    fn ids(&self) -> IncommingResult<Vec<Uuid>>;
+    fn change(&self) -> IncommingResult<BulkChange>;
+}
+
+pub trait VelocityCmd: Command {
+    /// the number of sprints used by rolling statistics.
+    fn window(&self) -> IncommingResult<usize>;
+}
+
+pub trait BurndownCmd: Command {
+    fn sprint(&self) -> IncommingResult<Uuid>;
+}
+
+pub trait ForecastCmd: Command {
+    fn basis(&self) -> IncommingResult<ForecastBasis>;
+    fn simulation(&self) -> IncommingResult<Simulation>;
+}
+
+pub trait AddUserCmd: Command {
+    fn user(&self) -> IncommingResult<User>;
+}
+
+pub trait UpdateUserCmd: Command {
+    fn handle(&self) -> IncommingResult<String>;
+    /// rename the user if it is some.
+    fn new_handle(&self) -> IncommingResult<Option<String>>;
+    fn display_name(&self) -> IncommingResult<Option<String>>;
+    fn active(&self) -> IncommingResult<Option<bool>>;
+}
+
+pub trait AddTeamCmd: Command {
+    fn team(&self) -> IncommingResult<TeamProfile>;
+}
+
+pub trait UpdateTeamCmd: Command {
+    fn handle(&self) -> IncommingResult<String>;
+    fn display_name(&self) -> IncommingResult<Option<String>>;
+    fn active(&self) -> IncommingResult<Option<bool>>;
+}
+
+pub trait BoardCmd: Command {
+    /// If it is none, the configured swimlane is used.
+    fn swimlane(&self) -> IncommingResult<Option<Swimlane>>;
+}
+
+pub trait MoveItemCmd: Command {
+    fn id(&self) -> IncommingResult<Uuid>;
+    fn column(&self) -> IncommingResult<String>;
+}
+
+pub trait ConfigureBoardCmd: Command {
+    fn config(&self) -> IncommingResult<BoardConfig>;
+}
+
+pub trait AddSprintCmd: Command {
+    fn sprint(&self) -> IncommingResult<Sprint>;
+}
+
+pub trait PlanItemCmd: Command {
+    fn id(&self) -> IncommingResult<Uuid>;
+    /// `None` means no sprint.
+    fn sprint(&self) -> IncommingResult<Option<Uuid>>;
+}
+
+pub trait AddReleaseCmd: Command {
+    fn release(&self) -> IncommingResult<Release>;
+}
+
+pub trait SetFixVersionCmd: Command {
+    fn id(&self) -> IncommingResult<Uuid>;
+    /// `None` means no release.
+    fn release(&self) -> IncommingResult<Option<String>>;
+}
+
+pub trait ReleaseCmd: Command {
+    fn name(&self) -> IncommingResult<String>;
+}
+
+pub trait ForecastItemsCmd: Command {
+    fn date(&self) -> IncommingResult<NaiveDate>;
+    fn simulation(&self) -> IncommingResult<Simulation>;
+}
+
+#[cfg(test)]
+mod test_get_backlog {
+    use super::*;
+    use backlog::Entity;
+    use memory::MemoryBacklogRepository;
+
+    #[tokio::test]
+    async fn test_get_backlog() {
+        let backlog = Backlog::new();
+        let repo = MemoryBacklogRepository::with_backlog(backlog.clone());
+        let uc = mock::InMemory { repo };
+
+        assert_eq!(uc.get_backlog().await.unwrap().id(), backlog.id());
+    }
+}
+
+#[cfg(test)]
+mod test_add_item {
+    use super::*;
+    use backlog::{AddItem, FindFromCollection, Story};
+    use backlog_repo::ATTEMPTS;
+    use memory::MemoryBacklogRepository;
+
+    #[tokio::test]
+    async fn test_add_item() {
+        let repo = MemoryBacklogRepository::new();
+        let uc = mock::InMemory { repo: repo.clone() };
+
+        let mut cmd = mock::MockAddItemCmd::new();
+        cmd.expect_item().returning(|| {
+            let story = Story::new("", None, None);
+            Ok(Box::new(story))
+        });
+        cmd.expect_force().returning(|| Ok(false));
+        uc.add_item(cmd).await.unwrap();
+
+        let saved = repo.get().await.unwrap();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved.revision(), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_add_item_concurrently() {
+        let repo = MemoryBacklogRepository::new();
+        let mut tasks = Vec::new();
+        for i in 0..ATTEMPTS {
+            let uc = mock::InMemory { repo: repo.clone() };
+            tasks.push(tokio::spawn(async move {
+                let mut cmd = mock::MockAddItemCmd::new();
+                cmd.expect_item().returning(move || {
+                    let story = Story::new(&format!("Story {}", i), None, None);
+                    Ok(Box::new(story))
+                });
+                cmd.expect_force().returning(|| Ok(true));
+                uc.add_item(cmd).await.unwrap();
+            }));
+        }
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        let backlog = repo.get().await.unwrap();
+        assert_eq!(backlog.len(), ATTEMPTS);
+        assert_eq!(backlog.revision(), ATTEMPTS as u64);
+    }
+
+    #[tokio::test]
+    async fn test_add_item_duplicated() {
+        let mut backlog = Backlog::new();
+        backlog.add_item(Box::new(Story::new("Fix the login bug", None, None)));
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let uc = mock::InMemory { repo: repo.clone() };
+
+        let cmd = |force: bool| {
+            let mut cmd = mock::MockAddItemCmd::new();
+            cmd.expect_item().returning(|| {
+                let story = Story::new("fix login bug", None, None);
+                Ok(Box::new(story))
+            });
+            cmd.expect_force().returning(move || Ok(force));
+            cmd
+        };
+
+        let err = uc.add_item(cmd(false)).await.unwrap_err();
+        assert!(err.downcast_ref::<BusinessLogicError>().is_some());
+        assert_eq!(repo.get().await.unwrap().len(), 1);
+
+        let added = uc.add_item(cmd(true)).await.unwrap();
+        assert_eq!(added.duplicates().len(), 1);
+        assert_eq!(repo.get().await.unwrap().len(), 2);
+    }
+}
+
+#[cfg(test)]
+mod test_estimate_item {
+    use super::*;
+    use backlog::BacklogFixture;
+    use memory::MemoryBacklogRepository;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_estimate_item() {
+        let (item_id, backlog) = Backlog::specific_id();
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let uc = mock::InMemory { repo: repo.clone() };
+
+        let mut cmd = mock::MockEstimateItemCmd::new();
+        cmd.expect_id().returning(move || Ok(item_id));
+        cmd.expect_point()
+            .returning(|| Ok(StoryPoint::new(1).unwrap()));
+        uc.estimate_item(cmd).await.unwrap();
+
+        let saved = repo.get().await.unwrap();
+        let finder = mock::finder(&saved, "$.items.ec1985c0-b7ee-4556-a0d1-461ee9eb754f.point");
+        assert_eq!(finder.find(), json!([1]));
+    }
+}
+
+#[cfg(test)]
+mod test_assign_item {
+    use super::*;
+    use backlog::BacklogFixture;
+    use memory::MemoryBacklogRepository;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_assign_item() {
+        let (item_id, backlog) = Backlog::specific_id();
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let uc = mock::InMemory { repo: repo.clone() };
+
+        let mut cmd = mock::MockAssignItemCmd::new();
+        cmd.expect_id().returning(move || Ok(item_id));
+        cmd.expect_assignee()
+            .returning(|| Ok(Assignee::new("dummy")));
+        uc.assign_item(cmd).await.unwrap();
+
+        let saved = repo.get().await.unwrap();
+        let finder = mock::finder(
+            &saved,
+            "$.items.ec1985c0-b7ee-4556-a0d1-461ee9eb754f.assignee",
+        );
+        assert_eq!(finder.find(), json!(["dummy"]));
+    }
+
+    #[tokio::test]
+    async fn test_assign_item_expect_fail() {
+        let (item_id, backlog) = Backlog::specific_id();
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let uc = mock::InMemory { repo: repo.clone() };
+
+        let mut cmd = mock::MockAssignItemCmd::new();
+        cmd.expect_id().returning(move || Ok(item_id));
+        cmd.expect_assignee()
+            .returning(|| Ok(Assignee::new("unknown")));
+
+        let err = uc.assign_item(cmd).await.unwrap_err();
+        assert!(err.downcast_ref::<BusinessLogicError>().is_some());
+        assert_eq!(repo.get().await.unwrap().revision(), 0);
+    }
+}
+
+#[cfg(test)]
+mod test_label_item {
+    use super::*;
+    use backlog::{AddItem, Entity, Story, Task};
+    use memory::MemoryBacklogRepository;
+
+    #[tokio::test]
+    async fn test_label_item() {
+        let repo = MemoryBacklogRepository::new();
+        let mut backlog = repo.get().await.unwrap();
+        let story = Story::new("Login page", None, None);
+        let task = Task::new("Upgrade the database", None, None);
+        let (story_id, task_id) = (story.id(), task.id());
+        backlog.add_item(Box::new(story));
+        backlog.add_item(Box::new(task));
+        repo.save(backlog).await.unwrap();
+        let uc = mock::InMemory { repo: repo.clone() };
+
+        let mut cmd = mock::MockLabelItemCmd::new();
+        cmd.expect_id().returning(move || Ok(story_id));
+        cmd.expect_labels()
+            .returning(|| Ok(vec!["ui".to_string(), "login".to_string()]));
+        let backlog = uc.label_item(cmd).await.unwrap();
+        assert_eq!(backlog.items()[&story_id].labels(), ["ui", "login"]);
+
+        // the task can not be labelled.
+        let mut cmd = mock::MockLabelItemCmd::new();
+        cmd.expect_id().returning(move || Ok(task_id));
+        cmd.expect_labels().returning(|| Ok(vec!["ui".to_string()]));
+        let err = uc.label_item(cmd).await.unwrap_err();
+        assert!(err.downcast_ref::<BusinessLogicError>().is_some());
+        assert_eq!(repo.get().await.unwrap().revision(), 2);
+    }
+}
+
+#[cfg(test)]
+mod test_plan_item {
+    use super::*;
+    use backlog::{BacklogFixture, Entity, Team};
+    use memory::MemoryBacklogRepository;
+
+    #[tokio::test]
+    async fn test_plan_item() {
+        let repo = MemoryBacklogRepository::new();
+        let (item_id, backlog) = Backlog::specific_id();
+        repo.save(backlog).await.unwrap();
+        let uc = mock::InMemory { repo: repo.clone() };
+
+        let start = NaiveDate::from_ymd_opt(2022, 1, 3).unwrap();
+        let end = NaiveDate::from_ymd_opt(2022, 1, 14).unwrap();
+        let sprint = Sprint::new("sprint 1", Team::new("team"), start, end).unwrap();
+        let sprint_id = sprint.id();
+        let mut cmd = mock::MockAddSprintCmd::new();
+        cmd.expect_sprint().returning(move || Ok(sprint.clone()));
+        uc.add_sprint(cmd).await.unwrap();
+        assert_eq!(uc.get_sprints().await.unwrap().len(), 1);
+
+        let mut cmd = mock::MockPlanItemCmd::new();
+        cmd.expect_id().returning(move || Ok(item_id));
+        cmd.expect_sprint().returning(move || Ok(Some(sprint_id)));
+        let backlog = uc.plan_item(cmd).await.unwrap();
+        assert_eq!(backlog.items()[&item_id].sprint(), Some(sprint_id));
+
+        // the item can not be planned into the unknown sprint.
+        let mut cmd = mock::MockPlanItemCmd::new();
+        cmd.expect_id().returning(move || Ok(item_id));
+        cmd.expect_sprint().returning(|| Ok(Some(Uuid::new_v4())));
+        let err = uc.plan_item(cmd).await.unwrap_err();
+        assert!(err.downcast_ref::<BusinessLogicError>().is_some());
+        assert_eq!(repo.get().await.unwrap().revision(), 3);
+    }
+}
+
+#[cfg(test)]
+mod test_retry_on_conflict {
+    use super::*;
+    use backlog::BacklogFixture;
+    use backlog_repo::{BacklogRepositoryError, ATTEMPTS};
+
+    fn is_conflict(err: &eyre::Report) -> bool {
+        matches!(err.downcast_ref::<OutcommingError>(), Some(err) if err.is_conflict())
+    }
+
+    fn conflict() -> BacklogRepositoryError {
+        BacklogRepositoryError::Conflict {
+            loaded: 0,
+            stored: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_conflict() {
+        let (item_id, backlog) = Backlog::specific_id();
+
+        let mut mock = mock::MockTest::new();
+        mock.expect_get()
+            .times(2)
+            .returning(move || Ok(backlog.clone()));
+        let mut saves = 0;
+        mock.expect_save().times(2).returning(move |_| {
+            saves += 1;
+            if saves == 1 {
+                Err(conflict())
+            } else {
+                Ok(())
+            }
+        });
+
+        let mut cmd = mock::MockAssignItemCmd::new();
+        cmd.expect_id().times(1).returning(move || Ok(item_id));
+        cmd.expect_assignee()
+            .times(1)
+            .returning(|| Ok(Assignee::new("dummy")));
+
+        assert!(mock.assign_item(cmd).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_conflict_expect_fail() {
+        let (item_id, backlog) = Backlog::specific_id();
+
+        let mut mock = mock::MockTest::new();
+        mock.expect_get()
+            .times(ATTEMPTS)
+            .returning(move || Ok(backlog.clone()));
+        mock.expect_save()
+            .times(ATTEMPTS)
+            .returning(|_| Err(conflict()));
+
+        let mut cmd = mock::MockAssignItemCmd::new();
+        cmd.expect_id().returning(move || Ok(item_id));
+        cmd.expect_assignee()
+            .returning(|| Ok(Assignee::new("dummy")));
+
+        let err = mock.assign_item(cmd).await.unwrap_err();
+        assert!(is_conflict(&err));
+    }
+}
+
+#[cfg(test)]
+mod test_update_and_notify {
+    use super::*;
+    use backlog::BacklogFixture;
+    use memory::MemoryBacklogRepository;
+
+    #[tokio::test]
+    async fn test_unchanged_backlog_is_not_saved() {
+        let (_, backlog) = Backlog::specific_id();
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let uc = mock::InMemory { repo: repo.clone() };
+
+        let committed = uc
+            .update_and_notify("Count the items", |backlog| Ok(backlog.items().len()))
+            .await
+            .unwrap();
+        assert!(!committed.is_saved());
+        assert_eq!(committed.into_value(), 1);
+        assert_eq!(repo.get().await.unwrap().revision(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_failed_work_is_rolled_back() {
+        let (item_id, backlog) = Backlog::specific_id();
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let uc = mock::InMemory { repo: repo.clone() };
+
+        let err = uc
+            .update_and_notify("Add the item", |backlog| {
+                backlog
+                    .assign_item(&item_id, Assignee::new("dummy"))
+                    .wrap::<BusinessLogicError>()?;
+                backlog
+                    .rank_item(&Uuid::new_v4(), Placement::Top)
+                    .wrap::<BusinessLogicError>()
+            })
+            .await
+            .unwrap_err();
+        assert!(err.downcast_ref::<BusinessLogicError>().is_some());
+
+        let saved = repo.get().await.unwrap();
+        assert_eq!(saved.revision(), 0);
+        assert!(saved.items()[&item_id].assignee().is_none());
+    }
+}
+
+#[cfg(test)]
+mod test_update_user {
+    use super::*;
+    use backlog::{BacklogFixture, FindFromCollection};
+    use memory::MemoryBacklogRepository;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_rename_user() {
+        let (item_id, mut backlog) = Backlog::specific_id();
+        backlog
+            .assign_item(&item_id, Assignee::new("dummy"))
+            .unwrap();
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let uc = mock::InMemory { repo: repo.clone() };
+
+        let mut cmd = mock::MockUpdateUserCmd::new();
+        cmd.expect_handle().returning(|| Ok("dummy".to_string()));
+        cmd.expect_new_handle()
+            .returning(|| Ok(Some("renamed".to_string())));
+        cmd.expect_display_name().returning(|| Ok(None));
+        cmd.expect_active().returning(|| Ok(None));
+
+        let directory = uc.update_user(cmd).await.unwrap();
+        assert!(directory.user("renamed").is_some());
+
+        let saved = repo.get().await.unwrap();
+        let finder = mock::finder(
+            &saved,
+            "$.items.ec1985c0-b7ee-4556-a0d1-461ee9eb754f.assignee",
+        );
+        assert_eq!(saved.len(), 1);
+        assert_eq!(finder.find(), json!(["renamed"]));
+    }
+}
+
+#[cfg(test)]
+mod test_rank_item {
+    use super::*;
+    use backlog::BacklogFixture;
+    use memory::MemoryBacklogRepository;
+
+    #[tokio::test]
+    async fn test_rank_item_expect_fail() {
+        let (item_id, backlog) = Backlog::specific_id();
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let uc = mock::InMemory { repo: repo.clone() };
+
+        let mut cmd = mock::MockRankItemCmd::new();
+        cmd.expect_id().returning(move || Ok(item_id));
+        cmd.expect_placement()
+            .returning(|| Ok(Placement::After(Uuid::new_v4())));
+
+        let err = uc.rank_item(cmd).await.unwrap_err();
+        assert!(err.downcast_ref::<BusinessLogicError>().is_some());
+        assert_eq!(repo.get().await.unwrap().revision(), 0);
+    }
+}
+
+#[cfg(test)]
+mod test_split_item {
+    use super::*;
+    use backlog::BacklogFixture;
+    use memory::MemoryBacklogRepository;
+
+    #[tokio::test]
+    async fn test_split_item_expect_fail() {
+        let (item_id, backlog) = Backlog::specific_id();
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let uc = mock::InMemory { repo: repo.clone() };
+
+        // the fixture item is a task, which can not be split.
+        let mut cmd = mock::MockSplitItemCmd::new();
+        cmd.expect_id().returning(move || Ok(item_id));
+        cmd.expect_split_into()
+            .returning(|| Ok(SplitInto::Count(2)));
+
+        let err = uc.split_item(cmd).await.unwrap_err();
+        assert!(err.downcast_ref::<BusinessLogicError>().is_some());
+        assert_eq!(repo.get().await.unwrap().revision(), 0);
+    }
+}
+
+#[cfg(test)]
+mod test_bulk_update {
+    use super::*;
+    use backlog::BacklogFixture;
+    use memory::MemoryBacklogRepository;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_bulk_update() {
+        let (item_id, backlog) = Backlog::specific_id();
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let uc = mock::InMemory { repo: repo.clone() };
+
+        let mut cmd = mock::MockBulkCmd::new();
+        cmd.expect_ids().returning(move || Ok(vec![item_id]));
+        cmd.expect_change()
+            .returning(|| Ok(BulkChange::Assign(Assignee::new("dummy"))));
+
+        let report = uc.bulk_update(cmd).await.unwrap();
+        assert_eq!(report.results().len(), 1);
+        let saved = repo.get().await.unwrap();
+        let finder = mock::finder(
+            &saved,
+            "$.items.ec1985c0-b7ee-4556-a0d1-461ee9eb754f.assignee",
+        );
+        assert_eq!(finder.find(), json!(["dummy"]));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_update_expect_fail() {
+        let (item_id, backlog) = Backlog::specific_id();
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let uc = mock::InMemory { repo: repo.clone() };
+
+        let mut cmd = mock::MockBulkCmd::new();
+        cmd.expect_ids()
+            .returning(move || Ok(vec![item_id, Uuid::new_v4()]));
+        cmd.expect_change()
+            .returning(|| Ok(BulkChange::Assign(Assignee::new("dummy"))));
+
+        let err = uc.bulk_update(cmd).await.unwrap_err();
+        assert!(err.downcast_ref::<BusinessLogicError>().is_some());
+        assert_eq!(repo.get().await.unwrap().revision(), 0);
+    }
+}
+
+#[cfg(test)]
+mod test_velocity {
+    use super::*;
+    use memory::MemoryBacklogRepository;
+
+    #[tokio::test]
+    async fn test_velocity() {
+        let uc = mock::InMemory {
+            repo: MemoryBacklogRepository::new(),
+        };
+
+        let mut cmd = mock::MockVelocityCmd::new();
+        cmd.expect_window().returning(|| Ok(3));
+
+        let report = uc.velocity(cmd).await.unwrap();
+        assert_eq!(report.window(), 3);
+        assert!(report.teams().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod test_burndown {
+    use super::*;
+    use memory::MemoryBacklogRepository;
+
+    #[tokio::test]
+    async fn test_burndown_expect_fail() {
+        let uc = mock::InMemory {
+            repo: MemoryBacklogRepository::new(),
+        };
+
+        let mut cmd = mock::MockBurndownCmd::new();
+        cmd.expect_sprint().returning(|| Ok(Uuid::new_v4()));
+
+        let err = uc.burndown(cmd).await.unwrap_err();
+        assert!(err.downcast_ref::<BusinessLogicError>().is_some());
+    }
+}
+
 #[cfg(test)]
-mod test_get_backlog {
+mod test_forecast {
     use super::*;
+    use memory::MemoryBacklogRepository;
 
     #[tokio::test]
-    async fn test_get_backlog() {
-        let mut mock = mock::MockTest::new();
-        mock.expect_get().times(1).returning(|| Ok(Backlog::new()));
-        mock.get_backlog().await.unwrap();
+    async fn test_forecast_expect_fail() {
+        let uc = mock::InMemory {
+            repo: MemoryBacklogRepository::new(),
+        };
+
+        let mut cmd = mock::MockForecastCmd::new();
+        cmd.expect_basis().returning(|| Ok(ForecastBasis::Velocity));
+        cmd.expect_simulation()
+            .returning(|| Ok(Simulation::new(Some(1), 10, None).unwrap()));
+
+        let err = uc.forecast(cmd).await.unwrap_err();
+        assert!(err.downcast_ref::<BusinessLogicError>().is_some());
     }
 }
 
 #[cfg(test)]
-mod test_add_item {
+mod test_watch_item {
     use super::*;
-    use backlog::{FindFromCollection, Story};
+    use backlog::{BacklogFixture, Change, Status};
+    use memory::MemoryBacklogRepository;
+    use notifier::NotifierError;
 
     #[tokio::test]
-    async fn test_add_item() {
-        let mut mock = mock::MockTest::new();
-        mock.expect_get().times(1).returning(|| Ok(Backlog::new()));
-        mock.expect_save()
+    async fn test_notify_watchers() {
+        let (item_id, mut backlog) = Backlog::specific_id();
+        backlog
+            .watch_item(&item_id, Assignee::new("dummy"))
+            .unwrap();
+
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let mut notifier = mock::MockNotifier::new();
+        notifier
+            .expect_notify()
             .times(1)
-            .withf(|backlog| backlog.len() == 1)
+            .withf(|notification| {
+                notification.recipients() == [Assignee::new("dummy")]
+                    && notification.event().change()
+                        == &Change::StatusChanged {
+                            from: Status::Todo,
+                            to: Status::InProgress,
+                        }
+            })
             .returning(|_| Ok(()));
+        let use_case = mock::Notifying { repo, notifier };
 
-        let mut cmd = mock::MockAddItemCmd::new();
-        cmd.expect_item().returning(|| {
-            let story = Story::new("", None, None);
-            Ok(Box::new(story))
-        });
-        mock.add_item(cmd).await.unwrap();
+        let mut cmd = mock::MockMoveItemCmd::new();
+        cmd.expect_id().returning(move || Ok(item_id));
+        cmd.expect_column()
+            .returning(|| Ok("In Progress".to_string()));
+        use_case.move_item(cmd).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_notify_watchers_fail_after_save() {
+        let (item_id, mut backlog) = Backlog::specific_id();
+        backlog
+            .watch_item(&item_id, Assignee::new("dummy"))
+            .unwrap();
+
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let mut notifier = mock::MockNotifier::new();
+        notifier
+            .expect_notify()
+            .returning(|_| Err(NotifierError::delivery("unreachable")));
+        let use_case = mock::Notifying {
+            repo: repo.clone(),
+            notifier,
+        };
+
+        let mut cmd = mock::MockMoveItemCmd::new();
+        cmd.expect_id().returning(move || Ok(item_id));
+        cmd.expect_column()
+            .returning(|| Ok("In Progress".to_string()));
+        // the command has been saved, so it succeeds without the notification.
+        use_case.move_item(cmd).await.unwrap();
+        assert_eq!(repo.get().await.unwrap().revision(), 1);
     }
 }
 
 #[cfg(test)]
-mod test_estimate_item {
+mod test_attachment {
     use super::*;
-    use backlog::BacklogFixture;
-    use serde_json::json;
+    use backlog::{BacklogFixture, Entity};
+    use blob_storage::BlobStorageError;
+    use memory::MemoryBacklogRepository;
 
     #[tokio::test]
-    async fn test_estimate_item() {
+    async fn test_attach_and_delete_attachment() {
         let (item_id, backlog) = Backlog::specific_id();
-
-        let mut mock = mock::MockTest::new();
-        mock.expect_get()
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let mut blob_storage = mock::MockBlobStorage::new();
+        blob_storage
+            .expect_put()
             .times(1)
-            .returning(move || Ok(backlog.clone()));
-        mock.expect_save()
+            .withf(|hash, content| hash == backlog::content_hash(b"png") && content == b"png")
+            .returning(|_, _| Ok(()));
+        // the content is deleted with the last attachment which refers to it.
+        let hash = backlog::content_hash(b"png");
+        blob_storage
+            .expect_delete()
             .times(1)
-            .withf(|backlog| {
-                let finder = mock::finder(
-                    backlog,
-                    "$.items.ec1985c0-b7ee-4556-a0d1-461ee9eb754f.point",
-                );
-                finder.find() == json!([1])
-            })
+            .withf(move |h| h == hash)
             .returning(|_| Ok(()));
+        let use_case = mock::Storing {
+            repo: repo.clone(),
+            blob_storage,
+        };
 
-        let mut cmd = mock::MockEstimateItemCmd::new();
+        let mut cmd = mock::MockAttachItemCmd::new();
         cmd.expect_id().returning(move || Ok(item_id));
-        cmd.expect_point()
-            .returning(|| Ok(StoryPoint::new(1).unwrap()));
+        cmd.expect_filename()
+            .returning(|| Ok("login.png".to_string()));
+        cmd.expect_content().returning(|| Ok(b"png".to_vec()));
+        cmd.expect_uploader()
+            .returning(|| Ok(Assignee::new("dummy")));
+        let attachment = use_case.attach_item(cmd).await.unwrap();
+        assert_eq!(attachment.size(), 3);
+
+        let mut cmd = mock::MockAttachmentCmd::new();
+        cmd.expect_id().returning(move || Ok(item_id));
+        cmd.expect_attachment()
+            .returning(move || Ok(attachment.id()));
+        use_case.delete_attachment(cmd).await.unwrap();
+
+        let saved = repo.get().await.unwrap();
+        assert!(saved.attachments(&item_id).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_attach_item_expect_fail() {
+        let (item_id, backlog) = Backlog::specific_id();
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let mut blob_storage = mock::MockBlobStorage::new();
+        blob_storage.expect_put().returning(|_, _| {
+            Err(BlobStorageError::Io(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "disk full",
+            )))
+        });
+        let use_case = mock::Storing {
+            repo: repo.clone(),
+            blob_storage,
+        };
+
+        let mut cmd = mock::MockAttachItemCmd::new();
+        cmd.expect_id().returning(move || Ok(item_id));
+        cmd.expect_filename()
+            .returning(|| Ok("login.png".to_string()));
+        cmd.expect_content().returning(|| Ok(b"png".to_vec()));
+        cmd.expect_uploader()
+            .returning(|| Ok(Assignee::new("dummy")));
+        let err = use_case.attach_item(cmd).await.unwrap_err();
+        assert!(err.downcast_ref::<OutcommingError>().is_some());
+        // the backlog never refers to the content which fails to be stored.
+        assert_eq!(repo.get().await.unwrap().revision(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_attach_unknown_item_expect_fail() {
+        let (_, backlog) = Backlog::specific_id();
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        // the content is never stored for the attachment which the backlog rejects.
+        let mut blob_storage = mock::MockBlobStorage::new();
+        blob_storage.expect_put().times(0);
+        let use_case = mock::Storing {
+            repo: repo.clone(),
+            blob_storage,
+        };
 
-        assert!(mock.estimate_item(cmd).await.is_ok());
+        let mut cmd = mock::MockAttachItemCmd::new();
+        cmd.expect_id().returning(|| Ok(Uuid::new_v4()));
+        cmd.expect_filename()
+            .returning(|| Ok("login.png".to_string()));
+        cmd.expect_content().returning(|| Ok(b"png".to_vec()));
+        cmd.expect_uploader()
+            .returning(|| Ok(Assignee::new("dummy")));
+        let err = use_case.attach_item(cmd).await.unwrap_err();
+        assert!(err.downcast_ref::<BusinessLogicError>().is_some());
+        assert_eq!(repo.get().await.unwrap().revision(), 0);
     }
 }
 
 #[cfg(test)]
-mod test_assign_item {
+mod test_release {
+    use super::*;
+    use backlog::{BacklogFixture, NaiveDate};
+    use memory::MemoryBacklogRepository;
+
+    #[tokio::test]
+    async fn test_ship_release() {
+        let (item_id, mut backlog) = Backlog::specific_id();
+        let target = NaiveDate::from_ymd_opt(2022, 4, 1).unwrap();
+        backlog
+            .add_release(Release::new("1.0", target).unwrap())
+            .unwrap();
+        backlog.set_fix_version(&item_id, Some("1.0")).unwrap();
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let uc = mock::InMemory { repo: repo.clone() };
+
+        // the item of the release is not done yet.
+        let mut cmd = mock::MockReleaseCmd::new();
+        cmd.expect_name().returning(|| Ok("1.0".to_string()));
+        let err = uc.ship_release(cmd).await.unwrap_err();
+        assert!(err.downcast_ref::<BusinessLogicError>().is_some());
+        assert_eq!(repo.get().await.unwrap().revision(), 0);
+    }
+}
+
+#[cfg(test)]
+mod test_board {
     use super::*;
     use backlog::BacklogFixture;
+    use memory::MemoryBacklogRepository;
     use serde_json::json;
 
     #[tokio::test]
-    async fn test_assign_item() {
+    async fn test_move_item() {
         let (item_id, backlog) = Backlog::specific_id();
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let uc = mock::InMemory { repo: repo.clone() };
 
-        let mut mock = mock::MockTest::new();
-        mock.expect_get()
-            .times(1)
-            .returning(move || Ok(backlog.clone()));
-        mock.expect_save()
-            .times(1)
-            .withf(|backlog| {
-                let finder = mock::finder(
-                    backlog,
-                    "$.items.ec1985c0-b7ee-4556-a0d1-461ee9eb754f.assignee",
-                );
-                finder.find() == json!(["dummy"])
-            })
-            .returning(|_| Ok(()));
+        let mut cmd = mock::MockMoveItemCmd::new();
+        cmd.expect_id().returning(move || Ok(item_id));
+        cmd.expect_column()
+            .returning(|| Ok("In Progress".to_string()));
 
-        let mut cmd = mock::MockAssignItemCmd::new();
+        let board = uc.move_item(cmd).await.unwrap();
+        assert_eq!(board.columns()[1].count(), 1);
+        let saved = repo.get().await.unwrap();
+        let finder = mock::finder(
+            &saved,
+            "$.items.ec1985c0-b7ee-4556-a0d1-461ee9eb754f.status",
+        );
+        assert_eq!(finder.find(), json!(["InProgress"]));
+    }
+
+    #[tokio::test]
+    async fn test_move_item_expect_fail() {
+        let (item_id, backlog) = Backlog::specific_id();
+        let repo = MemoryBacklogRepository::with_backlog(backlog);
+        let uc = mock::InMemory { repo: repo.clone() };
+
+        let mut cmd = mock::MockMoveItemCmd::new();
         cmd.expect_id().returning(move || Ok(item_id));
-        cmd.expect_assignee()
-            .returning(|| Ok(Assignee::new("dummy")));
+        cmd.expect_column().returning(|| Ok("Unknown".to_string()));
 
-        assert!(mock.assign_item(cmd).await.is_ok());
+        let err = uc.move_item(cmd).await.unwrap_err();
+        assert!(err.downcast_ref::<BusinessLogicError>().is_some());
+        assert_eq!(repo.get().await.unwrap().revision(), 0);
     }
 }
 
@@ -180,15 +1819,21 @@ mod test_assign_item {
 pub mod mock {
     use super::*;
     use async_trait::async_trait;
+    use backlog::Notification;
     use backlog_repo::BacklogRepositoryResult;
+    use blob_storage::{BlobStorageError, BlobStorageResult};
     use jsonpath_rust::*;
+    use memory::MemoryBacklogRepository;
     use mockall::mock;
+    use notifier::{NotifierResult, Notifiers};
 
     pub fn finder(value: impl serde::Serialize, path: &str) -> JsonPathFinder {
         let ser = serde_json::to_string(&value).expect("fail to serialize");
         JsonPathFinder::from_str(&ser, path).expect("fail to create JsonPathFinder")
     }
 
+    // the repository only to inject the errors, e.g. the conflicts of the saves,
+    // and the other use cases run over `MemoryBacklogRepository`.
     mock! {
         pub Test {}
 
@@ -199,6 +1844,17 @@ pub mod mock {
         }
     }
 
+    mock! {
+        pub BlobStorage {}
+
+        #[async_trait]
+        impl BlobStorage for BlobStorage {
+            async fn put(&self, hash: &str, content: Vec<u8>) -> BlobStorageResult<()>;
+            async fn get(&self, hash: &str) -> BlobStorageResult<Vec<u8>>;
+            async fn delete(&self, hash: &str) -> BlobStorageResult<()>;
+        }
+    }
+
     mock! {
         pub AddItemCmd {}
 
@@ -206,6 +1862,7 @@ pub mod mock {
 
         impl AddItemCmd for AddItemCmd {
             fn item(&self) -> IncommingResult<Box<dyn BacklogItem>>;
+            fn force(&self) -> IncommingResult<bool>;
         }
     }
 
@@ -231,6 +1888,169 @@ pub mod mock {
         }
     }
 
+    mock! {
+        pub VelocityCmd {}
+
+        impl Command for VelocityCmd {}
+
+        impl VelocityCmd for VelocityCmd {
+            fn window(&self) -> IncommingResult<usize>;
+        }
+    }
+
+    mock! {
+        pub BurndownCmd {}
+
+        impl Command for BurndownCmd {}
+
+        impl BurndownCmd for BurndownCmd {
+            fn sprint(&self) -> IncommingResult<Uuid>;
+        }
+    }
+
+    mock! {
+        pub ForecastCmd {}
+
+        impl Command for ForecastCmd {}
+
+        impl ForecastCmd for ForecastCmd {
+            fn basis(&self) -> IncommingResult<ForecastBasis>;
+            fn simulation(&self) -> IncommingResult<Simulation>;
+        }
+    }
+
+    mock! {
+        pub UpdateUserCmd {}
+
+        impl Command for UpdateUserCmd {}
+
+        impl UpdateUserCmd for UpdateUserCmd {
+            fn handle(&self) -> IncommingResult<String>;
+            fn new_handle(&self) -> IncommingResult<Option<String>>;
+            fn display_name(&self) -> IncommingResult<Option<String>>;
+            fn active(&self) -> IncommingResult<Option<bool>>;
+        }
+    }
+
+    mock! {
+        pub MoveItemCmd {}
+
+        impl Command for MoveItemCmd {}
+
+        impl MoveItemCmd for MoveItemCmd {
+            fn id(&self) -> IncommingResult<Uuid>;
+            fn column(&self) -> IncommingResult<String>;
+        }
+    }
+
+    mock! {
+        pub RankItemCmd {}
+
+        impl Command for RankItemCmd {}
+
+        impl RankItemCmd for RankItemCmd {
+            fn id(&self) -> IncommingResult<Uuid>;
+            fn placement(&self) -> IncommingResult<Placement>;
+        }
+    }
+
+    mock! {
+        pub BulkCmd {}
+
+        impl Command for BulkCmd {}
+
+        impl BulkCmd for BulkCmd {
+            fn ids(&self) -> IncommingResult<Vec<Uuid>>;
+            fn change(&self) -> IncommingResult<BulkChange>;
+        }
+    }
+
+    mock! {
+        pub SplitItemCmd {}
+
+        impl Command for SplitItemCmd {}
+
+        impl SplitItemCmd for SplitItemCmd {
+            fn id(&self) -> IncommingResult<Uuid>;
+            fn split_into(&self) -> IncommingResult<SplitInto>;
+        }
+    }
+
+    mock! {
+        pub ReleaseCmd {}
+
+        impl Command for ReleaseCmd {}
+
+        impl ReleaseCmd for ReleaseCmd {
+            fn name(&self) -> IncommingResult<String>;
+        }
+    }
+
+    mock! {
+        pub AttachItemCmd {}
+
+        impl Command for AttachItemCmd {}
+
+        impl AttachItemCmd for AttachItemCmd {
+            fn id(&self) -> IncommingResult<Uuid>;
+            fn filename(&self) -> IncommingResult<String>;
+            fn content(&self) -> IncommingResult<Vec<u8>>;
+            fn uploader(&self) -> IncommingResult<Assignee>;
+        }
+    }
+
+    mock! {
+        pub LabelItemCmd {}
+
+        impl Command for LabelItemCmd {}
+
+        impl LabelItemCmd for LabelItemCmd {
+            fn id(&self) -> IncommingResult<Uuid>;
+            fn labels(&self) -> IncommingResult<Vec<String>>;
+        }
+    }
+
+    mock! {
+        pub AddSprintCmd {}
+
+        impl Command for AddSprintCmd {}
+
+        impl AddSprintCmd for AddSprintCmd {
+            fn sprint(&self) -> IncommingResult<Sprint>;
+        }
+    }
+
+    mock! {
+        pub PlanItemCmd {}
+
+        impl Command for PlanItemCmd {}
+
+        impl PlanItemCmd for PlanItemCmd {
+            fn id(&self) -> IncommingResult<Uuid>;
+            fn sprint(&self) -> IncommingResult<Option<Uuid>>;
+        }
+    }
+
+    mock! {
+        pub AttachmentCmd {}
+
+        impl Command for AttachmentCmd {}
+
+        impl AttachmentCmd for AttachmentCmd {
+            fn id(&self) -> IncommingResult<Uuid>;
+            fn attachment(&self) -> IncommingResult<Uuid>;
+        }
+    }
+
+    mock! {
+        pub Notifier {}
+
+        #[async_trait]
+        impl Notifier for Notifier {
+            async fn notify(&self, notification: &Notification) -> NotifierResult<()>;
+        }
+    }
+
     impl BacklogUseCase for MockTest {}
 
     impl ProvideBacklogRepository for MockTest {
@@ -240,4 +2060,138 @@ pub mod mock {
             &self
         }
     }
+
+    /// the use cases of `MockTest` notify nobody.
+    static NOTIFIERS: Notifiers = Notifiers::new();
+
+    impl ProvideNotifier for MockTest {
+        type Notifier = Notifiers;
+
+        fn notifier(&self) -> &Self::Notifier {
+            &NOTIFIERS
+        }
+    }
+
+    /// The use cases which notify the watchers through the mock.
+    pub struct Notifying {
+        pub repo: MemoryBacklogRepository,
+        pub notifier: MockNotifier,
+    }
+
+    impl BacklogUseCase for Notifying {}
+
+    impl ProvideBacklogRepository for Notifying {
+        type Repository = MemoryBacklogRepository;
+
+        fn provide(&self) -> &Self::Repository {
+            &self.repo
+        }
+    }
+
+    impl ProvideNotifier for Notifying {
+        type Notifier = MockNotifier;
+
+        fn notifier(&self) -> &Self::Notifier {
+            &self.notifier
+        }
+    }
+
+    impl ProvideBlobStorage for Notifying {
+        type BlobStorage = NoBlobStorage;
+
+        fn blob_storage(&self) -> &Self::BlobStorage {
+            &NO_BLOB_STORAGE
+        }
+    }
+
+    /// The storage which has no content and refuses to store any.
+    pub struct NoBlobStorage;
+
+    static NO_BLOB_STORAGE: NoBlobStorage = NoBlobStorage;
+
+    #[async_trait]
+    impl BlobStorage for NoBlobStorage {
+        async fn put(&self, hash: &str, _content: Vec<u8>) -> BlobStorageResult<()> {
+            Err(BlobStorageError::not_found(hash))
+        }
+
+        async fn get(&self, hash: &str) -> BlobStorageResult<Vec<u8>> {
+            Err(BlobStorageError::not_found(hash))
+        }
+
+        async fn delete(&self, _hash: &str) -> BlobStorageResult<()> {
+            Ok(())
+        }
+    }
+
+    impl ProvideBlobStorage for MockTest {
+        type BlobStorage = NoBlobStorage;
+
+        fn blob_storage(&self) -> &Self::BlobStorage {
+            &NO_BLOB_STORAGE
+        }
+    }
+
+    /// The use cases over the backlog kept in memory, which need no expectation.
+    pub struct InMemory {
+        pub repo: MemoryBacklogRepository,
+    }
+
+    impl BacklogUseCase for InMemory {}
+
+    impl ProvideBacklogRepository for InMemory {
+        type Repository = MemoryBacklogRepository;
+
+        fn provide(&self) -> &Self::Repository {
+            &self.repo
+        }
+    }
+
+    impl ProvideNotifier for InMemory {
+        type Notifier = Notifiers;
+
+        fn notifier(&self) -> &Self::Notifier {
+            &NOTIFIERS
+        }
+    }
+
+    impl ProvideBlobStorage for InMemory {
+        type BlobStorage = NoBlobStorage;
+
+        fn blob_storage(&self) -> &Self::BlobStorage {
+            &NO_BLOB_STORAGE
+        }
+    }
+
+    /// The use cases which store the contents through the mock.
+    pub struct Storing {
+        pub repo: MemoryBacklogRepository,
+        pub blob_storage: MockBlobStorage,
+    }
+
+    impl BacklogUseCase for Storing {}
+
+    impl ProvideBacklogRepository for Storing {
+        type Repository = MemoryBacklogRepository;
+
+        fn provide(&self) -> &Self::Repository {
+            &self.repo
+        }
+    }
+
+    impl ProvideNotifier for Storing {
+        type Notifier = Notifiers;
+
+        fn notifier(&self) -> &Self::Notifier {
+            &NOTIFIERS
+        }
+    }
+
+    impl ProvideBlobStorage for Storing {
+        type BlobStorage = MockBlobStorage;
+
+        fn blob_storage(&self) -> &Self::BlobStorage {
+            &self.blob_storage
+        }
+    }
 }
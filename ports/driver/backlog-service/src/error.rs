@@ -2,6 +2,8 @@ use std::fmt::Debug;
 
 use backlog::BacklogError;
 use backlog_repo::BacklogRepositoryError;
+use blob_storage::BlobStorageError;
+use notifier::NotifierError;
 use thiserror::Error;
 
 pub type IncommingResult<T> = Result<T, IncommingError>;
@@ -25,6 +27,20 @@ impl IncommingError {
 pub enum OutcommingError {
     #[error("BacklogRepositoryError: {0}")]
     BacklogRepository(#[from] BacklogRepositoryError),
+    #[error("NotifierError: {0}")]
+    Notifier(#[from] NotifierError),
+    #[error("BlobStorageError: {0}")]
+    BlobStorage(#[from] BlobStorageError),
+}
+
+impl OutcommingError {
+    /// The backlog has been saved by another since it was loaded.
+    pub fn is_conflict(&self) -> bool {
+        matches!(
+            self,
+            Self::BacklogRepository(BacklogRepositoryError::Conflict { .. })
+        )
+    }
 }
 
 #[derive(Debug, Error)]
@@ -1,5 +1,12 @@
 mod backlog_uc;
 mod error;
 
-pub use backlog_uc::{AddItemCmd, AssignItemCmd, BacklogUseCase, Command, EstimateItemCmd};
+pub use backlog_uc::{
+    AddItemCmd, AddReleaseCmd, AddSprintCmd, AddTeamCmd, AddUserCmd, AssignItemCmd, AttachItemCmd,
+    AttachmentCmd, AttachmentsCmd, BacklogUseCase, BoardCmd, BulkCmd, BurndownCmd, Command,
+    ConfigureBoardCmd, DiffCmd, EstimateItemCmd, FindDuplicatesCmd, ForecastCmd, ForecastItemsCmd,
+    HistoryCmd, LabelItemCmd, LinkItemCmd, ListItemsCmd, MigrateCmd, MoveItemCmd, PlanItemCmd,
+    PrioritizeItemCmd, RankItemCmd, ReleaseCmd, RestoreCmd, SetEpicCmd, SetFixVersionCmd,
+    SplitItemCmd, UpdateTeamCmd, UpdateUserCmd, VelocityCmd, WatchItemCmd,
+};
 pub use error::{BusinessLogicError, IncommingError, IncommingResult, OutcommingError};